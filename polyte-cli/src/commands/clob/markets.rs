@@ -0,0 +1,42 @@
+use clap::Subcommand;
+use color_eyre::eyre::Result;
+use polyte_clob::Clob;
+use polyte_core::QueryBuilder;
+
+use crate::commands::common::output::{print, OutputFormat};
+
+#[derive(Subcommand)]
+pub enum MarketsCommand {
+    /// List markets, one page at a time
+    List {
+        /// Cursor of the page to fetch (from a previous `next_cursor`)
+        #[arg(short, long)]
+        cursor: Option<String>,
+    },
+    /// Get a market by condition ID
+    Get {
+        /// Market condition ID
+        condition_id: String,
+    },
+}
+
+impl MarketsCommand {
+    pub async fn run(self, clob: &Clob, format: OutputFormat) -> Result<()> {
+        match self {
+            Self::List { cursor } => {
+                let markets = clob
+                    .markets()
+                    .list()
+                    .query_opt("next_cursor", cursor)
+                    .send()
+                    .await?;
+                print(&markets, format)?;
+            }
+            Self::Get { condition_id } => {
+                let market = clob.markets().get(condition_id).send().await?;
+                print(&market, format)?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,110 @@
+mod markets;
+
+use clap::{Subcommand, ValueEnum};
+use color_eyre::eyre::Result;
+use polyte_clob::{Account, Clob, OrderSide};
+
+use crate::commands::common::output::{print, OutputFormat};
+
+#[derive(Subcommand)]
+pub enum ClobCommand {
+    /// Query CLOB markets
+    Markets {
+        #[command(subcommand)]
+        command: markets::MarketsCommand,
+    },
+    /// Get the order book of a token
+    Book {
+        /// Token ID
+        token_id: String,
+    },
+    /// Get the best price of a token on one side of the book
+    Price {
+        /// Token ID
+        token_id: String,
+        /// Side of the book
+        #[arg(value_enum)]
+        side: Side,
+    },
+    /// Get the midpoint price of a token
+    Midpoint {
+        /// Token ID
+        token_id: String,
+    },
+}
+
+impl ClobCommand {
+    pub async fn run(self, format: OutputFormat) -> Result<()> {
+        let clob = Clob::from_account(Account::from_env()?)?;
+
+        match self {
+            Self::Markets { command } => command.run(&clob, format).await?,
+            Self::Book { token_id } => {
+                let book = clob.markets().order_book(token_id).send().await?;
+                print(&book, format)?;
+            }
+            Self::Price { token_id, side } => {
+                let price = clob.markets().price(token_id, side.into()).send().await?;
+                print(&price, format)?;
+            }
+            Self::Midpoint { token_id } => {
+                let midpoint = clob.markets().midpoint(token_id).send().await?;
+                print(&midpoint, format)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Side of the order book
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Side {
+    /// Buy side
+    Buy,
+    /// Sell side
+    Sell,
+}
+
+impl From<Side> for OrderSide {
+    fn from(side: Side) -> Self {
+        match side {
+            Side::Buy => Self::Buy,
+            Side::Sell => Self::Sell,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+
+    use super::*;
+
+    #[derive(Parser)]
+    struct Cli {
+        #[command(subcommand)]
+        clob: ClobCommand,
+    }
+
+    #[test]
+    fn test_price_side() {
+        let cli = Cli::try_parse_from(["polyte", "price", "123", "sell"]).unwrap();
+        assert!(matches!(
+            cli.clob,
+            ClobCommand::Price { ref token_id, side: Side::Sell } if token_id == "123"
+        ));
+        assert!(Cli::try_parse_from(["polyte", "price", "123", "hold"]).is_err());
+    }
+
+    #[test]
+    fn test_markets_subcommands() {
+        let cli = Cli::try_parse_from(["polyte", "markets", "get", "0xabc"]).unwrap();
+        assert!(matches!(
+            cli.clob,
+            ClobCommand::Markets {
+                command: markets::MarketsCommand::Get { ref condition_id }
+            } if condition_id == "0xabc"
+        ));
+        assert!(Cli::try_parse_from(["polyte", "markets", "list", "--cursor", "MTAw"]).is_ok());
+    }
+}
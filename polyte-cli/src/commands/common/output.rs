@@ -0,0 +1,364 @@
+use std::io::{self, Write};
+
+use clap::ValueEnum;
+use color_eyre::eyre::{bail, Result};
+use polyte_data::types::{Activity, Position, Trade};
+use polyte_gamma::types::{Event, Market};
+use serde::Serialize;
+
+/// Widest a table cell may grow before it is truncated
+const MAX_TABLE_WIDTH: usize = 48;
+
+/// Output format for command results
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum, Default)]
+pub enum OutputFormat {
+    /// Compact JSON (one value per line)
+    Json,
+    /// Pretty-printed JSON
+    #[default]
+    Pretty,
+    /// CSV with a header row
+    Csv,
+    /// Aligned columns for reading in a terminal
+    #[value(alias = "summary")]
+    Table,
+}
+
+/// A type that renders as one row of CSV or table output
+pub trait Tabular {
+    /// Column names, in the order of [`Tabular::row`]
+    const COLUMNS: &'static [&'static str];
+
+    /// Field values for this row
+    fn row(&self) -> Vec<String>;
+}
+
+/// Print a value that has no row layout; CSV and table output are rejected
+pub fn print<T: Serialize + ?Sized>(value: &T, format: OutputFormat) -> Result<()> {
+    write_json(value, format, &mut io::stdout().lock())
+}
+
+/// Print a list of rows in any format
+pub fn print_rows<T: Serialize + Tabular>(rows: &[T], format: OutputFormat) -> Result<()> {
+    write_rows(rows, format, &mut io::stdout().lock())
+}
+
+/// Print a single value in any format: as itself in JSON, as one row otherwise
+pub fn print_row<T: Serialize + Tabular>(row: &T, format: OutputFormat) -> Result<()> {
+    write_row(row, format, &mut io::stdout().lock())
+}
+
+fn write_json<T: Serialize + ?Sized>(
+    value: &T,
+    format: OutputFormat,
+    out: &mut impl Write,
+) -> Result<()> {
+    match format {
+        OutputFormat::Json => writeln!(out, "{}", serde_json::to_string(value)?)?,
+        OutputFormat::Pretty => writeln!(out, "{}", serde_json::to_string_pretty(value)?)?,
+        OutputFormat::Csv | OutputFormat::Table => {
+            bail!("csv and table output are not supported for this command, use json or pretty")
+        }
+    }
+    Ok(())
+}
+
+fn write_row<T: Serialize + Tabular>(
+    row: &T,
+    format: OutputFormat,
+    out: &mut impl Write,
+) -> Result<()> {
+    match format {
+        OutputFormat::Json | OutputFormat::Pretty => write_json(row, format, out),
+        OutputFormat::Csv | OutputFormat::Table => {
+            write_rows(std::slice::from_ref(row), format, out)
+        }
+    }
+}
+
+pub fn write_rows<T: Serialize + Tabular>(
+    rows: &[T],
+    format: OutputFormat,
+    out: &mut impl Write,
+) -> Result<()> {
+    match format {
+        OutputFormat::Json | OutputFormat::Pretty => write_json(rows, format, out)?,
+        OutputFormat::Csv => {
+            writeln!(out, "{}", T::COLUMNS.join(","))?;
+            for row in rows {
+                let fields: Vec<String> = row.row().iter().map(|f| csv_field(f)).collect();
+                writeln!(out, "{}", fields.join(","))?;
+            }
+        }
+        OutputFormat::Table => {
+            let header: Vec<String> = T::COLUMNS.iter().map(|c| c.to_uppercase()).collect();
+            let cells: Vec<Vec<String>> = rows
+                .iter()
+                .map(|row| row.row().iter().map(|f| table_cell(f)).collect())
+                .collect();
+
+            let mut widths: Vec<usize> = header.iter().map(|h| h.chars().count()).collect();
+            for row in &cells {
+                for (width, cell) in widths.iter_mut().zip(row) {
+                    *width = (*width).max(cell.chars().count());
+                }
+            }
+
+            for row in std::iter::once(&header).chain(&cells) {
+                let line: Vec<String> = row
+                    .iter()
+                    .zip(&widths)
+                    .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+                    .collect();
+                writeln!(out, "{}", line.join("  ").trim_end())?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Quote a CSV field when it contains a separator, quote or line break
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Flatten line breaks and truncate a table cell to [`MAX_TABLE_WIDTH`]
+fn table_cell(value: &str) -> String {
+    let value = value.replace(['\n', '\r'], " ");
+    if value.chars().count() <= MAX_TABLE_WIDTH {
+        return value;
+    }
+    let mut truncated: String = value.chars().take(MAX_TABLE_WIDTH - 1).collect();
+    truncated.push('…');
+    truncated
+}
+
+fn opt<T: ToString>(value: &Option<T>) -> String {
+    value.as_ref().map(ToString::to_string).unwrap_or_default()
+}
+
+impl Tabular for Position {
+    const COLUMNS: &'static [&'static str] = &[
+        "title",
+        "outcome",
+        "size",
+        "avg_price",
+        "cur_price",
+        "current_value",
+        "cash_pnl",
+        "percent_pnl",
+        "condition_id",
+    ];
+
+    fn row(&self) -> Vec<String> {
+        vec![
+            self.title.clone(),
+            self.outcome.clone(),
+            self.size.to_string(),
+            self.avg_price.to_string(),
+            self.cur_price.to_string(),
+            self.current_value.to_string(),
+            self.cash_pnl.to_string(),
+            self.percent_pnl.to_string(),
+            self.condition_id.clone(),
+        ]
+    }
+}
+
+impl Tabular for Trade {
+    const COLUMNS: &'static [&'static str] = &[
+        "timestamp",
+        "side",
+        "size",
+        "price",
+        "outcome",
+        "title",
+        "proxy_wallet",
+        "transaction_hash",
+    ];
+
+    fn row(&self) -> Vec<String> {
+        vec![
+            self.timestamp.to_string(),
+            self.side.to_string(),
+            self.size.to_string(),
+            self.price.to_string(),
+            self.outcome.clone(),
+            self.title.clone(),
+            self.proxy_wallet.clone(),
+            opt(&self.transaction_hash),
+        ]
+    }
+}
+
+impl Tabular for Activity {
+    const COLUMNS: &'static [&'static str] = &[
+        "timestamp",
+        "type",
+        "side",
+        "size",
+        "usdc_size",
+        "price",
+        "asset",
+        "condition_id",
+        "outcome",
+        "title",
+        "transaction_hash",
+    ];
+
+    fn row(&self) -> Vec<String> {
+        vec![
+            self.timestamp.to_string(),
+            self.activity_type.to_string(),
+            opt(&self.side),
+            self.size.to_string(),
+            self.usdc_size.to_string(),
+            opt(&self.price),
+            opt(&self.asset),
+            self.condition_id.clone(),
+            opt(&self.outcome),
+            opt(&self.title),
+            opt(&self.transaction_hash),
+        ]
+    }
+}
+
+impl Tabular for Market {
+    const COLUMNS: &'static [&'static str] = &[
+        "id",
+        "question",
+        "slug",
+        "outcome_prices",
+        "volume",
+        "liquidity",
+        "active",
+        "closed",
+        "end_date",
+    ];
+
+    fn row(&self) -> Vec<String> {
+        vec![
+            self.id.clone(),
+            self.question.clone(),
+            opt(&self.slug),
+            opt(&self.outcome_prices),
+            opt(&self.volume),
+            opt(&self.liquidity),
+            opt(&self.active),
+            opt(&self.closed),
+            opt(&self.end_date_iso),
+        ]
+    }
+}
+
+impl Tabular for Event {
+    const COLUMNS: &'static [&'static str] = &[
+        "id",
+        "title",
+        "slug",
+        "markets",
+        "volume",
+        "liquidity",
+        "active",
+        "closed",
+        "end_date",
+    ];
+
+    fn row(&self) -> Vec<String> {
+        vec![
+            self.id.clone(),
+            opt(&self.title),
+            opt(&self.slug),
+            self.markets.len().to_string(),
+            opt(&self.volume),
+            opt(&self.liquidity),
+            opt(&self.active),
+            opt(&self.closed),
+            opt(&self.end_date),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Serialize)]
+    struct Row {
+        name: &'static str,
+        value: &'static str,
+    }
+
+    impl Tabular for Row {
+        const COLUMNS: &'static [&'static str] = &["name", "value"];
+
+        fn row(&self) -> Vec<String> {
+            vec![self.name.to_string(), self.value.to_string()]
+        }
+    }
+
+    const ROWS: [Row; 2] = [
+        Row {
+            name: "a",
+            value: "Rain, or \"shine\"?",
+        },
+        Row {
+            name: "longer",
+            value: "1",
+        },
+    ];
+
+    fn render(format: OutputFormat) -> String {
+        let mut out = Vec::new();
+        write_rows(&ROWS, format, &mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_csv_quotes_fields() {
+        assert_eq!(
+            render(OutputFormat::Csv),
+            "name,value\na,\"Rain, or \"\"shine\"\"?\"\nlonger,1\n"
+        );
+    }
+
+    #[test]
+    fn test_table_aligns_columns() {
+        assert_eq!(
+            render(OutputFormat::Table),
+            "NAME    VALUE\na       Rain, or \"shine\"?\nlonger  1\n"
+        );
+        assert_eq!(table_cell(&"x".repeat(60)).chars().count(), MAX_TABLE_WIDTH);
+    }
+
+    #[test]
+    fn test_json_formats() {
+        assert_eq!(
+            render(OutputFormat::Json),
+            "[{\"name\":\"a\",\"value\":\"Rain, or \\\"shine\\\"?\"},{\"name\":\"longer\",\"value\":\"1\"}]\n"
+        );
+        assert!(write_json(&1, OutputFormat::Csv, &mut Vec::new()).is_err());
+        assert_eq!(
+            OutputFormat::from_str("summary", true),
+            Ok(OutputFormat::Table)
+        );
+    }
+
+    #[test]
+    fn test_single_row_json_is_not_wrapped() {
+        let mut out = Vec::new();
+        write_row(&ROWS[1], OutputFormat::Json, &mut out).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "{\"name\":\"longer\",\"value\":\"1\"}\n"
+        );
+
+        let mut out = Vec::new();
+        write_row(&ROWS[1], OutputFormat::Csv, &mut out).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), "name,value\nlonger,1\n");
+    }
+}
@@ -24,6 +24,10 @@ pub fn parse_duration(s: &str) -> Result<Duration, String> {
         (n, "m")
     } else if let Some(n) = s.strip_suffix('h') {
         (n, "h")
+    } else if let Some(n) = s.strip_suffix('d') {
+        (n, "d")
+    } else if let Some(n) = s.strip_suffix('w') {
+        (n, "w")
     } else {
         // Default to seconds if no unit
         (s, "s")
@@ -38,6 +42,8 @@ pub fn parse_duration(s: &str) -> Result<Duration, String> {
         "s" => Ok(Duration::from_secs(num)),
         "m" => Ok(Duration::from_secs(num * 60)),
         "h" => Ok(Duration::from_secs(num * 3600)),
+        "d" => Ok(Duration::from_secs(num * 86_400)),
+        "w" => Ok(Duration::from_secs(num * 7 * 86_400)),
         _ => Err(format!("unknown unit: {}", unit)),
     }
 }
@@ -1,9 +1,16 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
 use clap::{Args, ValueEnum};
 use color_eyre::eyre::Result;
-use polyte_data::{types::ActivityType, DataApi};
+use futures_util::TryStreamExt;
+use polyte_data::{
+    types::{Activity, ActivityType},
+    DataApi,
+};
 
 use super::SortOrder;
-use crate::commands::common::parsing::parse_comma_separated;
+use crate::commands::common::output::{print_rows, OutputFormat};
+use crate::commands::common::parsing::{parse_comma_separated, parse_duration};
 use crate::commands::data::trades::TradeSideFilter;
 
 #[derive(Args)]
@@ -17,19 +24,29 @@ pub struct UserActivityCommand {
     /// Filter by event IDs (comma-separated)
     #[arg(short, long, value_parser = parse_comma_separated)]
     event_id: Option<Vec<String>>,
-    /// Filter by activity types (comma-separated: trade, split, merge, redeem, reward, conversion)
-    #[arg(short = 'T', long)]
-    activity_type: Option<String>,
+    /// Filter by activity types (comma-separated)
+    #[arg(
+        short = 'T',
+        long,
+        visible_alias = "type",
+        value_enum,
+        value_delimiter = ','
+    )]
+    activity_type: Vec<ActivityTypeFilter>,
     /// Filter by trade side
     #[arg(short, long, value_enum)]
     side: Option<TradeSideFilter>,
     /// Start timestamp filter
-    #[arg(long)]
+    #[arg(long, conflicts_with = "since")]
     start: Option<i64>,
+    /// Only activity within this long before now (e.g. 30d, 12h), fetching
+    /// every page in that window
+    #[arg(long, value_parser = parse_duration)]
+    since: Option<Duration>,
     /// End timestamp filter
     #[arg(long)]
     end: Option<i64>,
-    /// Maximum number of results (0-10000, default: 100)
+    /// Maximum number of results, or page size with --since (0-10000, default: 100)
     #[arg(short, long, default_value = "100")]
     limit: u32,
     /// Pagination offset (0-10000, default: 0)
@@ -44,7 +61,13 @@ pub struct UserActivityCommand {
 }
 
 impl UserActivityCommand {
-    pub async fn run(self, data: &DataApi) -> Result<()> {
+    pub async fn run(self, data: &DataApi, format: OutputFormat) -> Result<()> {
+        let activity = self.fetch(data, unix_now()).await?;
+        print_rows(&activity, format)
+    }
+
+    /// Query activity, walking every page when `--since` is set
+    async fn fetch(self, data: &DataApi, now: i64) -> Result<Vec<Activity>> {
         let positions_api = data.positions(&self.user);
 
         let mut request = positions_api
@@ -62,23 +85,7 @@ impl UserActivityCommand {
             let ids: Vec<&str> = ids.iter().map(|s| s.as_str()).collect();
             request = request.event_id(ids);
         }
-        if let Some(types) = self.activity_type {
-            let activity_types: Vec<ActivityType> = types
-                .split(',')
-                .filter_map(|s| match s.trim().to_uppercase().as_str() {
-                    "TRADE" => Some(ActivityType::Trade),
-                    "SPLIT" => Some(ActivityType::Split),
-                    "MERGE" => Some(ActivityType::Merge),
-                    "REDEEM" => Some(ActivityType::Redeem),
-                    "REWARD" => Some(ActivityType::Reward),
-                    "CONVERSION" => Some(ActivityType::Conversion),
-                    _ => None,
-                })
-                .collect();
-            if !activity_types.is_empty() {
-                request = request.activity_type(activity_types);
-            }
-        }
+        request = request.activity_type(self.activity_type.into_iter().map(Into::into));
         if let Some(s) = self.side {
             request = request.side(s.into());
         }
@@ -89,9 +96,50 @@ impl UserActivityCommand {
             request = request.end(ts);
         }
 
-        let activity = request.send().await?;
-        println!("{}", serde_json::to_string_pretty(&activity)?);
-        Ok(())
+        match self.since {
+            Some(since) => {
+                let start = now.saturating_sub(i64::try_from(since.as_secs()).unwrap_or(i64::MAX));
+                Ok(request.start(start).stream().try_collect().await?)
+            }
+            None => Ok(request.send().await?),
+        }
+    }
+}
+
+/// Current Unix timestamp in seconds
+fn unix_now() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs() as i64)
+}
+
+/// Activity type filter
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ActivityTypeFilter {
+    /// Trade activity
+    Trade,
+    /// Split activity
+    Split,
+    /// Merge activity
+    Merge,
+    /// Redeem activity
+    Redeem,
+    /// Reward activity
+    Reward,
+    /// Conversion activity
+    Conversion,
+}
+
+impl From<ActivityTypeFilter> for ActivityType {
+    fn from(activity_type: ActivityTypeFilter) -> Self {
+        match activity_type {
+            ActivityTypeFilter::Trade => Self::Trade,
+            ActivityTypeFilter::Split => Self::Split,
+            ActivityTypeFilter::Merge => Self::Merge,
+            ActivityTypeFilter::Redeem => Self::Redeem,
+            ActivityTypeFilter::Reward => Self::Reward,
+            ActivityTypeFilter::Conversion => Self::Conversion,
+        }
     }
 }
 
@@ -184,3 +232,104 @@ impl From<ActivitySortField> for polyte_data::types::ActivitySortBy {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use clap::Parser;
+    use polyte_core::testing::{MockResponse, MockTransport};
+
+    use super::*;
+    use crate::commands::common::output::{write_rows, Tabular};
+
+    #[derive(Parser)]
+    struct Cli {
+        #[command(flatten)]
+        activity: UserActivityCommand,
+    }
+
+    /// Serve three trades paginated by `limit`/`offset`
+    fn activity() -> Arc<MockTransport> {
+        let records: Vec<serde_json::Value> = (0..3)
+            .map(|i| {
+                serde_json::json!({
+                    "proxyWallet": "0x1",
+                    "timestamp": 1_699_999_000 - i,
+                    "conditionId": "0xabc",
+                    "type": "TRADE",
+                    "size": 10.0,
+                    "usdcSize": 5.5,
+                    "transactionHash": format!("0x{}", i),
+                    "price": 0.55,
+                    "asset": "123",
+                    "side": "BUY",
+                    "outcome": "Yes",
+                    "title": if i == 0 { "Rain, or shine?" } else { "Plain" },
+                })
+            })
+            .collect();
+
+        MockTransport::new(move |request| {
+            let param = |key: &str| -> usize {
+                request.query(key).and_then(|v| v.parse().ok()).unwrap_or(0)
+            };
+            let page: Vec<_> = records
+                .iter()
+                .skip(param("offset"))
+                .take(param("limit"))
+                .collect();
+            MockResponse::json(&page)
+        })
+    }
+
+    #[tokio::test]
+    async fn test_since_type_csv_workflow() {
+        let transport = activity();
+        let data = DataApi::builder()
+            .transport(transport.clone())
+            .build()
+            .unwrap();
+        let now = 1_700_000_000;
+
+        let cli = Cli::try_parse_from([
+            "polyte", "--user", "0x1", "--since", "30d", "--type", "trade", "--limit", "2",
+        ])
+        .unwrap();
+        let activity = cli.activity.fetch(&data, now).await.unwrap();
+
+        let mut out = Vec::new();
+        write_rows(&activity, OutputFormat::Csv, &mut out).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            [
+                Activity::COLUMNS.join(",").as_str(),
+                "1699999000,TRADE,BUY,10,5.5,0.55,123,0xabc,Yes,\"Rain, or shine?\",0x0",
+                "1699998999,TRADE,BUY,10,5.5,0.55,123,0xabc,Yes,Plain,0x1",
+                "1699998998,TRADE,BUY,10,5.5,0.55,123,0xabc,Yes,Plain,0x2",
+                "",
+            ]
+            .join("\n")
+        );
+
+        // Both pages carry the window start and the typed filter
+        let start = (now - 30 * 86_400).to_string();
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 2);
+        for (request, offset) in requests.iter().zip(["0", "2"]) {
+            assert_eq!(request.query("start"), Some(start.clone()));
+            assert_eq!(request.query("type").as_deref(), Some("TRADE"));
+            assert_eq!(request.query("offset").as_deref(), Some(offset));
+        }
+    }
+
+    #[test]
+    fn test_activity_type_list() {
+        let cli = Cli::try_parse_from(["polyte", "--user", "0x1", "-T", "trade,redeem"]).unwrap();
+        assert_eq!(
+            cli.activity.activity_type,
+            [ActivityTypeFilter::Trade, ActivityTypeFilter::Redeem]
+        );
+        assert!(Cli::try_parse_from(["polyte", "--user", "0x1", "--type", "swap"]).is_err());
+    }
+}
@@ -2,6 +2,8 @@ use clap::{Args, Subcommand, ValueEnum};
 use color_eyre::eyre::Result;
 use polyte_data::{api::builders::TimePeriod, DataApi};
 
+use crate::commands::common::output::{print, OutputFormat};
+
 #[derive(Subcommand)]
 pub enum BuildersCommand {
     /// Get aggregated builder leaderboard
@@ -11,10 +13,10 @@ pub enum BuildersCommand {
 }
 
 impl BuildersCommand {
-    pub async fn run(self, data: &DataApi) -> Result<()> {
+    pub async fn run(self, data: &DataApi, format: OutputFormat) -> Result<()> {
         match self {
-            Self::Leaderboard(cmd) => cmd.run(data).await,
-            Self::Volume(cmd) => cmd.run(data).await,
+            Self::Leaderboard(cmd) => cmd.run(data, format).await,
+            Self::Volume(cmd) => cmd.run(data, format).await,
         }
     }
 }
@@ -34,7 +36,7 @@ pub struct LeaderboardCommand {
 }
 
 impl LeaderboardCommand {
-    pub async fn run(self, data: &DataApi) -> Result<()> {
+    pub async fn run(self, data: &DataApi, format: OutputFormat) -> Result<()> {
         let rankings = data
             .builders()
             .leaderboard()
@@ -43,7 +45,7 @@ impl LeaderboardCommand {
             .offset(self.offset)
             .send()
             .await?;
-        println!("{}", serde_json::to_string_pretty(&rankings)?);
+        print(&rankings, format)?;
         Ok(())
     }
 }
@@ -57,14 +59,14 @@ pub struct VolumeCommand {
 }
 
 impl VolumeCommand {
-    pub async fn run(self, data: &DataApi) -> Result<()> {
+    pub async fn run(self, data: &DataApi, format: OutputFormat) -> Result<()> {
         let volumes = data
             .builders()
             .volume()
             .time_period(self.time_period.into())
             .send()
             .await?;
-        println!("{}", serde_json::to_string_pretty(&volumes)?);
+        print(&volumes, format)?;
         Ok(())
     }
 }
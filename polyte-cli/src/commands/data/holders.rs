@@ -2,6 +2,7 @@ use clap::Args;
 use color_eyre::eyre::Result;
 use polyte_data::DataApi;
 
+use crate::commands::common::output::{print, OutputFormat};
 use crate::commands::common::parsing::parse_comma_separated;
 
 /// Get top holders for markets
@@ -19,7 +20,7 @@ pub struct HoldersCommand {
 }
 
 impl HoldersCommand {
-    pub async fn run(self, data: &DataApi) -> Result<()> {
+    pub async fn run(self, data: &DataApi, format: OutputFormat) -> Result<()> {
         let ids: Vec<&str> = self.market.iter().map(|s| s.as_str()).collect();
         let request = data
             .holders()
@@ -28,7 +29,7 @@ impl HoldersCommand {
             .min_balance(self.min_balance);
 
         let holders = request.send().await?;
-        println!("{}", serde_json::to_string_pretty(&holders)?);
+        print(&holders, format)?;
         Ok(())
     }
 }
@@ -2,6 +2,8 @@ use clap::Args;
 use color_eyre::eyre::Result;
 use polyte_data::DataApi;
 
+use crate::commands::common::output::{print, OutputFormat};
+
 #[derive(Args)]
 pub struct LiveVolumeCommand {
     /// Event ID (must be >= 1)
@@ -10,9 +12,9 @@ pub struct LiveVolumeCommand {
 }
 
 impl LiveVolumeCommand {
-    pub async fn run(self, data: &DataApi) -> Result<()> {
+    pub async fn run(self, data: &DataApi, format: OutputFormat) -> Result<()> {
         let volume = data.live_volume().get(self.event_id).await?;
-        println!("{}", serde_json::to_string_pretty(&volume)?);
+        print(&volume, format)?;
         Ok(())
     }
 }
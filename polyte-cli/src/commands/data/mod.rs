@@ -11,6 +11,7 @@ use clap::{Subcommand, ValueEnum};
 use color_eyre::eyre::Result;
 use polyte_data::DataApi;
 
+use crate::commands::common::output::{print, OutputFormat};
 use crate::commands::data::{
     activity::UserActivityCommand, holders::HoldersCommand, live_volume::LiveVolumeCommand,
     open_interest::OpenInterestCommand, positions::PositionsCommand, traded::TradedCommand,
@@ -45,23 +46,23 @@ pub enum DataCommand {
 }
 
 impl DataCommand {
-    pub async fn run(self) -> Result<()> {
+    pub async fn run(self, format: OutputFormat) -> Result<()> {
         let data = DataApi::new()?;
 
         match self {
             Self::Health => {
                 let health = data.health().check().await?;
-                println!("{}", serde_json::to_string_pretty(&health)?);
+                print(&health, format)?;
                 Ok(())
             }
-            Self::Activity(cmd) => cmd.run(&data).await,
-            Self::Builders { command } => command.run(&data).await,
-            Self::Holders(cmd) => cmd.run(&data).await,
-            Self::Trades { command } => command.run(&data).await,
-            Self::Traded(cmd) => cmd.run(&data).await,
-            Self::Positions(cmd) => cmd.run(&data).await,
-            Self::OpenInterest(cmd) => cmd.run(&data).await,
-            Self::LiveVolume(cmd) => cmd.run(&data).await,
+            Self::Activity(cmd) => cmd.run(&data, format).await,
+            Self::Builders { command } => command.run(&data, format).await,
+            Self::Holders(cmd) => cmd.run(&data, format).await,
+            Self::Trades { command } => command.run(&data, format).await,
+            Self::Traded(cmd) => cmd.run(&data, format).await,
+            Self::Positions(cmd) => cmd.run(&data, format).await,
+            Self::OpenInterest(cmd) => cmd.run(&data, format).await,
+            Self::LiveVolume(cmd) => cmd.run(&data, format).await,
         }
     }
 }
@@ -3,6 +3,7 @@ use color_eyre::eyre::Result;
 use polyte_data::DataApi;
 
 use super::SortOrder;
+use crate::commands::common::output::{print, print_rows, OutputFormat};
 use crate::commands::common::parsing::parse_comma_separated;
 use crate::commands::data::trades::TradeSideFilter;
 
@@ -117,7 +118,7 @@ pub enum PositionsSubcommand {
 }
 
 impl PositionsCommand {
-    pub async fn run(self, data: &DataApi) -> Result<()> {
+    pub async fn run(self, data: &DataApi, format: OutputFormat) -> Result<()> {
         let positions_api = data.positions(&self.user);
 
         match self.command {
@@ -162,7 +163,7 @@ impl PositionsCommand {
                 }
 
                 let positions = request.send().await?;
-                println!("{}", serde_json::to_string_pretty(&positions)?);
+                print_rows(&positions, format)?;
             }
             PositionsSubcommand::Value { market } => {
                 let mut request = positions_api.positions_value();
@@ -171,7 +172,7 @@ impl PositionsCommand {
                     request = request.market(ids);
                 }
                 let value = request.send().await?;
-                println!("{}", serde_json::to_string_pretty(&value)?);
+                print(&value, format)?;
             }
             PositionsSubcommand::Closed {
                 market,
@@ -202,7 +203,7 @@ impl PositionsCommand {
                 }
 
                 let positions = request.send().await?;
-                println!("{}", serde_json::to_string_pretty(&positions)?);
+                print(&positions, format)?;
             }
             PositionsSubcommand::Activity {
                 market,
@@ -259,7 +260,7 @@ impl PositionsCommand {
                 }
 
                 let activity = request.send().await?;
-                println!("{}", serde_json::to_string_pretty(&activity)?);
+                print_rows(&activity, format)?;
             }
         }
         Ok(())
@@ -2,6 +2,8 @@ use clap::Args;
 use color_eyre::eyre::Result;
 use polyte_data::DataApi;
 
+use crate::commands::common::output::{print, OutputFormat};
+
 #[derive(Args)]
 pub struct TradedCommand {
     /// User address (0x-prefixed, 40 hex chars)
@@ -10,9 +12,9 @@ pub struct TradedCommand {
 }
 
 impl TradedCommand {
-    pub async fn run(self, data: &DataApi) -> Result<()> {
+    pub async fn run(self, data: &DataApi, format: OutputFormat) -> Result<()> {
         let result = data.traded(&self.user).get().await?;
-        println!("{}", serde_json::to_string_pretty(&result)?);
+        print(&result, format)?;
         Ok(())
     }
 }
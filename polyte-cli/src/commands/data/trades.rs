@@ -2,6 +2,7 @@ use clap::{Subcommand, ValueEnum};
 use color_eyre::eyre::Result;
 use polyte_data::DataApi;
 
+use crate::commands::common::output::{print_rows, OutputFormat};
 use crate::commands::common::parsing::parse_comma_separated;
 
 #[derive(Subcommand)]
@@ -39,7 +40,7 @@ pub enum TradesCommand {
 }
 
 impl TradesCommand {
-    pub async fn run(self, data: &DataApi) -> Result<()> {
+    pub async fn run(self, data: &DataApi, format: OutputFormat) -> Result<()> {
         match self {
             Self::List {
                 user,
@@ -108,7 +109,7 @@ impl TradesCommand {
                     request.send().await?
                 };
 
-                println!("{}", serde_json::to_string_pretty(&trades)?);
+                print_rows(&trades, format)?;
             }
         }
         Ok(())
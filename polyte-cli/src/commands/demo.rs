@@ -0,0 +1,176 @@
+use std::io::{self, BufRead, Write};
+
+use clap::Args;
+use color_eyre::eyre::{eyre, Result};
+use polyte_clob::{Account, Clob, CreateOrderParams, OrderSide};
+use polyte_gamma::{types::Market, Gamma};
+
+/// Default order size when the market doesn't report a minimum
+const DEFAULT_ORDER_SIZE: f64 = 5.0;
+/// Default tick size when the market doesn't report one
+const DEFAULT_TICK_SIZE: f64 = 0.01;
+
+/// Walk through the full trading loop: discover, inspect, place and cancel.
+///
+/// Credentials are read from the `POLYMARKET_*` environment variables. The
+/// order is the smallest size allowed, priced at the lowest tick so it rests
+/// far from the market, and is cancelled right after being placed.
+#[derive(Args, Debug)]
+pub struct DemoCommand {
+    /// Market slug to trade (defaults to the highest 24h volume active market)
+    #[arg(long)]
+    slug: Option<String>,
+
+    /// Sign the order but stop before posting it
+    #[arg(long)]
+    dry_run: bool,
+
+    /// Skip the confirmation prompt
+    #[arg(short, long)]
+    yes: bool,
+}
+
+impl DemoCommand {
+    pub async fn run(self) -> Result<()> {
+        // 1. Discover a market via Gamma
+        let gamma = Gamma::new()?;
+        let market = match &self.slug {
+            Some(slug) => gamma.markets().get_by_slug(slug).send().await?,
+            None => gamma
+                .markets()
+                .list()
+                .active(true)
+                .order("volume24hr")
+                .ascending(false)
+                .limit(1)
+                .send()
+                .await?
+                .into_iter()
+                .next()
+                .ok_or_else(|| eyre!("No active market found"))?,
+        };
+        let token_id = first_token_id(&market)?;
+
+        println!("[1/4] Market: {}", market.question);
+        println!("      Token:  {}", token_id);
+
+        // 2. Show the order book via CLOB
+        let clob = Clob::from_account(Account::from_env()?)?;
+        let book = clob.markets().order_book(&token_id).send().await?;
+        let best_bid = book.best_bid().map(|price| price.to_string());
+        let best_ask = book.best_ask().map(|price| price.to_string());
+
+        println!(
+            "[2/4] Book:   {} bids, {} asks (best bid {}, best ask {})",
+            book.bids.len(),
+            book.asks.len(),
+            best_bid.as_deref().unwrap_or("-"),
+            best_ask.as_deref().unwrap_or("-"),
+        );
+
+        // 3. Build and sign a tiny order that rests far from the market
+        let params = CreateOrderParams::new(
+            token_id,
+            market
+                .order_price_min_tick_size
+                .unwrap_or(DEFAULT_TICK_SIZE),
+            market.order_min_size.unwrap_or(DEFAULT_ORDER_SIZE),
+            OrderSide::Buy,
+        );
+        let order = clob.create_order(&params).await?;
+        let signed_order = clob.sign_order(&order).await?;
+
+        println!(
+            "[3/4] Order:  BUY {} @ {} (signed)",
+            params.size, params.price
+        );
+
+        if self.dry_run {
+            println!("{}", serde_json::to_string_pretty(&signed_order)?);
+            println!("Dry run: stopping before posting the order");
+            return Ok(());
+        }
+
+        if !self.yes && !confirm("Post this order and cancel it right away?")? {
+            println!("Aborted");
+            return Ok(());
+        }
+
+        // 4. Place then cancel the order
+        let response = clob.post_order(&signed_order).await?;
+        let order_id = match (response.success, response.order_id) {
+            (true, Some(order_id)) => order_id,
+            _ => {
+                return Err(eyre!(
+                    "Order rejected: {}",
+                    response.error_msg.unwrap_or_default()
+                ))
+            }
+        };
+        let cancel = clob.orders().cancel(&order_id).send().await?;
+
+        println!(
+            "[4/4] Placed {} and {}",
+            order_id,
+            if cancel.success || cancel.canceled.contains(&order_id) {
+                "cancelled it"
+            } else {
+                "failed to cancel it"
+            }
+        );
+
+        Ok(())
+    }
+}
+
+/// Extract the first CLOB token ID from a Gamma market
+fn first_token_id(market: &Market) -> Result<String> {
+    let ids = market
+        .clob_token_ids
+        .as_deref()
+        .ok_or_else(|| eyre!("Market {} has no CLOB tokens", market.id))?;
+    let ids: Vec<String> = serde_json::from_str(ids)?;
+
+    ids.into_iter()
+        .next()
+        .ok_or_else(|| eyre!("Market {} has no CLOB tokens", market.id))
+}
+
+/// Ask the user for a yes/no confirmation on stdin
+fn confirm(prompt: &str) -> Result<bool> {
+    print!("{} [y/N] ", prompt);
+    io::stdout().flush()?;
+
+    let mut answer = String::new();
+    io::stdin().lock().read_line(&mut answer)?;
+
+    Ok(matches!(answer.trim(), "y" | "Y" | "yes"))
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+
+    use super::*;
+
+    #[derive(Parser)]
+    struct Cli {
+        #[command(flatten)]
+        demo: DemoCommand,
+    }
+
+    #[test]
+    fn test_parse_defaults() {
+        let cli = Cli::try_parse_from(["polyte"]).unwrap();
+        assert_eq!(cli.demo.slug, None);
+        assert!(!cli.demo.dry_run);
+        assert!(!cli.demo.yes);
+    }
+
+    #[test]
+    fn test_parse_dry_run() {
+        let cli = Cli::try_parse_from(["polyte", "--slug", "some-market", "--dry-run"]).unwrap();
+        assert_eq!(cli.demo.slug.as_deref(), Some("some-market"));
+        assert!(cli.demo.dry_run);
+    }
+}
@@ -2,6 +2,7 @@ use clap::{Subcommand, ValueEnum};
 use color_eyre::eyre::Result;
 use polyte_gamma::Gamma;
 
+use crate::commands::common::output::{print, OutputFormat};
 use crate::commands::gamma::SortOrder;
 
 /// Parent entity type for comments
@@ -57,7 +58,7 @@ pub enum CommentsCommand {
 }
 
 impl CommentsCommand {
-    pub async fn run(self, gamma: &Gamma) -> Result<()> {
+    pub async fn run(self, gamma: &Gamma, format: OutputFormat) -> Result<()> {
         match self {
             Self::List {
                 limit,
@@ -93,7 +94,7 @@ impl CommentsCommand {
                 }
 
                 let comments = request.send().await?;
-                println!("{}", serde_json::to_string_pretty(&comments)?);
+                print(&comments, format)?;
             }
         }
         Ok(())
@@ -2,6 +2,7 @@ use clap::{ArgAction, Subcommand, ValueEnum};
 use color_eyre::eyre::Result;
 use polyte_gamma::Gamma;
 
+use crate::commands::common::output::{print_row, print_rows, OutputFormat};
 use crate::commands::gamma::SortOrder;
 
 /// Event status filter
@@ -75,7 +76,7 @@ pub enum EventsCommand {
 }
 
 impl EventsCommand {
-    pub async fn run(self, gamma: &Gamma) -> Result<()> {
+    pub async fn run(self, gamma: &Gamma, format: OutputFormat) -> Result<()> {
         match self {
             Self::List {
                 limit,
@@ -130,19 +131,19 @@ impl EventsCommand {
                 }
 
                 let events = request.send().await?;
-                println!("{}", serde_json::to_string_pretty(&events)?);
+                print_rows(&events, format)?;
             }
             Self::Get { id } => {
                 let event = gamma.events().get(&id).send().await?;
-                println!("{}", serde_json::to_string_pretty(&event)?);
+                print_row(&event, format)?;
             }
             Self::GetBySlug { slug } => {
                 let event = gamma.events().get_by_slug(&slug).send().await?;
-                println!("{}", serde_json::to_string_pretty(&event)?);
+                print_row(&event, format)?;
             }
             Self::Related { slug } => {
                 let events = gamma.events().get_related_by_slug(&slug).send().await?;
-                println!("{}", serde_json::to_string_pretty(&events)?);
+                print_rows(&events, format)?;
             }
         }
         Ok(())
@@ -2,6 +2,7 @@ use clap::{Subcommand, ValueEnum};
 use color_eyre::eyre::Result;
 use polyte_gamma::Gamma;
 
+use crate::commands::common::output::{print_row, print_rows, OutputFormat};
 use crate::commands::gamma::SortOrder;
 
 /// Market status filter
@@ -19,6 +20,8 @@ pub enum MarketStatus {
 /// Preset filters for common market queries
 #[derive(Debug, Clone, Copy, ValueEnum)]
 pub enum MarketPreset {
+    /// Open markets that can currently be traded
+    Tradeable,
     /// Active markets with high volume (>$100k) sorted by 24h volume
     Trending,
     /// Active markets sorted by total volume (descending)
@@ -31,6 +34,19 @@ pub enum MarketPreset {
     Competitive,
 }
 
+impl From<MarketPreset> for polyte_gamma::api::markets::MarketPreset {
+    fn from(preset: MarketPreset) -> Self {
+        match preset {
+            MarketPreset::Tradeable => Self::Tradeable,
+            MarketPreset::Trending => Self::Trending,
+            MarketPreset::TopVolume => Self::TopVolume,
+            MarketPreset::HighLiquidity => Self::HighLiquidity,
+            MarketPreset::New => Self::New,
+            MarketPreset::Competitive => Self::Competitive,
+        }
+    }
+}
+
 #[derive(Subcommand)]
 pub enum MarketsCommand {
     /// List markets
@@ -82,7 +98,7 @@ pub enum MarketsCommand {
 }
 
 impl MarketsCommand {
-    pub async fn run(self, gamma: &Gamma) -> Result<()> {
+    pub async fn run(self, gamma: &Gamma, format: OutputFormat) -> Result<()> {
         match self {
             Self::List {
                 preset,
@@ -100,28 +116,9 @@ impl MarketsCommand {
                 let mut request = gamma.markets().list();
 
                 // Apply preset filters first (can be overridden by explicit flags)
-                request = match preset {
-                    Some(MarketPreset::Trending) => request
-                        .active(true)
-                        .volume_num_min(100_000.0)
-                        .order("volume24hr")
-                        .ascending(false),
-                    Some(MarketPreset::TopVolume) => {
-                        request.active(true).order("volume").ascending(false)
-                    }
-                    Some(MarketPreset::HighLiquidity) => request
-                        .active(true)
-                        .liquidity_num_min(50_000.0)
-                        .order("liquidity")
-                        .ascending(false),
-                    Some(MarketPreset::New) => {
-                        request.active(true).order("startDate").ascending(false)
-                    }
-                    Some(MarketPreset::Competitive) => {
-                        request.active(true).order("competitive").ascending(false)
-                    }
-                    None => request,
-                };
+                if let Some(preset) = preset {
+                    request = request.preset(preset.into());
+                }
 
                 // Apply explicit overrides (these take precedence over presets)
                 request = request.limit(limit).offset(offset).active(active);
@@ -154,15 +151,15 @@ impl MarketsCommand {
                 }
 
                 let markets = request.send().await?;
-                println!("{}", serde_json::to_string_pretty(&markets)?);
+                print_rows(&markets, format)?;
             }
             Self::Get { id } => {
                 let market = gamma.markets().get(&id).send().await?;
-                println!("{}", serde_json::to_string_pretty(&market)?);
+                print_row(&market, format)?;
             }
             Self::GetBySlug { slug } => {
                 let market = gamma.markets().get_by_slug(&slug).send().await?;
-                println!("{}", serde_json::to_string_pretty(&market)?);
+                print_row(&market, format)?;
             }
         }
         Ok(())
@@ -9,6 +9,8 @@ use clap::{Subcommand, ValueEnum};
 use color_eyre::eyre::Result;
 use polyte_gamma::Gamma;
 
+use crate::commands::common::output::OutputFormat;
+
 #[derive(Subcommand)]
 pub enum GammaCommand {
     /// Query markets
@@ -44,16 +46,16 @@ pub enum GammaCommand {
 }
 
 impl GammaCommand {
-    pub async fn run(self) -> Result<()> {
+    pub async fn run(self, format: OutputFormat) -> Result<()> {
         let gamma = Gamma::new()?;
 
         match self {
-            Self::Markets { command } => command.run(&gamma).await,
-            Self::Events { command } => command.run(&gamma).await,
-            Self::Tags { command } => command.run(&gamma).await,
-            Self::Series { command } => command.run(&gamma).await,
-            Self::Sports { command } => command.run(&gamma).await,
-            Self::Comments { command } => command.run(&gamma).await,
+            Self::Markets { command } => command.run(&gamma, format).await,
+            Self::Events { command } => command.run(&gamma, format).await,
+            Self::Tags { command } => command.run(&gamma, format).await,
+            Self::Series { command } => command.run(&gamma, format).await,
+            Self::Sports { command } => command.run(&gamma, format).await,
+            Self::Comments { command } => command.run(&gamma, format).await,
         }
     }
 }
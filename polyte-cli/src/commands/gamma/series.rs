@@ -2,6 +2,7 @@ use clap::{Subcommand, ValueEnum};
 use color_eyre::eyre::Result;
 use polyte_gamma::Gamma;
 
+use crate::commands::common::output::{print, OutputFormat};
 use crate::commands::gamma::SortOrder;
 
 /// Series status filter
@@ -39,7 +40,7 @@ pub enum SeriesCommand {
 }
 
 impl SeriesCommand {
-    pub async fn run(self, gamma: &Gamma) -> Result<()> {
+    pub async fn run(self, gamma: &Gamma, format: OutputFormat) -> Result<()> {
         match self {
             Self::List {
                 limit,
@@ -56,11 +57,11 @@ impl SeriesCommand {
                     .closed(matches!(status, SeriesStatus::Closed));
 
                 let series = request.send().await?;
-                println!("{}", serde_json::to_string_pretty(&series)?);
+                print(&series, format)?;
             }
             Self::Get { id } => {
                 let series = gamma.series().get(&id).send().await?;
-                println!("{}", serde_json::to_string_pretty(&series)?);
+                print(&series, format)?;
             }
         }
         Ok(())
@@ -2,6 +2,7 @@ use clap::Subcommand;
 use color_eyre::eyre::Result;
 use polyte_gamma::Gamma;
 
+use crate::commands::common::output::{print, OutputFormat};
 use crate::commands::gamma::SortOrder;
 
 #[derive(Subcommand)]
@@ -29,11 +30,11 @@ pub enum SportsCommand {
 }
 
 impl SportsCommand {
-    pub async fn run(self, gamma: &Gamma) -> Result<()> {
+    pub async fn run(self, gamma: &Gamma, format: OutputFormat) -> Result<()> {
         match self {
             Self::List => {
                 let sports = gamma.sports().list().send().await?;
-                println!("{}", serde_json::to_string_pretty(&sports)?);
+                print(&sports, format)?;
             }
             Self::Teams {
                 limit,
@@ -57,7 +58,7 @@ impl SportsCommand {
                 }
 
                 let teams = request.send().await?;
-                println!("{}", serde_json::to_string_pretty(&teams)?);
+                print(&teams, format)?;
             }
         }
         Ok(())
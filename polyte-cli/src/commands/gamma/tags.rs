@@ -2,6 +2,7 @@ use clap::Subcommand;
 use color_eyre::eyre::Result;
 use polyte_gamma::Gamma;
 
+use crate::commands::common::output::{print, OutputFormat};
 use crate::commands::gamma::SortOrder;
 
 #[derive(Subcommand)]
@@ -47,7 +48,7 @@ pub enum TagsCommand {
 }
 
 impl TagsCommand {
-    pub async fn run(self, gamma: &Gamma) -> Result<()> {
+    pub async fn run(self, gamma: &Gamma, format: OutputFormat) -> Result<()> {
         match self {
             Self::List {
                 limit,
@@ -71,23 +72,23 @@ impl TagsCommand {
                 }
 
                 let tags = request.send().await?;
-                println!("{}", serde_json::to_string_pretty(&tags)?);
+                print(&tags, format)?;
             }
             Self::Get { id } => {
                 let tag = gamma.tags().get(&id).send().await?;
-                println!("{}", serde_json::to_string_pretty(&tag)?);
+                print(&tag, format)?;
             }
             Self::GetBySlug { slug } => {
                 let tag = gamma.tags().get_by_slug(&slug).send().await?;
-                println!("{}", serde_json::to_string_pretty(&tag)?);
+                print(&tag, format)?;
             }
             Self::Related { id } => {
                 let tags = gamma.tags().get_related(&id).send().await?;
-                println!("{}", serde_json::to_string_pretty(&tags)?);
+                print(&tags, format)?;
             }
             Self::RelatedBySlug { slug } => {
                 let tags = gamma.tags().get_related_by_slug(&slug).send().await?;
-                println!("{}", serde_json::to_string_pretty(&tags)?);
+                print(&tags, format)?;
             }
         }
         Ok(())
@@ -1,11 +1,18 @@
 mod common;
 
+pub mod clob;
 pub mod completions;
 pub mod data;
+#[cfg(feature = "demo")]
+pub mod demo;
 pub mod gamma;
 pub mod ws;
 
+pub use clob::ClobCommand;
+pub use common::output::OutputFormat;
 pub use completions::CompletionsCommand;
 pub use data::DataCommand;
+#[cfg(feature = "demo")]
+pub use demo::DemoCommand;
 pub use gamma::GammaCommand;
 pub use ws::WsCommand;
@@ -7,10 +7,12 @@ use std::{
 };
 
 use clap::Args;
-use color_eyre::eyre::Result;
+use color_eyre::eyre::{bail, Result};
 use futures_util::StreamExt;
 use polyte_clob::ws::{Channel, MarketMessage, WebSocket};
 
+use super::CSV_UNSUPPORTED;
+use crate::commands::common::output::OutputFormat;
 use crate::commands::common::parsing::parse_duration;
 
 /// Market event types to filter
@@ -32,10 +34,6 @@ pub struct MarketArgs {
     #[arg(required = true)]
     asset_ids: Vec<String>,
 
-    /// Output format
-    #[arg(short, long, value_enum, default_value = "pretty")]
-    format: OutputFormat,
-
     /// Filter by event type (can be specified multiple times)
     #[arg(long, value_enum)]
     filter: Vec<MarketEventType>,
@@ -49,18 +47,7 @@ pub struct MarketArgs {
     timeout: Option<Duration>,
 }
 
-#[derive(Debug, Clone, Copy, clap::ValueEnum, Default)]
-pub enum OutputFormat {
-    /// Pretty-printed JSON
-    #[default]
-    Pretty,
-    /// Compact JSON (one message per line)
-    Json,
-    /// Human-readable summary
-    Summary,
-}
-
-pub async fn run(args: MarketArgs) -> Result<()> {
+pub async fn run(args: MarketArgs, format: OutputFormat) -> Result<()> {
     let running = Arc::new(AtomicBool::new(true));
     let r = running.clone();
 
@@ -101,7 +88,7 @@ pub async fn run(args: MarketArgs) -> Result<()> {
                 match msg {
                     Some(Ok(channel)) => {
                         if should_print(&channel, &args.filter) {
-                            print_message(&channel, args.format)?;
+                            print_message(&channel, format)?;
                             message_count += 1;
 
                             // Check count limit
@@ -165,7 +152,8 @@ fn print_message(channel: &Channel, format: OutputFormat) -> Result<()> {
             OutputFormat::Json => {
                 println!("{}", serde_json::to_string(&msg)?);
             }
-            OutputFormat::Summary => {
+            OutputFormat::Csv => bail!(CSV_UNSUPPORTED),
+            OutputFormat::Table => {
                 print_market_summary(msg);
             }
         },
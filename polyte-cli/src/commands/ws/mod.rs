@@ -2,7 +2,13 @@ mod market;
 mod user;
 
 use clap::Subcommand;
-use color_eyre::eyre::Result;
+use color_eyre::eyre::{bail, Result};
+
+use crate::commands::common::output::OutputFormat;
+
+/// Error for the one output format WebSocket streams cannot render
+const CSV_UNSUPPORTED: &str =
+    "csv output is not supported for WebSocket streams, use json, pretty or table";
 
 #[derive(Subcommand)]
 pub enum WsCommand {
@@ -19,10 +25,14 @@ pub enum WsCommand {
 }
 
 impl WsCommand {
-    pub async fn run(self) -> Result<()> {
+    pub async fn run(self, format: OutputFormat) -> Result<()> {
+        if format == OutputFormat::Csv {
+            bail!(CSV_UNSUPPORTED);
+        }
+
         match self {
-            Self::Market { args } => market::run(args).await,
-            Self::User { args } => user::run(args).await,
+            Self::Market { args } => market::run(args, format).await,
+            Self::User { args } => user::run(args, format).await,
         }
     }
 }
@@ -7,10 +7,12 @@ use std::{
 };
 
 use clap::{Args, ValueEnum};
-use color_eyre::eyre::Result;
+use color_eyre::eyre::{bail, Result};
 use futures_util::StreamExt;
 use polyte_clob::ws::{ApiCredentials, Channel, UserMessage, WebSocket};
 
+use super::CSV_UNSUPPORTED;
+use crate::commands::common::output::OutputFormat;
 use crate::commands::common::parsing::parse_duration;
 
 /// User event types to filter
@@ -40,10 +42,6 @@ pub struct UserArgs {
     #[arg(long, env = "POLYMARKET_API_PASSPHRASE")]
     api_passphrase: Option<String>,
 
-    /// Output format
-    #[arg(short, long, value_enum, default_value = "pretty")]
-    format: OutputFormat,
-
     /// Filter by event type (can be specified multiple times)
     #[arg(long, value_enum)]
     filter: Vec<UserEventType>,
@@ -57,18 +55,7 @@ pub struct UserArgs {
     timeout: Option<Duration>,
 }
 
-#[derive(Debug, Clone, Copy, ValueEnum, Default)]
-pub enum OutputFormat {
-    /// Pretty-printed JSON
-    #[default]
-    Pretty,
-    /// Compact JSON (one message per line)
-    Json,
-    /// Human-readable summary
-    Summary,
-}
-
-pub async fn run(args: UserArgs) -> Result<()> {
+pub async fn run(args: UserArgs, format: OutputFormat) -> Result<()> {
     let credentials = get_credentials(args.api_key, args.api_secret, args.api_passphrase);
 
     let running = Arc::new(AtomicBool::new(true));
@@ -111,7 +98,7 @@ pub async fn run(args: UserArgs) -> Result<()> {
                 match msg {
                     Some(Ok(channel)) => {
                         if should_print(&channel, &args.filter) {
-                            print_message(&channel, args.format)?;
+                            print_message(&channel, format)?;
                             message_count += 1;
 
                             // Check count limit
@@ -200,7 +187,8 @@ fn print_message(channel: &Channel, format: OutputFormat) -> Result<()> {
             OutputFormat::Json => {
                 println!("{}", serde_json::to_string(&msg)?);
             }
-            OutputFormat::Summary => {
+            OutputFormat::Csv => bail!(CSV_UNSUPPORTED),
+            OutputFormat::Table => {
                 print_user_summary(msg);
             }
         },
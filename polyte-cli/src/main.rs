@@ -7,12 +7,20 @@ mod commands;
 #[command(name = "polyte")]
 #[command(version, about = "CLI tool for querying Polymarket APIs", long_about = None)]
 struct Cli {
+    /// Output format
+    #[arg(short, long, global = true, value_enum, default_value = "pretty")]
+    format: commands::OutputFormat,
     #[command(subcommand)]
     command: Commands,
 }
 
 #[derive(Subcommand)]
 enum Commands {
+    /// Query CLOB API (order books and prices)
+    Clob {
+        #[command(subcommand)]
+        command: commands::ClobCommand,
+    },
     /// Query Data API (user positions)
     Data {
         #[command(subcommand)]
@@ -28,6 +36,9 @@ enum Commands {
         #[command(subcommand)]
         command: commands::WsCommand,
     },
+    /// Walk through the full trading loop (discover, inspect, place, cancel)
+    #[cfg(feature = "demo")]
+    Demo(commands::DemoCommand),
     /// Generate shell completions
     Completions(commands::CompletionsCommand),
 }
@@ -39,9 +50,12 @@ async fn main() -> Result<()> {
     let cli = Cli::parse();
 
     match cli.command {
-        Commands::Data { command } => command.run().await?,
-        Commands::Gamma { command } => command.run().await?,
-        Commands::Ws { command } => command.run().await?,
+        Commands::Clob { command } => command.run(cli.format).await?,
+        Commands::Data { command } => command.run(cli.format).await?,
+        Commands::Gamma { command } => command.run(cli.format).await?,
+        Commands::Ws { command } => command.run(cli.format).await?,
+        #[cfg(feature = "demo")]
+        Commands::Demo(cmd) => cmd.run().await?,
         Commands::Completions(cmd) => cmd.run::<Cli>(),
     }
 
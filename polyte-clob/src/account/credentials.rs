@@ -5,6 +5,7 @@ use serde::{Deserialize, Serialize};
 /// API credentials for L2 authentication
 #[derive(Clone, Serialize, Deserialize)]
 pub struct Credentials {
+    #[serde(alias = "apiKey")]
     pub key: String,
     pub secret: String,
     pub passphrase: String,
@@ -13,9 +14,15 @@ pub struct Credentials {
 impl fmt::Debug for Credentials {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("Credentials")
-            .field("key", &"<redacted>")
-            .field("secret", &"<redacted>")
-            .field("passphrase", &"<redacted>")
+            .field("key", &redact_key(&self.key))
+            .field("secret", &"***")
+            .field("passphrase", &"***")
             .finish()
     }
 }
+
+/// First characters of an API key, enough to tell keys apart in logs
+pub(crate) fn redact_key(key: &str) -> String {
+    let prefix: String = key.chars().take(2).collect();
+    format!("{}..", prefix)
+}
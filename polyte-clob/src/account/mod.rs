@@ -10,6 +10,8 @@ mod wallet;
 use std::path::Path;
 
 use alloy::primitives::Address;
+#[cfg(feature = "ws")]
+pub(crate) use credentials::redact_key;
 pub use credentials::Credentials;
 use serde::{Deserialize, Serialize};
 pub use signer::Signer;
@@ -18,7 +20,7 @@ pub use wallet::Wallet;
 use crate::{
     core::eip712::{sign_clob_auth, sign_order},
     error::ClobError,
-    types::{Order, SignedOrder},
+    types::{Order, SignatureType, SignedOrder},
 };
 
 /// Environment variable names for account configuration
@@ -59,11 +61,19 @@ pub struct AccountConfig {
 /// println!("Address: {:?}", account.address());
 /// # Ok::<(), polyte_clob::ClobError>(())
 /// ```
+///
+/// # Delegated signing
+///
+/// With Polymarket proxy wallets the key that signs orders differs from the
+/// address holding the funds. Use [`Account::with_funder`] to set the funder,
+/// which becomes the order `maker` while the wallet remains the order `signer`.
 #[derive(Clone, Debug)]
 pub struct Account {
     wallet: Wallet,
     credentials: Credentials,
     signer: Signer,
+    funder: Option<Address>,
+    signature_type: SignatureType,
 }
 
 impl Account {
@@ -93,15 +103,77 @@ impl Account {
         credentials: Credentials,
     ) -> Result<Self, ClobError> {
         let wallet = Wallet::from_private_key(&private_key.into())?;
+        Self::from_wallet(wallet, credentials)
+    }
+
+    /// Create an account from an encrypted Ethereum V3 keystore file.
+    ///
+    /// Requires the `keystore` feature. A wrong password or malformed file
+    /// is reported as a validation error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use polyte_clob::{Account, Credentials};
+    ///
+    /// let credentials = Credentials {
+    ///     key: "api_key".to_string(),
+    ///     secret: "api_secret".to_string(),
+    ///     passphrase: "passphrase".to_string(),
+    /// };
+    ///
+    /// let account = Account::from_keystore("keystore.json", "password", credentials)?;
+    /// # Ok::<(), polyte_clob::ClobError>(())
+    /// ```
+    #[cfg(feature = "keystore")]
+    pub fn from_keystore(
+        path: impl AsRef<Path>,
+        password: impl AsRef<[u8]>,
+        credentials: Credentials,
+    ) -> Result<Self, ClobError> {
+        let wallet = Wallet::from_keystore(path, password)?;
+        Self::from_wallet(wallet, credentials)
+    }
+
+    fn from_wallet(wallet: Wallet, credentials: Credentials) -> Result<Self, ClobError> {
         let signer = Signer::new(&credentials.secret)?;
 
         Ok(Self {
             wallet,
             credentials,
             signer,
+            funder: None,
+            signature_type: SignatureType::default(),
         })
     }
 
+    /// Trade on behalf of a funder address (e.g. a Polymarket proxy wallet).
+    ///
+    /// Orders are built with `maker` set to `funder` and `signer` set to this
+    /// account's wallet, signed with the given `signature_type`.
+    ///
+    /// The funder is ignored for [`SignatureType::Eoa`], whose orders are
+    /// made by the wallet itself, and [`ClobBuilder::build`](crate::ClobBuilder::build)
+    /// rejects that combination.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use alloy::primitives::address;
+    /// use polyte_clob::{Account, SignatureType};
+    ///
+    /// let account = Account::from_env()?.with_funder(
+    ///     address!("70997970C51812dc3A010C7d01b50e0d17dc79C8"),
+    ///     SignatureType::PolyProxy,
+    /// );
+    /// # Ok::<(), polyte_clob::ClobError>(())
+    /// ```
+    pub fn with_funder(mut self, funder: Address, signature_type: SignatureType) -> Self {
+        self.funder = Some(funder);
+        self.signature_type = signature_type;
+        self
+    }
+
     /// Load account from environment variables.
     ///
     /// Reads the following environment variables:
@@ -144,9 +216,11 @@ impl Account {
         Self::new(private_key, credentials)
     }
 
-    /// Load account from a JSON configuration file.
+    /// Load account from a configuration file.
     ///
-    /// The file should contain:
+    /// The format is picked from the extension: `.toml` requires the `toml`
+    /// feature, `.yaml`/`.yml` the `yaml` feature, and anything else is
+    /// parsed as JSON. All formats share the [`AccountConfig`] shape:
     /// ```json
     /// {
     ///     "private_key": "0x...",
@@ -174,7 +248,15 @@ impl Account {
             ))
         })?;
 
-        Self::from_json(&content)
+        let extension = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .map(str::to_ascii_lowercase);
+        match extension.as_deref() {
+            Some("toml") => Self::from_toml_content(&content),
+            Some("yaml" | "yml") => Self::from_yaml_content(&content),
+            _ => Self::from_json(&content),
+        }
     }
 
     /// Load account from a JSON string.
@@ -201,11 +283,112 @@ impl Account {
         Self::new(config.private_key, config.credentials)
     }
 
+    /// Load account from a TOML string.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use polyte_clob::Account;
+    ///
+    /// let toml = r#"
+    ///     private_key = "0x..."
+    ///     key = "api_key"
+    ///     secret = "api_secret"
+    ///     passphrase = "passphrase"
+    /// "#;
+    ///
+    /// let account = Account::from_toml(toml)?;
+    /// # Ok::<(), polyte_clob::ClobError>(())
+    /// ```
+    #[cfg(feature = "toml")]
+    pub fn from_toml(toml: &str) -> Result<Self, ClobError> {
+        let config: AccountConfig = toml::from_str(toml)
+            .map_err(|e| ClobError::validation(format!("Failed to parse TOML config: {}", e)))?;
+
+        Self::new(config.private_key, config.credentials)
+    }
+
+    /// Load account from a YAML string.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use polyte_clob::Account;
+    ///
+    /// let yaml = r#"
+    /// private_key: "0x..."
+    /// key: api_key
+    /// secret: api_secret
+    /// passphrase: passphrase
+    /// "#;
+    ///
+    /// let account = Account::from_yaml(yaml)?;
+    /// # Ok::<(), polyte_clob::ClobError>(())
+    /// ```
+    #[cfg(feature = "yaml")]
+    pub fn from_yaml(yaml: &str) -> Result<Self, ClobError> {
+        let config: AccountConfig = serde_norway::from_str(yaml)
+            .map_err(|e| ClobError::validation(format!("Failed to parse YAML config: {}", e)))?;
+
+        Self::new(config.private_key, config.credentials)
+    }
+
+    #[cfg(feature = "toml")]
+    fn from_toml_content(content: &str) -> Result<Self, ClobError> {
+        Self::from_toml(content)
+    }
+
+    #[cfg(not(feature = "toml"))]
+    fn from_toml_content(_content: &str) -> Result<Self, ClobError> {
+        Err(ClobError::validation(
+            "TOML config files require the `toml` feature",
+        ))
+    }
+
+    #[cfg(feature = "yaml")]
+    fn from_yaml_content(content: &str) -> Result<Self, ClobError> {
+        Self::from_yaml(content)
+    }
+
+    #[cfg(not(feature = "yaml"))]
+    fn from_yaml_content(_content: &str) -> Result<Self, ClobError> {
+        Err(ClobError::validation(
+            "YAML config files require the `yaml` feature",
+        ))
+    }
+
     /// Get the wallet address.
+    ///
+    /// This is the address that signs orders.
     pub fn address(&self) -> Address {
         self.wallet.address()
     }
 
+    /// Derive the address of a private key without building an account,
+    /// e.g. to check a key maps to the expected funder before trading.
+    ///
+    /// See [`Wallet::address_from_private_key`].
+    pub fn address_from_private_key(private_key: &str) -> Result<Address, ClobError> {
+        Wallet::address_from_private_key(private_key)
+    }
+
+    /// Get the address funding orders.
+    ///
+    /// Defaults to the wallet address unless a funder was configured.
+    pub fn funder(&self) -> Address {
+        self.funder.unwrap_or_else(|| self.wallet.address())
+    }
+
+    /// Funder set with [`Account::with_funder`], if any
+    pub(crate) fn funder_address(&self) -> Option<Address> {
+        self.funder
+    }
+
+    /// Get the signature type used for orders.
+    pub fn signature_type(&self) -> SignatureType {
+        self.signature_type
+    }
+
     /// Get a reference to the wallet.
     pub fn wallet(&self) -> &Wallet {
         &self.wallet
@@ -320,4 +503,227 @@ mod tests {
         assert!(!signature.contains('+'));
         assert!(!signature.contains('/'));
     }
+
+    #[test]
+    fn test_funder_defaults_to_wallet() {
+        let json = r#"{
+            "private_key": "0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80",
+            "key": "test_key",
+            "secret": "c2VjcmV0",
+            "passphrase": "test_pass"
+        }"#;
+
+        let account = Account::from_json(json).unwrap();
+        assert_eq!(account.funder(), account.address());
+        assert_eq!(account.signature_type(), SignatureType::Eoa);
+    }
+
+    const PRIVATE_KEY: &str = "0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80";
+
+    fn assert_same_account(a: &Account, b: &Account) {
+        assert_eq!(a.address(), b.address());
+        assert_eq!(a.credentials().key, b.credentials().key);
+        assert_eq!(a.credentials().secret, b.credentials().secret);
+        assert_eq!(a.credentials().passphrase, b.credentials().passphrase);
+    }
+
+    fn json_fixture() -> String {
+        format!(
+            r#"{{"private_key": "{}", "key": "test_key", "secret": "c2VjcmV0", "passphrase": "test_pass"}}"#,
+            PRIVATE_KEY
+        )
+    }
+
+    #[cfg(feature = "toml")]
+    fn toml_fixture() -> String {
+        format!(
+            "private_key = \"{}\"\nkey = \"test_key\"\nsecret = \"c2VjcmV0\"\npassphrase = \"test_pass\"\n",
+            PRIVATE_KEY
+        )
+    }
+
+    #[cfg(feature = "yaml")]
+    fn yaml_fixture() -> String {
+        format!(
+            "private_key: \"{}\"\nkey: test_key\nsecret: c2VjcmV0\npassphrase: test_pass\n",
+            PRIVATE_KEY
+        )
+    }
+
+    /// Write `content` to a file with the given name in a fresh temporary
+    /// directory, which is removed when the returned guard is dropped
+    fn write_fixture(name: &str, content: &str) -> (tempfile::TempDir, std::path::PathBuf) {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(name);
+        std::fs::write(&path, content).unwrap();
+        (dir, path)
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn test_from_toml_matches_json() {
+        let json = Account::from_json(&json_fixture()).unwrap();
+        let toml = Account::from_toml(&toml_fixture()).unwrap();
+        assert_same_account(&json, &toml);
+    }
+
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn test_from_yaml_matches_json() {
+        let json = Account::from_json(&json_fixture()).unwrap();
+        let yaml = Account::from_yaml(&yaml_fixture()).unwrap();
+        assert_same_account(&json, &yaml);
+    }
+
+    #[cfg(all(feature = "toml", feature = "yaml"))]
+    #[test]
+    fn test_from_file_detects_format() {
+        let (_json_dir, json_path) = write_fixture("account.json", &json_fixture());
+        let (_toml_dir, toml_path) = write_fixture("account.toml", &toml_fixture());
+        let (_yaml_dir, yaml_path) = write_fixture("account.yml", &yaml_fixture());
+        let json = Account::from_file(json_path).unwrap();
+        let toml = Account::from_file(toml_path).unwrap();
+        let yaml = Account::from_file(yaml_path).unwrap();
+
+        assert_same_account(&json, &toml);
+        assert_same_account(&json, &yaml);
+    }
+
+    #[cfg(not(feature = "yaml"))]
+    #[test]
+    fn test_yaml_file_requires_feature() {
+        let (_dir, path) = write_fixture("account.yml", "key: test_key");
+        let err = Account::from_file(path).unwrap_err();
+        assert!(err.to_string().contains("`yaml` feature"));
+    }
+
+    #[cfg(not(feature = "toml"))]
+    #[test]
+    fn test_toml_file_requires_feature() {
+        let (_dir, path) = write_fixture("account.toml", "key = \"test_key\"");
+        let err = Account::from_file(path).unwrap_err();
+        assert!(err.to_string().contains("`toml` feature"));
+    }
+
+    #[test]
+    fn test_from_file_defaults_to_json() {
+        let json = Account::from_json(&json_fixture()).unwrap();
+        let (_dir, path) = write_fixture("account.conf", &json_fixture());
+        let file = Account::from_file(path).unwrap();
+        assert_same_account(&json, &file);
+    }
+
+    /// Web3 Secret Storage test vector, password "testpassword"
+    #[cfg(feature = "keystore")]
+    const KEYSTORE: &str = r#"{
+        "crypto": {
+            "cipher": "aes-128-ctr",
+            "cipherparams": { "iv": "6087dab2f9fdbbfaddc31a909735c1e6" },
+            "ciphertext": "5318b4d5bcd28de64ee5559e671353e16f075ecae9f99c7a79a38af5f869aa46",
+            "kdf": "pbkdf2",
+            "kdfparams": {
+                "c": 262144,
+                "dklen": 32,
+                "prf": "hmac-sha256",
+                "salt": "ae3cd4e7013836a3df6bd7241b12db061dbe2c6785853cce422d148a624ce0bd"
+            },
+            "mac": "517ead924a9d0dc3124507e3393d175ce3ff7c1e96529c6c555ce9e51205e9b2"
+        },
+        "id": "3198bc9c-6672-5ab3-d995-4942343ae5b6",
+        "version": 3
+    }"#;
+
+    #[cfg(feature = "keystore")]
+    fn credentials() -> Credentials {
+        Credentials {
+            key: "test_key".to_string(),
+            secret: "c2VjcmV0".to_string(),
+            passphrase: "test_pass".to_string(),
+        }
+    }
+
+    #[cfg(feature = "keystore")]
+    #[test]
+    fn test_from_keystore() {
+        let (_dir, path) = write_fixture("keystore.json", KEYSTORE);
+        let account = Account::from_keystore(&path, "testpassword", credentials()).unwrap();
+        let expected = Account::new(
+            "7a28b5ba57c53603b0b07b56bba752f7784bf506fa95edc395f5cf6c7514fe9d",
+            credentials(),
+        )
+        .unwrap();
+        assert_same_account(&account, &expected);
+    }
+
+    #[cfg(feature = "keystore")]
+    #[test]
+    fn test_from_keystore_rejects_bad_input() {
+        let (_dir, path) = write_fixture("keystore.json", KEYSTORE);
+        let err = Account::from_keystore(&path, "wrong", credentials()).unwrap_err();
+        assert!(matches!(err, ClobError::Api(_)));
+        assert!(err.to_string().contains("wrong password or malformed file"));
+
+        let (_dir, path) = write_fixture("keystore.json", "{\"version\": 3}");
+        let err = Account::from_keystore(&path, "testpassword", credentials()).unwrap_err();
+        assert!(err.to_string().contains("wrong password or malformed file"));
+    }
+
+    #[test]
+    fn test_address_from_private_key() {
+        let private_key = "0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80";
+        let account = Account::new(
+            private_key,
+            Credentials {
+                key: "test_key".to_string(),
+                secret: "c2VjcmV0".to_string(),
+                passphrase: "test_pass".to_string(),
+            },
+        )
+        .unwrap();
+
+        assert_eq!(
+            Account::address_from_private_key(private_key).unwrap(),
+            account.address()
+        );
+        assert!(Account::address_from_private_key("0x1234").is_err());
+    }
+
+    #[test]
+    fn test_debug_redacts_secrets() {
+        let private_key = "ac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80";
+        let credentials = Credentials {
+            key: "key-0123456789".to_string(),
+            secret: "c2VjcmV0LXZhbHVl".to_string(),
+            passphrase: "xyzzy-7f3e9b2d".to_string(),
+        };
+        let account = Account::new(private_key, credentials.clone()).unwrap();
+        let signer = Signer::new(&credentials.secret).unwrap();
+
+        for debug in [
+            format!("{:?}", account),
+            format!("{:#?}", account),
+            format!("{:?}", account.wallet()),
+            format!("{:?}", signer),
+        ] {
+            for secret in [
+                private_key,
+                &credentials.key,
+                &credentials.secret,
+                &credentials.passphrase,
+            ] {
+                // No run of 6 characters of any secret shows up
+                for window in secret.as_bytes().windows(6) {
+                    let window = std::str::from_utf8(window).unwrap();
+                    assert!(!debug.contains(window), "{} leaks {}", debug, window);
+                }
+            }
+            // Nor the bytes of the decoded HMAC secret
+            assert!(!debug.contains("115, 101, 99"), "{}", debug);
+        }
+
+        assert_eq!(
+            format!("{:?}", credentials),
+            r#"Credentials { key: "ke..", secret: "***", passphrase: "***" }"#
+        );
+    }
 }
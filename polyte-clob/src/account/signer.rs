@@ -1,3 +1,5 @@
+use std::fmt;
+
 use base64::{engine::general_purpose::STANDARD, prelude::BASE64_URL_SAFE_NO_PAD, Engine};
 use hmac::{Hmac, Mac};
 use sha2::Sha256;
@@ -5,11 +7,17 @@ use sha2::Sha256;
 use crate::error::ClobError;
 
 /// HMAC signer for API authentication
-#[derive(Clone, Debug)]
+#[derive(Clone)]
 pub struct Signer {
     secret: Vec<u8>,
 }
 
+impl fmt::Debug for Signer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Signer").field("secret", &"***").finish()
+    }
+}
+
 impl Signer {
     /// Create a new signer from base64-encoded secret (supports multiple formats)
     pub fn new(secret: &str) -> Result<Self, ClobError> {
@@ -1,14 +1,24 @@
+use std::fmt;
+
 use alloy::{network::EthereumWallet, primitives::Address, signers::local::PrivateKeySigner};
 
 use crate::error::ClobError;
 
 /// Wallet wrapper for signing operations
-#[derive(Clone, Debug)]
+#[derive(Clone)]
 pub struct Wallet {
     signer: PrivateKeySigner,
     wallet: EthereumWallet,
 }
 
+impl fmt::Debug for Wallet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Wallet")
+            .field("address", &self.address())
+            .finish_non_exhaustive()
+    }
+}
+
 impl Wallet {
     /// Create wallet from private key hex string
     pub fn from_private_key(private_key: &str) -> Result<Self, ClobError> {
@@ -20,11 +30,52 @@ impl Wallet {
         Ok(Self { signer, wallet })
     }
 
+    /// Create wallet by decrypting an Ethereum V3 keystore JSON file
+    #[cfg(feature = "keystore")]
+    pub fn from_keystore(
+        path: impl AsRef<std::path::Path>,
+        password: impl AsRef<[u8]>,
+    ) -> Result<Self, ClobError> {
+        let path = path.as_ref();
+        let signer = PrivateKeySigner::decrypt_keystore(path, password).map_err(|e| {
+            ClobError::validation(format!(
+                "Failed to decrypt keystore {} (wrong password or malformed file): {}",
+                path.display(),
+                e
+            ))
+        })?;
+        let wallet = EthereumWallet::from(signer.clone());
+
+        Ok(Self { signer, wallet })
+    }
+
     /// Get the wallet address
     pub fn address(&self) -> Address {
         self.signer.address()
     }
 
+    /// Derive the address of a private key without keeping the key.
+    ///
+    /// The key must be 32 bytes of hex, with or without a `0x` prefix. The
+    /// returned address displays with its EIP-55 checksum.
+    pub fn address_from_private_key(private_key: &str) -> Result<Address, ClobError> {
+        let hex = private_key.trim();
+        let hex = hex
+            .strip_prefix("0x")
+            .or_else(|| hex.strip_prefix("0X"))
+            .unwrap_or(hex);
+        if hex.len() != 64 || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(ClobError::validation(
+                "Private key must be 64 hex characters, optionally prefixed with 0x",
+            ));
+        }
+
+        let signer = hex
+            .parse::<PrivateKeySigner>()
+            .map_err(|e| ClobError::Crypto(format!("Failed to parse private key: {}", e)))?;
+        Ok(signer.address())
+    }
+
     /// Get reference to the signer
     pub fn signer(&self) -> &PrivateKeySigner {
         &self.signer
@@ -35,3 +86,59 @@ impl Wallet {
         &self.wallet
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use alloy::primitives::address;
+
+    use super::*;
+
+    #[test]
+    fn test_address_from_private_key() {
+        // Well-known development keys and their addresses
+        let vectors = [
+            (
+                "0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80",
+                address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266"),
+            ),
+            (
+                "59c6995e998f97a5a0044966f0945389dc9e86dae88c7a8412f4603b6b78690d",
+                address!("70997970C51812dc3A010C7d01b50e0d17dc79C8"),
+            ),
+        ];
+
+        for (private_key, expected) in vectors {
+            let address = Wallet::address_from_private_key(private_key).unwrap();
+            assert_eq!(address, expected);
+            assert_eq!(
+                address,
+                Wallet::from_private_key(private_key).unwrap().address()
+            );
+        }
+
+        let address = Wallet::address_from_private_key(vectors[0].0).unwrap();
+        assert_eq!(
+            address.to_string(),
+            "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266"
+        );
+    }
+
+    #[test]
+    fn test_address_from_invalid_private_key() {
+        for invalid in [
+            "",
+            "0x",
+            "0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff",
+            "0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff8000",
+            "0xzc0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80",
+        ] {
+            assert!(
+                Wallet::address_from_private_key(invalid).is_err(),
+                "{}",
+                invalid
+            );
+        }
+        // The zero scalar is not a valid key
+        assert!(Wallet::address_from_private_key(&"0".repeat(64)).is_err());
+    }
+}
@@ -1,62 +1,229 @@
-use alloy::primitives::Address;
+use std::{fmt, sync::Arc};
+
+use futures_util::{stream, Stream, StreamExt, TryStreamExt};
+
+use alloy::primitives::{Address, U256};
 use polyte_core::QueryBuilder;
-use reqwest::Client;
-use serde::{Deserialize, Serialize};
-use url::Url;
+use reqwest::Method;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 use crate::{
-    account::{Credentials, Signer, Wallet},
-    request::{AuthMode, Request},
+    error::ClobError,
+    request::{AuthMode, ClobContext, Request},
     types::OrderSide,
 };
 
+/// Decimals of USDC and of Polymarket conditional tokens
+pub const USDC_DECIMALS: u32 = 6;
+
 /// Account API namespace for account-related operations
 #[derive(Clone)]
 pub struct AccountApi {
-    pub(crate) client: Client,
-    pub(crate) base_url: Url,
-    pub(crate) wallet: Wallet,
-    pub(crate) credentials: Credentials,
-    pub(crate) signer: Signer,
-    pub(crate) chain_id: u64,
+    pub(crate) context: Arc<ClobContext>,
+    pub(crate) auth: AuthMode,
 }
 
 impl AccountApi {
-    /// Get balance and allowance for a token
-    pub fn balance_allowance(
+    /// Get balance and allowance of USDC collateral or of a conditional
+    /// token (set with [`BalanceAllowanceRequest::token_id`])
+    pub fn balance_allowance(&self, asset_type: AssetType) -> BalanceAllowanceRequest {
+        self.balance_allowance_request("/balance-allowance", asset_type)
+    }
+
+    /// Ask the CLOB to refresh its cached balance and allowance, returning
+    /// the refreshed values.
+    ///
+    /// This does not send an on-chain approval; it only makes the CLOB
+    /// re-read the chain. After approving the exchange contracts, poll this
+    /// until the allowance shows up.
+    pub fn update_balance_allowance(&self, asset_type: AssetType) -> BalanceAllowanceRequest {
+        self.balance_allowance_request("/balance-allowance/update", asset_type)
+    }
+
+    fn balance_allowance_request(
         &self,
-        token_id: impl Into<String>,
-    ) -> Request<BalanceAllowanceResponse> {
-        Request::get(
-            self.client.clone(),
-            self.base_url.clone(),
-            "/balance-allowance",
-            AuthMode::L2 {
-                address: self.wallet.clone().address(),
-                credentials: self.credentials.clone(),
-                signer: self.signer.clone(),
-            },
-            self.chain_id,
-        )
-        .query("token_id", token_id.into())
-    }
-
-    /// Get trades
-    pub fn trades(&self) -> Request<Vec<Trade>> {
-        Request::get(
-            self.client.clone(),
-            self.base_url.clone(),
-            "/trades",
-            AuthMode::L2 {
-                address: self.wallet.clone().address(),
-                credentials: self.credentials.clone(),
-                signer: self.signer.clone(),
-            },
-            self.chain_id,
+        path: &str,
+        asset_type: AssetType,
+    ) -> BalanceAllowanceRequest {
+        let request = self
+            .context
+            .request(Method::GET, path, self.auth.clone())
+            .query("asset_type", asset_type);
+
+        BalanceAllowanceRequest {
+            request,
+            asset_type,
+            token_id: None,
+        }
+    }
+
+    /// Get the account's trade history, newest first
+    pub fn trades(&self) -> TradesRequest {
+        TradesRequest {
+            context: self.context.clone(),
+            auth: self.auth.clone(),
+            market: None,
+            asset_id: None,
+            before: None,
+            after: None,
+            limit: None,
+            cursor: None,
+        }
+    }
+}
+
+/// Cursor returned with the last page of a paginated CLOB endpoint
+pub const END_CURSOR: &str = "LTE=";
+
+/// Request builder for the account's trade history
+#[derive(Clone)]
+pub struct TradesRequest {
+    context: Arc<ClobContext>,
+    auth: AuthMode,
+    market: Option<String>,
+    asset_id: Option<String>,
+    before: Option<u64>,
+    after: Option<u64>,
+    limit: Option<usize>,
+    cursor: Option<String>,
+}
+
+impl TradesRequest {
+    /// Filter by market condition ID
+    pub fn market(mut self, condition_id: impl Into<String>) -> Self {
+        self.market = Some(condition_id.into());
+        self
+    }
+
+    /// Filter by token ID
+    pub fn asset_id(mut self, asset_id: impl Into<String>) -> Self {
+        self.asset_id = Some(asset_id.into());
+        self
+    }
+
+    /// Only trades before this unix timestamp (seconds)
+    pub fn before(mut self, timestamp: u64) -> Self {
+        self.before = Some(timestamp);
+        self
+    }
+
+    /// Only trades after this unix timestamp (seconds)
+    pub fn after(mut self, timestamp: u64) -> Self {
+        self.after = Some(timestamp);
+        self
+    }
+
+    /// Stop after this many trades
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Resume from a cursor returned in [`TradesPage::next_cursor`]
+    pub fn cursor(mut self, cursor: impl Into<String>) -> Self {
+        self.cursor = Some(cursor.into());
+        self
+    }
+
+    /// Check that the time window is not empty
+    fn validate(&self) -> Result<(), ClobError> {
+        match (self.before, self.after) {
+            (Some(before), Some(after)) if before <= after => Err(ClobError::validation(format!(
+                "`before` ({}) must be later than `after` ({})",
+                before, after
+            ))),
+            _ => Ok(()),
+        }
+    }
+
+    fn page_request(&self, cursor: Option<&str>) -> Request<TradesPage> {
+        let mut request = self
+            .context
+            .request(Method::GET, "/data/trades", self.auth.clone())
+            .query_opt("market", self.market.as_ref())
+            .query_opt("asset_id", self.asset_id.as_ref())
+            .query_opt("before", self.before)
+            .query_opt("after", self.after);
+        if let Some(cursor) = cursor {
+            request = request.query("next_cursor", cursor);
+        }
+        request
+    }
+
+    /// Fetch a single page, starting at the configured cursor
+    pub async fn page(&self) -> Result<TradesPage, ClobError> {
+        self.validate()?;
+        self.page_request(self.cursor.as_deref()).send().await
+    }
+
+    /// Fetch every matching trade, following the cursor up to `limit`
+    pub async fn send(self) -> Result<Vec<Trade>, ClobError> {
+        self.stream().try_collect().await
+    }
+
+    /// Stream matching trades, fetching pages lazily by cursor.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use futures_util::StreamExt;
+    /// use polyte_clob::{Account, Clob};
+    ///
+    /// # async fn example(account: Account) -> Result<(), Box<dyn std::error::Error>> {
+    /// let clob = Clob::from_account(account)?;
+    /// let mut trades = clob.account_api().trades().after(1_700_000_000).stream();
+    ///
+    /// while let Some(trade) = trades.next().await {
+    ///     println!("{}", trade?.id);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn stream(self) -> impl Stream<Item = Result<Trade, ClobError>> + Send + Unpin {
+        let invalid = self.validate().err();
+        let limit = self.limit.unwrap_or(usize::MAX);
+        let start = invalid.is_none().then(|| self.cursor.clone());
+
+        let pages = stream::try_unfold(start, move |cursor| {
+            let request = self.clone();
+            async move {
+                let Some(cursor) = cursor else {
+                    return Ok::<_, ClobError>(None);
+                };
+
+                let page = request.page_request(cursor.as_deref()).send().await?;
+                let next = page.next_cursor().map(|cursor| Some(cursor.to_string()));
+
+                Ok(Some((stream::iter(page.data.into_iter().map(Ok)), next)))
+            }
+        });
+
+        Box::pin(
+            stream::iter(invalid.map(Err))
+                .chain(pages.try_flatten())
+                .take(limit),
         )
     }
 }
 
+/// Page of the account's trade history
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TradesPage {
+    pub data: Vec<Trade>,
+    #[serde(default)]
+    pub next_cursor: Option<String>,
+}
+
+impl TradesPage {
+    /// Cursor of the next page, `None` on the last page
+    pub fn next_cursor(&self) -> Option<&str> {
+        self.next_cursor
+            .as_deref()
+            .filter(|cursor| !cursor.is_empty() && *cursor != END_CURSOR)
+    }
+}
+
 /// Trade information
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Trade {
@@ -77,11 +244,323 @@ pub struct Trade {
     pub bucket_index: Option<u32>,
     pub owner: Address,
     pub transaction_hash: String,
+    /// Resting orders the taker order matched against
+    #[serde(default)]
+    pub maker_orders: Vec<MakerOrder>,
 }
 
-/// Balance and allowance response
+impl Trade {
+    /// Whether `order_id` took part in the trade, as taker or as maker
+    pub fn involves_order(&self, order_id: &str) -> bool {
+        self.taker_order_id == order_id
+            || self
+                .maker_orders
+                .iter()
+                .any(|maker| maker.order_id == order_id)
+    }
+
+    /// Whether the trade has been mined or confirmed on chain
+    pub fn is_settled(&self) -> bool {
+        self.status.eq_ignore_ascii_case("MINED") || self.status.eq_ignore_ascii_case("CONFIRMED")
+    }
+
+    /// Whether on-chain settlement of the trade failed
+    pub fn is_failed(&self) -> bool {
+        self.status.eq_ignore_ascii_case("FAILED")
+    }
+}
+
+/// Maker side of a trade
 #[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MakerOrder {
+    pub order_id: String,
+    pub asset_id: String,
+    pub matched_amount: String,
+    pub price: String,
+    pub outcome: String,
+}
+
+/// Kind of asset whose balance and allowance are queried
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum AssetType {
+    /// USDC collateral
+    Collateral,
+    /// Outcome token of a market, identified by its token ID
+    Conditional,
+}
+
+impl fmt::Display for AssetType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Collateral => write!(f, "COLLATERAL"),
+            Self::Conditional => write!(f, "CONDITIONAL"),
+        }
+    }
+}
+
+/// Request builder for balance and allowance
+pub struct BalanceAllowanceRequest {
+    request: Request<BalanceAllowanceResponse>,
+    asset_type: AssetType,
+    token_id: Option<String>,
+}
+
+impl BalanceAllowanceRequest {
+    /// Token ID of the conditional token (required for, and only accepted
+    /// with, [`AssetType::Conditional`])
+    pub fn token_id(mut self, token_id: impl Into<String>) -> Self {
+        self.token_id = Some(token_id.into());
+        self
+    }
+
+    /// Check that a token ID is given exactly for conditional tokens
+    fn validate(&self) -> Result<(), ClobError> {
+        match (self.asset_type, &self.token_id) {
+            (AssetType::Conditional, None) => Err(ClobError::validation(
+                "A token ID is required for conditional balances",
+            )),
+            (AssetType::Collateral, Some(_)) => Err(ClobError::validation(
+                "Collateral balances do not take a token ID",
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    /// Execute the request
+    pub async fn send(self) -> Result<BalanceAllowanceResponse, ClobError> {
+        self.validate()?;
+        let mut request = self.request;
+        if let Some(token_id) = self.token_id {
+            request = request.query("token_id", token_id);
+        }
+        request.send().await
+    }
+}
+
+/// Balance and allowance, in base units (6 decimals)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct BalanceAllowanceResponse {
-    pub balance: String,
-    pub allowance: String,
+    #[serde(
+        serialize_with = "serialize_base_units",
+        deserialize_with = "deserialize_base_units"
+    )]
+    pub balance: U256,
+    #[serde(
+        serialize_with = "serialize_base_units",
+        deserialize_with = "deserialize_base_units"
+    )]
+    pub allowance: U256,
+}
+
+impl BalanceAllowanceResponse {
+    /// Balance in whole units (e.g. USDC)
+    pub fn balance_units(&self) -> Option<Decimal> {
+        from_base_units(self.balance)
+    }
+
+    /// Allowance in whole units (e.g. USDC); `None` when too large for a
+    /// [`Decimal`], as with an unlimited approval
+    pub fn allowance_units(&self) -> Option<Decimal> {
+        from_base_units(self.allowance)
+    }
+}
+
+/// Convert a base-unit amount to whole units, `None` if it does not fit a
+/// [`Decimal`]
+pub fn from_base_units(amount: U256) -> Option<Decimal> {
+    let amount = i128::try_from(amount).ok()?;
+    Decimal::try_from_i128_with_scale(amount, USDC_DECIMALS).ok()
+}
+
+fn serialize_base_units<S: Serializer>(amount: &U256, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&amount.to_string())
+}
+
+fn deserialize_base_units<'de, D: Deserializer<'de>>(deserializer: D) -> Result<U256, D::Error> {
+    let amount = String::deserialize(deserializer)?;
+    U256::from_str_radix(&amount, 10).map_err(serde::de::Error::custom)
+}
+
+#[cfg(test)]
+mod tests {
+    use polyte_core::testing::{MockResponse, MockTransport};
+
+    use super::*;
+    use crate::{testing::account, Clob, ClobBuilder};
+
+    fn account_api() -> AccountApi {
+        Clob::from_account(account()).unwrap().account_api()
+    }
+
+    fn mock_account_api(transport: &Arc<MockTransport>) -> AccountApi {
+        ClobBuilder::new(account())
+            .transport(transport.clone())
+            .build()
+            .unwrap()
+            .account_api()
+    }
+
+    #[test]
+    fn test_base_unit_conversion() {
+        let response: BalanceAllowanceResponse =
+            serde_json::from_str(r#"{"balance": "12345678", "allowance": "1000000"}"#).unwrap();
+
+        assert_eq!(response.balance, U256::from(12_345_678u64));
+        assert_eq!(response.balance_units(), Some(Decimal::new(12_345_678, 6)));
+        assert_eq!(response.balance_units().unwrap().to_string(), "12.345678");
+        assert_eq!(response.allowance_units(), Some(Decimal::ONE));
+        assert_eq!(from_base_units(U256::ZERO), Some(Decimal::ZERO));
+    }
+
+    #[test]
+    fn test_unlimited_allowance_does_not_fit() {
+        let response = BalanceAllowanceResponse {
+            balance: U256::from(1u64),
+            allowance: U256::MAX,
+        };
+        assert_eq!(response.allowance_units(), None);
+
+        let json = serde_json::to_value(&response).unwrap();
+        assert_eq!(json["allowance"], U256::MAX.to_string());
+    }
+
+    #[test]
+    fn test_token_id_required_only_for_conditional() {
+        let api = account_api();
+        assert!(api
+            .balance_allowance(AssetType::Collateral)
+            .validate()
+            .is_ok());
+        assert!(api
+            .balance_allowance(AssetType::Conditional)
+            .token_id("123")
+            .validate()
+            .is_ok());
+        assert!(api
+            .balance_allowance(AssetType::Conditional)
+            .validate()
+            .is_err());
+        assert!(api
+            .balance_allowance(AssetType::Collateral)
+            .token_id("123")
+            .validate()
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_update_balance_allowance() {
+        let transport = MockTransport::always(MockResponse::ok(
+            r#"{"balance":"5000000","allowance":"2500000"}"#,
+        ));
+
+        let response = mock_account_api(&transport)
+            .update_balance_allowance(AssetType::Conditional)
+            .token_id("123")
+            .send()
+            .await
+            .unwrap();
+
+        let request = &transport.requests()[0];
+        assert_eq!(request.method, reqwest::Method::GET);
+        assert_eq!(request.path(), "/balance-allowance/update");
+        assert_eq!(request.query("asset_type").as_deref(), Some("CONDITIONAL"));
+        assert_eq!(request.query("token_id").as_deref(), Some("123"));
+        assert_eq!(request.header("poly_api_key"), Some("test_key"));
+        assert_eq!(response.balance_units(), Some(Decimal::new(5, 0)));
+        assert_eq!(response.allowance_units(), Some(Decimal::new(25, 1)));
+    }
+
+    fn trade(id: &str) -> serde_json::Value {
+        serde_json::json!({
+            "id": id,
+            "taker_order_id": "0xorder",
+            "market": "0xmarket",
+            "asset_id": "123",
+            "side": "BUY",
+            "size": "10",
+            "fee_rate_bps": "0",
+            "price": "0.5",
+            "status": "MATCHED",
+            "match_time": "1700000000",
+            "outcome": "Yes",
+            "owner": "0x0000000000000000000000000000000000000000",
+            "transaction_hash": "0x",
+        })
+    }
+
+    /// Serve `/data/trades` in two pages
+    fn trade_pages() -> Arc<MockTransport> {
+        MockTransport::new(|request| {
+            let body = if request.query("next_cursor").as_deref() == Some("page2") {
+                serde_json::json!({ "data": [trade("3")], "next_cursor": END_CURSOR })
+            } else {
+                serde_json::json!({ "data": [trade("1"), trade("2")], "next_cursor": "page2" })
+            };
+            MockResponse::json(&body)
+        })
+    }
+
+    #[tokio::test]
+    async fn test_trades_follow_cursor() {
+        let transport = trade_pages();
+
+        let trades = mock_account_api(&transport)
+            .trades()
+            .market("0xmarket")
+            .after(1_700_000_000)
+            .before(1_800_000_000)
+            .send()
+            .await
+            .unwrap();
+        let ids: Vec<&str> = trades.iter().map(|trade| trade.id.as_str()).collect();
+        assert_eq!(ids, ["1", "2", "3"]);
+
+        let requests = transport.requests();
+        let first = &requests[0];
+        assert_eq!(first.path(), "/data/trades");
+        assert_eq!(first.query("market").as_deref(), Some("0xmarket"));
+        assert_eq!(first.query("after").as_deref(), Some("1700000000"));
+        assert_eq!(first.query("before").as_deref(), Some("1800000000"));
+        assert_eq!(first.query("next_cursor"), None);
+        assert_eq!(requests[1].query("next_cursor").as_deref(), Some("page2"));
+    }
+
+    #[tokio::test]
+    async fn test_trades_limit_stops_paging() {
+        let transport = trade_pages();
+
+        let trades = mock_account_api(&transport)
+            .trades()
+            .limit(2)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(trades.len(), 2);
+        assert_eq!(transport.request_count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_trades_rejects_empty_window() {
+        let err = account_api()
+            .trades()
+            .after(200)
+            .before(100)
+            .send()
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("must be later than"));
+    }
+
+    #[test]
+    fn test_trades_page_end_cursor() {
+        let page = |cursor: Option<&str>| TradesPage {
+            data: Vec::new(),
+            next_cursor: cursor.map(String::from),
+        };
+        assert_eq!(page(Some("MTAw")).next_cursor(), Some("MTAw"));
+        assert_eq!(page(Some(END_CURSOR)).next_cursor(), None);
+        assert_eq!(page(Some("")).next_cursor(), None);
+        assert_eq!(page(None).next_cursor(), None);
+    }
 }
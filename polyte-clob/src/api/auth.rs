@@ -0,0 +1,140 @@
+use std::sync::Arc;
+
+use polyte_core::HttpClientBuilder;
+use reqwest::Method;
+
+use crate::{
+    account::{Credentials, Wallet},
+    core::chain::Chain,
+    error::ClobError,
+    request::{ClobContext, Request},
+    utils::Clock,
+};
+
+/// API key namespace, authenticated with the wallet alone (L1).
+///
+/// Obtained from [`crate::Clob::auth`], or with [`Auth::new`] when no API
+/// credentials exist yet to build a [`crate::Clob`].
+#[derive(Clone)]
+pub struct Auth {
+    pub(crate) context: Arc<ClobContext>,
+    pub(crate) wallet: Wallet,
+}
+
+impl Auth {
+    /// Create a standalone namespace for `wallet` on Polygon mainnet.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use polyte_clob::{api::Auth, Account, Wallet};
+    ///
+    /// # async fn run() -> Result<(), polyte_clob::ClobError> {
+    /// let wallet = Wallet::from_private_key("0x...")?;
+    /// let credentials = Auth::new(wallet)?.derive_api_key(0).send().await?;
+    /// let account = Account::new("0x...", credentials)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new(wallet: Wallet) -> Result<Self, ClobError> {
+        let chain = Chain::PolygonMainnet;
+        Self::with_base_url(wallet, chain.endpoints().clob, chain)
+    }
+
+    /// Create a standalone namespace for `wallet` against a custom URL and chain
+    pub fn with_base_url(wallet: Wallet, base_url: &str, chain: Chain) -> Result<Self, ClobError> {
+        let http = HttpClientBuilder::new(base_url).build()?;
+
+        Ok(Self {
+            context: Arc::new(ClobContext::new(http, chain.chain_id(), Clock::default())),
+            wallet,
+        })
+    }
+
+    /// Create new API credentials for the wallet.
+    ///
+    /// Fails if credentials already exist for `nonce`; use
+    /// [`Auth::derive_api_key`] to recover them.
+    pub fn create_api_key(&self, nonce: u32) -> Request<Credentials> {
+        self.context.request(
+            Method::POST,
+            "/auth/api-key",
+            self.context.l1(&self.wallet, nonce),
+        )
+    }
+
+    /// Derive the existing API credentials of the wallet for `nonce`
+    pub fn derive_api_key(&self, nonce: u32) -> Request<Credentials> {
+        self.context.request(
+            Method::GET,
+            "/auth/derive-api-key",
+            self.context.l1(&self.wallet, nonce),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use polyte_core::testing::{MockResponse, MockTransport};
+
+    use super::*;
+    use crate::{core::eip712::sign_clob_auth, Account};
+
+    const PRIVATE_KEY: &str = "0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80";
+
+    const CREDENTIALS: &str =
+        r#"{"apiKey": "key", "secret": "c2VjcmV0", "passphrase": "passphrase"}"#;
+
+    fn auth(transport: &Arc<MockTransport>) -> Auth {
+        let http = HttpClientBuilder::new("https://clob.example.com")
+            .transport(transport.clone())
+            .build()
+            .unwrap();
+        Auth {
+            context: Arc::new(ClobContext::new(http, 137, Clock::default())),
+            wallet: Wallet::from_private_key(PRIVATE_KEY).unwrap(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_derive_api_key_signs_with_wallet() {
+        let transport = MockTransport::always(MockResponse::ok(CREDENTIALS));
+        let auth = auth(&transport);
+
+        let credentials = auth.derive_api_key(7).send().await.unwrap();
+        assert_eq!(credentials.key, "key");
+        assert_eq!(credentials.secret, "c2VjcmV0");
+        assert_eq!(credentials.passphrase, "passphrase");
+
+        let request = &transport.requests()[0];
+        assert_eq!(request.method, reqwest::Method::GET);
+        assert_eq!(request.path(), "/auth/derive-api-key");
+        assert_eq!(request.header("poly_nonce"), Some("7"));
+        assert_eq!(
+            request.header("poly_address"),
+            Some(format!("{:?}", auth.wallet.address()).as_str())
+        );
+
+        let timestamp: u64 = request.header("poly_timestamp").unwrap().parse().unwrap();
+        let expected = sign_clob_auth(auth.wallet.signer(), 137, timestamp, 7)
+            .await
+            .unwrap();
+        assert_eq!(request.header("poly_signature"), Some(expected.as_str()));
+
+        // The credentials are enough to build a full account
+        assert!(Account::new(PRIVATE_KEY, credentials).is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_create_api_key_posts() {
+        let transport = MockTransport::always(MockResponse::ok(CREDENTIALS));
+
+        let credentials = auth(&transport).create_api_key(0).send().await.unwrap();
+        assert_eq!(credentials.key, "key");
+
+        let request = &transport.requests()[0];
+        assert_eq!(request.method, reqwest::Method::POST);
+        assert_eq!(request.path(), "/auth/api-key");
+        assert_eq!(request.header("poly_nonce"), Some("0"));
+    }
+}
@@ -1,79 +1,381 @@
+use std::{collections::HashMap, fmt, sync::Arc};
+
 use polyte_core::QueryBuilder;
-use reqwest::Client;
+use reqwest::Method;
 use serde::{Deserialize, Serialize};
-use url::Url;
 
 use crate::{
-    request::{AuthMode, Request},
+    error::ClobError,
+    request::{AuthMode, ClobContext, Request},
     types::OrderSide,
 };
 
+/// Default tolerance used when comparing quotes against the order book
+pub const DEFAULT_QUOTE_TOLERANCE: f64 = 0.01;
+/// Intervals accepted by [`PricesHistory::interval`]
+pub const PRICE_HISTORY_INTERVALS: [&str; 6] = ["1m", "1h", "6h", "1d", "1w", "max"];
+
 /// Markets namespace for market-related operations
 #[derive(Clone)]
 pub struct Markets {
-    pub(crate) client: Client,
-    pub(crate) base_url: Url,
-    pub(crate) chain_id: u64,
+    pub(crate) context: Arc<ClobContext>,
 }
 
 impl Markets {
     /// Get a market by condition ID
     pub fn get(&self, condition_id: impl Into<String>) -> Request<Market> {
-        Request::get(
-            self.client.clone(),
-            self.base_url.clone(),
+        self.context.request(
+            Method::GET,
             format!("/markets/{}", urlencoding::encode(&condition_id.into())),
             AuthMode::None,
-            self.chain_id,
         )
     }
 
     /// List all markets
     pub fn list(&self) -> Request<ListMarketsResponse> {
-        Request::get(
-            self.client.clone(),
-            self.base_url.clone(),
-            "/markets",
-            AuthMode::None,
-            self.chain_id,
-        )
+        self.context
+            .request(Method::GET, "/markets", AuthMode::None)
     }
 
     /// Get order book for a token
     pub fn order_book(&self, token_id: impl Into<String>) -> Request<OrderBook> {
-        Request::get(
-            self.client.clone(),
-            self.base_url.clone(),
-            "/book",
-            AuthMode::None,
-            self.chain_id,
-        )
-        .query("token_id", token_id.into())
+        self.context
+            .request(Method::GET, "/book", AuthMode::None)
+            .query("token_id", token_id.into())
+    }
+
+    /// Get the order books of several tokens in one request.
+    ///
+    /// Books are returned in the order of `token_ids`. Fails if the server
+    /// reports an error for any token or leaves one out.
+    pub async fn order_books(&self, token_ids: Vec<String>) -> Result<Vec<OrderBook>, ClobError> {
+        if token_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let params: Vec<TokenParams<'_>> = token_ids
+            .iter()
+            .map(|token_id| TokenParams {
+                token_id,
+                side: None,
+            })
+            .collect();
+
+        let entries: Vec<BookEntry> = self.batch_request("/books").body(&params)?.send().await?;
+
+        align_books(&token_ids, entries)
     }
 
     /// Get price for a token and side
     pub fn price(&self, token_id: impl Into<String>, side: OrderSide) -> Request<PriceResponse> {
-        Request::get(
-            self.client.clone(),
-            self.base_url.clone(),
-            "/price",
-            AuthMode::None,
-            self.chain_id,
-        )
-        .query("token_id", token_id.into())
-        .query("side", side.to_string())
+        self.context
+            .request(Method::GET, "/price", AuthMode::None)
+            .query("token_id", token_id.into())
+            .query("side", side.to_string())
+    }
+
+    /// Get the buy and sell prices of a token, as `(buy, sell)`
+    pub async fn buy_sell_price(
+        &self,
+        token_id: impl Into<String>,
+    ) -> Result<(f64, f64), ClobError> {
+        let token_id = token_id.into();
+
+        let (buy, sell) = tokio::try_join!(
+            self.price(&token_id, OrderSide::Buy).send(),
+            self.price(&token_id, OrderSide::Sell).send(),
+        )?;
+
+        Ok((
+            parse_price(&token_id, &buy.price)?,
+            parse_price(&token_id, &sell.price)?,
+        ))
+    }
+
+    /// Get the prices of several tokens and sides in one request, keyed by
+    /// token ID then side
+    pub async fn prices(
+        &self,
+        queries: Vec<(String, OrderSide)>,
+    ) -> Result<HashMap<String, HashMap<OrderSide, f64>>, ClobError> {
+        if queries.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let params: Vec<TokenParams<'_>> = queries
+            .iter()
+            .map(|(token_id, side)| TokenParams {
+                token_id,
+                side: Some(*side),
+            })
+            .collect();
+
+        let response: HashMap<String, HashMap<OrderSide, String>> =
+            self.batch_request("/prices").body(&params)?.send().await?;
+
+        parse_prices(response)
+    }
+
+    /// Get the midpoint prices of several tokens in one request, keyed by
+    /// token ID
+    pub async fn midpoints(
+        &self,
+        token_ids: Vec<String>,
+    ) -> Result<HashMap<String, f64>, ClobError> {
+        if token_ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let params: Vec<TokenParams<'_>> = token_ids
+            .iter()
+            .map(|token_id| TokenParams {
+                token_id,
+                side: None,
+            })
+            .collect();
+
+        let response: HashMap<String, String> = self
+            .batch_request("/midpoints")
+            .body(&params)?
+            .send()
+            .await?;
+
+        parse_midpoints(response)
     }
 
     /// Get midpoint price for a token
     pub fn midpoint(&self, token_id: impl Into<String>) -> Request<MidpointResponse> {
-        Request::get(
-            self.client.clone(),
-            self.base_url.clone(),
-            "/midpoint",
-            AuthMode::None,
-            self.chain_id,
-        )
-        .query("token_id", token_id.into())
+        self.context
+            .request(Method::GET, "/midpoint", AuthMode::None)
+            .query("token_id", token_id.into())
+    }
+
+    /// Get the price history of a token
+    pub fn prices_history(&self, token_id: impl Into<String>) -> PricesHistory {
+        PricesHistory {
+            request: self
+                .context
+                .request(Method::GET, "/prices-history", AuthMode::None)
+                .query("market", token_id.into()),
+            interval: None,
+            start_ts: None,
+            end_ts: None,
+        }
+    }
+
+    /// Cross-check the order book, midpoint and best price for a token.
+    ///
+    /// Fetches all three concurrently and verifies they are internally consistent.
+    /// Inconsistencies (common during fast markets) are logged as warnings and
+    /// returned in the report rather than treated as errors.
+    pub async fn sanity_check(
+        &self,
+        token_id: impl Into<String>,
+    ) -> Result<QuoteSanity, ClobError> {
+        let token_id = token_id.into();
+
+        let (book, midpoint, price) = tokio::try_join!(
+            self.order_book(&token_id).send(),
+            self.midpoint(&token_id).send(),
+            self.price(&token_id, OrderSide::Buy).send(),
+        )?;
+
+        let report = QuoteSanity::check(&book, &midpoint, &price, DEFAULT_QUOTE_TOLERANCE);
+        for issue in &report.issues {
+            tracing::warn!("Inconsistent quotes for token {}: {}", token_id, issue);
+        }
+
+        Ok(report)
+    }
+
+    /// Unauthenticated POST of a batch of tokens
+    fn batch_request<T>(&self, path: &str) -> Request<T> {
+        self.context.request(Method::POST, path, AuthMode::None)
+    }
+}
+
+/// Request builder for a token's price history.
+///
+/// The window is either an `interval` ending now or an explicit
+/// `start_ts`/`end_ts` range; the API treats them as mutually exclusive.
+pub struct PricesHistory {
+    request: Request<PricesHistoryResponse>,
+    interval: Option<String>,
+    start_ts: Option<i64>,
+    end_ts: Option<i64>,
+}
+
+impl PricesHistory {
+    /// Set the duration ending now, one of [`PRICE_HISTORY_INTERVALS`]
+    pub fn interval(mut self, interval: impl Into<String>) -> Self {
+        self.interval = Some(interval.into());
+        self
+    }
+
+    /// Set the start of the range (Unix timestamp in seconds)
+    pub fn start_ts(mut self, start_ts: i64) -> Self {
+        self.start_ts = Some(start_ts);
+        self
+    }
+
+    /// Set the end of the range (Unix timestamp in seconds)
+    pub fn end_ts(mut self, end_ts: i64) -> Self {
+        self.end_ts = Some(end_ts);
+        self
+    }
+
+    /// Set the resolution of the series in minutes
+    pub fn fidelity(mut self, minutes: u32) -> Self {
+        self.request = self.request.query("fidelity", minutes);
+        self
+    }
+
+    /// Check the interval and range before sending
+    fn validate(&self) -> Result<(), ClobError> {
+        if self.interval.is_some() && (self.start_ts.is_some() || self.end_ts.is_some()) {
+            return Err(ClobError::validation(
+                "Interval cannot be combined with a start or end timestamp",
+            ));
+        }
+        if let Some(interval) = &self.interval {
+            if !PRICE_HISTORY_INTERVALS.contains(&interval.as_str()) {
+                return Err(ClobError::validation(format!(
+                    "Interval must be one of {}, got {:?}",
+                    PRICE_HISTORY_INTERVALS.join(", "),
+                    interval
+                )));
+            }
+        }
+        if let (Some(start), Some(end)) = (self.start_ts, self.end_ts) {
+            if start >= end {
+                return Err(ClobError::validation(format!(
+                    "Start timestamp must be before end timestamp, got {} >= {}",
+                    start, end
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Execute the request
+    pub async fn send(self) -> Result<Vec<PricePoint>, ClobError> {
+        self.validate()?;
+        let response = self
+            .request
+            .query_opt("interval", self.interval)
+            .query_opt("startTs", self.start_ts)
+            .query_opt("endTs", self.end_ts)
+            .send()
+            .await?;
+        Ok(response.history)
+    }
+}
+
+/// Price of a token at a point in time
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PricePoint {
+    /// Unix timestamp in seconds
+    pub t: i64,
+    /// Price
+    pub p: f64,
+}
+
+/// Price history response
+#[derive(Debug, Clone, Deserialize)]
+struct PricesHistoryResponse {
+    history: Vec<PricePoint>,
+}
+
+/// Result of cross-checking a token's order book against its quoted prices
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuoteSanity {
+    /// Highest bid in the order book
+    pub best_bid: Option<f64>,
+    /// Lowest ask in the order book
+    pub best_ask: Option<f64>,
+    /// Midpoint reported by the API
+    pub midpoint: Option<f64>,
+    /// Buy-side price reported by the API
+    pub price: Option<f64>,
+    /// Inconsistencies found between the values above
+    pub issues: Vec<QuoteIssue>,
+}
+
+impl QuoteSanity {
+    /// Compare an order book with the midpoint and buy-side price responses.
+    ///
+    /// `tolerance` is the absolute price difference allowed between values.
+    pub fn check(
+        book: &OrderBook,
+        midpoint: &MidpointResponse,
+        price: &PriceResponse,
+        tolerance: f64,
+    ) -> Self {
+        let best_bid = book.best_bid();
+        let best_ask = book.best_ask();
+        let mid = midpoint.mid.parse::<f64>().ok();
+        let quoted = price.price.parse::<f64>().ok();
+
+        let mut issues = Vec::new();
+
+        if let (Some(bid), Some(ask)) = (best_bid, best_ask) {
+            if bid > ask {
+                issues.push(QuoteIssue::CrossedBook { bid, ask });
+            } else if let Some(mid) = mid {
+                if mid < bid - tolerance || mid > ask + tolerance {
+                    issues.push(QuoteIssue::MidpointOutsideSpread { mid, bid, ask });
+                }
+            }
+        }
+
+        if let (Some(quoted), Some(bid)) = (quoted, best_bid) {
+            if (quoted - bid).abs() > tolerance {
+                issues.push(QuoteIssue::PriceMismatch {
+                    price: quoted,
+                    book: bid,
+                });
+            }
+        }
+
+        Self {
+            best_bid,
+            best_ask,
+            midpoint: mid,
+            price: quoted,
+            issues,
+        }
+    }
+
+    /// Whether no inconsistencies were found
+    pub fn is_consistent(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
+
+/// Inconsistency between a token's order book and quoted prices
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum QuoteIssue {
+    /// Best bid is above best ask
+    CrossedBook { bid: f64, ask: f64 },
+    /// Midpoint is not between best bid and best ask
+    MidpointOutsideSpread { mid: f64, bid: f64, ask: f64 },
+    /// Quoted price does not match the top of the book
+    PriceMismatch { price: f64, book: f64 },
+}
+
+impl fmt::Display for QuoteIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::CrossedBook { bid, ask } => {
+                write!(f, "crossed book: best bid {} above best ask {}", bid, ask)
+            }
+            Self::MidpointOutsideSpread { mid, bid, ask } => {
+                write!(f, "midpoint {} outside spread {} - {}", mid, bid, ask)
+            }
+            Self::PriceMismatch { price, book } => {
+                write!(f, "price {} does not match top of book {}", price, book)
+            }
+        }
     }
 }
 
@@ -96,6 +398,21 @@ pub struct Market {
     pub neg_risk: Option<bool>,
     pub neg_risk_market_id: Option<String>,
     pub enable_order_book: Option<bool>,
+    #[serde(default)]
+    pub accepting_orders: Option<bool>,
+    #[serde(default)]
+    pub rfq_enabled: Option<bool>,
+}
+
+impl Market {
+    /// Whether the market is open and currently accepting new orders
+    pub fn is_accepting_orders(&self) -> bool {
+        self.active
+            && !self.closed
+            && !self.archived
+            && self.enable_order_book != Some(false)
+            && self.accepting_orders != Some(false)
+    }
 }
 
 /// Markets list response
@@ -132,14 +449,488 @@ pub struct OrderBook {
     pub hash: String,
 }
 
+impl OrderBook {
+    /// Highest bid price
+    pub fn best_bid(&self) -> Option<f64> {
+        parse_levels(&self.bids)
+            .map(|(price, _)| price)
+            .reduce(f64::max)
+    }
+
+    /// Lowest ask price
+    pub fn best_ask(&self) -> Option<f64> {
+        parse_levels(&self.asks)
+            .map(|(price, _)| price)
+            .reduce(f64::min)
+    }
+
+    /// Difference between best ask and best bid, or `None` if a side is empty
+    pub fn spread(&self) -> Option<f64> {
+        Some(self.best_ask()? - self.best_bid()?)
+    }
+
+    /// Average of best bid and best ask, or `None` if a side is empty
+    pub fn midpoint(&self) -> Option<f64> {
+        Some((self.best_bid()? + self.best_ask()?) / 2.0)
+    }
+
+    /// Total size on the best `price_levels` levels of each side, as
+    /// `(bid_size, ask_size)`
+    pub fn depth_at(&self, price_levels: usize) -> (f64, f64) {
+        let mut bids: Vec<(f64, f64)> = parse_levels(&self.bids).collect();
+        let mut asks: Vec<(f64, f64)> = parse_levels(&self.asks).collect();
+        bids.sort_by(|a, b| b.0.total_cmp(&a.0));
+        asks.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        let depth = |levels: &[(f64, f64)]| -> f64 {
+            levels.iter().take(price_levels).map(|(_, size)| size).sum()
+        };
+        (depth(&bids), depth(&asks))
+    }
+}
+
+/// Parse price levels as `(price, size)`, skipping malformed ones
+fn parse_levels(levels: &[OrderLevel]) -> impl Iterator<Item = (f64, f64)> + '_ {
+    levels
+        .iter()
+        .filter_map(|level| Some((level.price.parse().ok()?, level.size.parse().ok()?)))
+}
+
 /// Price response
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PriceResponse {
     pub price: String,
 }
 
+impl PriceResponse {
+    /// Price as a number, or `None` if it doesn't parse
+    pub fn as_f64(&self) -> Option<f64> {
+        self.price.parse().ok()
+    }
+}
+
+/// Token of a batch request, with the side for batch prices
+#[derive(Serialize)]
+struct TokenParams<'a> {
+    token_id: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    side: Option<OrderSide>,
+}
+
+/// Entry of a batch order book response: a book, or the error for one token
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum BookEntry {
+    Book(OrderBook),
+    Error {
+        error: String,
+        #[serde(default, alias = "asset_id")]
+        token_id: Option<String>,
+    },
+}
+
+/// Match the books of a batch response to the requested tokens
+fn align_books(token_ids: &[String], entries: Vec<BookEntry>) -> Result<Vec<OrderBook>, ClobError> {
+    let mut books = HashMap::with_capacity(entries.len());
+    for entry in entries {
+        match entry {
+            BookEntry::Book(book) => {
+                books.insert(book.asset_id.clone(), book);
+            }
+            BookEntry::Error {
+                error,
+                token_id: Some(token_id),
+            } => {
+                return Err(ClobError::validation(format!(
+                    "Order book for token {} failed: {}",
+                    token_id, error
+                )));
+            }
+            BookEntry::Error { error, .. } => {
+                return Err(ClobError::validation(format!(
+                    "Order book request failed: {}",
+                    error
+                )));
+            }
+        }
+    }
+
+    token_ids
+        .iter()
+        .map(|token_id| {
+            books.get(token_id).cloned().ok_or_else(|| {
+                ClobError::validation(format!("No order book returned for token {}", token_id))
+            })
+        })
+        .collect()
+}
+
+/// Parse the prices of a batch prices response
+fn parse_prices(
+    response: HashMap<String, HashMap<OrderSide, String>>,
+) -> Result<HashMap<String, HashMap<OrderSide, f64>>, ClobError> {
+    response
+        .into_iter()
+        .map(|(token_id, sides)| {
+            let prices = sides
+                .into_iter()
+                .map(|(side, price)| Ok((side, parse_price(&token_id, &price)?)))
+                .collect::<Result<_, ClobError>>()?;
+            Ok((token_id, prices))
+        })
+        .collect()
+}
+
+/// Parse the prices of a batch midpoints response
+fn parse_midpoints(response: HashMap<String, String>) -> Result<HashMap<String, f64>, ClobError> {
+    response
+        .into_iter()
+        .map(|(token_id, mid)| {
+            let mid = parse_price(&token_id, &mid)?;
+            Ok((token_id, mid))
+        })
+        .collect()
+}
+
+fn parse_price(token_id: &str, price: &str) -> Result<f64, ClobError> {
+    price.parse().map_err(|_| {
+        ClobError::validation(format!("Invalid price {} for token {}", price, token_id))
+    })
+}
+
 /// Midpoint price response
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MidpointResponse {
     pub mid: String,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::Clock;
+
+    fn level(price: &str) -> OrderLevel {
+        OrderLevel {
+            price: price.to_string(),
+            size: "100".to_string(),
+        }
+    }
+
+    fn book(bids: &[&str], asks: &[&str]) -> OrderBook {
+        OrderBook {
+            market: "0xabc".to_string(),
+            asset_id: "123".to_string(),
+            bids: bids.iter().map(|p| level(p)).collect(),
+            asks: asks.iter().map(|p| level(p)).collect(),
+            timestamp: "0".to_string(),
+            hash: String::new(),
+        }
+    }
+
+    fn mid(mid: &str) -> MidpointResponse {
+        MidpointResponse {
+            mid: mid.to_string(),
+        }
+    }
+
+    fn price(price: &str) -> PriceResponse {
+        PriceResponse {
+            price: price.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_consistent_quotes() {
+        let report = QuoteSanity::check(
+            &book(&["0.48", "0.50"], &["0.54", "0.52"]),
+            &mid("0.51"),
+            &price("0.50"),
+            DEFAULT_QUOTE_TOLERANCE,
+        );
+
+        assert!(report.is_consistent());
+        assert_eq!(report.best_bid, Some(0.50));
+        assert_eq!(report.best_ask, Some(0.52));
+        assert_eq!(report.midpoint, Some(0.51));
+        assert_eq!(report.price, Some(0.50));
+    }
+
+    #[test]
+    fn test_stale_midpoint() {
+        let report = QuoteSanity::check(
+            &book(&["0.50"], &["0.52"]),
+            &mid("0.60"),
+            &price("0.50"),
+            DEFAULT_QUOTE_TOLERANCE,
+        );
+
+        assert_eq!(
+            report.issues,
+            vec![QuoteIssue::MidpointOutsideSpread {
+                mid: 0.60,
+                bid: 0.50,
+                ask: 0.52
+            }]
+        );
+    }
+
+    #[test]
+    fn test_stale_price() {
+        let report = QuoteSanity::check(
+            &book(&["0.50"], &["0.52"]),
+            &mid("0.51"),
+            &price("0.45"),
+            DEFAULT_QUOTE_TOLERANCE,
+        );
+
+        assert_eq!(
+            report.issues,
+            vec![QuoteIssue::PriceMismatch {
+                price: 0.45,
+                book: 0.50
+            }]
+        );
+    }
+
+    #[test]
+    fn test_crossed_book() {
+        let report = QuoteSanity::check(
+            &book(&["0.55"], &["0.52"]),
+            &mid("0.535"),
+            &price("0.55"),
+            DEFAULT_QUOTE_TOLERANCE,
+        );
+
+        assert_eq!(
+            report.issues,
+            vec![QuoteIssue::CrossedBook {
+                bid: 0.55,
+                ask: 0.52
+            }]
+        );
+    }
+
+    #[test]
+    fn test_empty_book() {
+        let report = QuoteSanity::check(
+            &book(&[], &[]),
+            &mid("0.5"),
+            &price("0.5"),
+            DEFAULT_QUOTE_TOLERANCE,
+        );
+
+        assert!(report.is_consistent());
+        assert_eq!(report.best_bid, None);
+        assert_eq!(report.best_ask, None);
+    }
+
+    fn prices_history() -> PricesHistory {
+        markets().prices_history("123")
+    }
+
+    #[test]
+    fn test_prices_history_validation() {
+        assert!(prices_history().interval("1d").validate().is_ok());
+        assert!(prices_history()
+            .start_ts(1_700_000_000)
+            .end_ts(1_700_086_400)
+            .validate()
+            .is_ok());
+
+        let err = prices_history().interval("2d").validate().unwrap_err();
+        assert!(err.to_string().contains("Interval must be one of"));
+
+        let err = prices_history()
+            .start_ts(1_700_086_400)
+            .end_ts(1_700_000_000)
+            .validate()
+            .unwrap_err();
+        assert!(err.to_string().contains("before end timestamp"));
+
+        for history in [
+            prices_history().interval("1d").start_ts(1_700_000_000),
+            prices_history().interval("1d").end_ts(1_700_086_400),
+        ] {
+            let err = history.validate().unwrap_err();
+            assert!(err.to_string().contains("cannot be combined"));
+        }
+    }
+
+    #[test]
+    fn test_prices_history_response() {
+        let response: PricesHistoryResponse = serde_json::from_str(
+            r#"{"history": [{"t": 1700000000, "p": 0.52}, {"t": 1700003600, "p": 0.545}]}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            response.history,
+            vec![
+                PricePoint {
+                    t: 1_700_000_000,
+                    p: 0.52
+                },
+                PricePoint {
+                    t: 1_700_003_600,
+                    p: 0.545
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_book_helpers() {
+        let mut book = book(&["0.48", "0.50", "0.49"], &["0.54", "0.52"]);
+        book.bids[1].size = "20".to_string();
+        book.asks[1].size = "5.5".to_string();
+
+        assert_eq!(book.best_bid(), Some(0.50));
+        assert_eq!(book.best_ask(), Some(0.52));
+        assert!((book.spread().unwrap() - 0.02).abs() < 1e-9);
+        assert!((book.midpoint().unwrap() - 0.51).abs() < 1e-9);
+
+        assert_eq!(book.depth_at(1), (20.0, 5.5));
+        assert_eq!(book.depth_at(2), (120.0, 105.5));
+        assert_eq!(book.depth_at(10), (220.0, 105.5));
+        assert_eq!(book.depth_at(0), (0.0, 0.0));
+    }
+
+    #[test]
+    fn test_book_helpers_one_sided() {
+        let book = book(&["0.40", "bad"], &[]);
+        assert_eq!(book.best_bid(), Some(0.40));
+        assert_eq!(book.best_ask(), None);
+        assert_eq!(book.spread(), None);
+        assert_eq!(book.midpoint(), None);
+        assert_eq!(book.depth_at(5), (100.0, 0.0));
+    }
+
+    #[test]
+    fn test_order_books_response() {
+        let body = r#"[
+            {"market": "0xabc", "asset_id": "2", "bids": [{"price": "0.4", "size": "10"}],
+             "asks": [], "timestamp": "1700000000", "hash": "b"},
+            {"market": "0xabc", "asset_id": "1", "bids": [],
+             "asks": [{"price": "0.6", "size": "5"}], "timestamp": "1700000000", "hash": "a"}
+        ]"#;
+        let entries: Vec<BookEntry> = serde_json::from_str(body).unwrap();
+        let token_ids = ["1".to_string(), "2".to_string()];
+
+        let books = align_books(&token_ids, entries).unwrap();
+        assert_eq!(books.len(), 2);
+        assert_eq!(books[0].asset_id, "1");
+        assert_eq!(books[0].best_ask(), Some(0.6));
+        assert_eq!(books[1].asset_id, "2");
+        assert_eq!(books[1].best_bid(), Some(0.4));
+    }
+
+    #[test]
+    fn test_order_books_errors() {
+        let token_ids = ["1".to_string(), "2".to_string()];
+
+        let body = r#"[
+            {"market": "0xabc", "asset_id": "1", "bids": [], "asks": [], "timestamp": "0", "hash": ""},
+            {"error": "No orderbook exists for the requested token id", "token_id": "2"}
+        ]"#;
+        let err = align_books(&token_ids, serde_json::from_str(body).unwrap()).unwrap_err();
+        assert!(err.to_string().contains("token 2"));
+
+        let body = r#"[
+            {"market": "0xabc", "asset_id": "1", "bids": [], "asks": [], "timestamp": "0", "hash": ""}
+        ]"#;
+        let err = align_books(&token_ids, serde_json::from_str(body).unwrap()).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("No order book returned for token 2"));
+    }
+
+    #[test]
+    fn test_prices_response() {
+        let body = r#"{
+            "123": {"BUY": "0.45", "SELL": "0.47"},
+            "456": {"BUY": "0.12"}
+        }"#;
+        let prices = parse_prices(serde_json::from_str(body).unwrap()).unwrap();
+
+        assert_eq!(prices.len(), 2);
+        assert_eq!(prices["123"][&OrderSide::Buy], 0.45);
+        assert_eq!(prices["123"][&OrderSide::Sell], 0.47);
+        assert_eq!(prices["456"][&OrderSide::Buy], 0.12);
+        assert!(!prices["456"].contains_key(&OrderSide::Sell));
+    }
+
+    #[test]
+    fn test_midpoints_response() {
+        let body = r#"{"123": "0.46", "456": "0.125"}"#;
+        let midpoints = parse_midpoints(serde_json::from_str(body).unwrap()).unwrap();
+        assert_eq!(midpoints.len(), 2);
+        assert_eq!(midpoints["123"], 0.46);
+        assert_eq!(midpoints["456"], 0.125);
+
+        let body = r#"{"123": "n/a"}"#;
+        assert!(parse_midpoints(serde_json::from_str(body).unwrap()).is_err());
+    }
+
+    #[test]
+    fn test_batch_params_body() {
+        let params = [
+            TokenParams {
+                token_id: "123",
+                side: Some(OrderSide::Sell),
+            },
+            TokenParams {
+                token_id: "456",
+                side: None,
+            },
+        ];
+        assert_eq!(
+            serde_json::to_value(params).unwrap(),
+            serde_json::json!([
+                {"token_id": "123", "side": "SELL"},
+                {"token_id": "456"}
+            ])
+        );
+    }
+
+    fn markets() -> Markets {
+        let http = polyte_core::HttpClientBuilder::new("https://clob.polymarket.com")
+            .build()
+            .unwrap();
+        Markets {
+            context: Arc::new(ClobContext::new(http, 137, Clock::default())),
+        }
+    }
+
+    #[test]
+    fn test_price_query() {
+        let request = markets().price("123", OrderSide::Sell);
+        assert_eq!(request.path, "/price");
+        assert_eq!(
+            request.query,
+            [
+                ("token_id".to_string(), "123".to_string()),
+                ("side".to_string(), "SELL".to_string()),
+            ]
+        );
+
+        let request = markets().price("123", OrderSide::Buy);
+        assert_eq!(request.query[1], ("side".to_string(), "BUY".to_string()));
+    }
+
+    #[test]
+    fn test_order_side_round_trip() {
+        for side in [OrderSide::Buy, OrderSide::Sell] {
+            assert_eq!(side.to_string().parse::<OrderSide>().unwrap(), side);
+        }
+        assert_eq!(" sell ".parse::<OrderSide>().unwrap(), OrderSide::Sell);
+        assert!("hold".parse::<OrderSide>().is_err());
+    }
+
+    #[test]
+    fn test_price_as_f64() {
+        assert_eq!(price("0.52").as_f64(), Some(0.52));
+        assert_eq!(price("").as_f64(), None);
+
+        let response: PriceResponse = serde_json::from_str(r#"{"price": "0.48"}"#).unwrap();
+        assert_eq!(response.as_f64(), Some(0.48));
+    }
+}
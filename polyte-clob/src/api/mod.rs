@@ -1,9 +1,11 @@
 //! API namespace modules for organizing CLOB operations
 
 pub mod account;
+pub mod auth;
 pub mod markets;
 pub mod orders;
 
-pub use account::AccountApi;
+pub use account::{AccountApi, AssetType, BalanceAllowanceRequest, TradesRequest};
+pub use auth::Auth;
 pub use markets::Markets;
-pub use orders::{CancelOrderRequest, Orders};
+pub use orders::{CancelOrderRequest, Orders, OrdersScoringRequest};
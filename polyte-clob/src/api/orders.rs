@@ -1,63 +1,148 @@
-use reqwest::Client;
+use std::{collections::HashMap, sync::Arc};
+
+use polyte_core::{ApiError, QueryBuilder};
+use reqwest::Method;
 use serde::{Deserialize, Serialize};
-use url::Url;
 
 use crate::{
-    account::{Credentials, Signer, Wallet},
     error::ClobError,
-    request::{AuthMode, Request},
-    types::SignedOrder,
+    request::{AuthMode, ClobContext, Request},
+    types::{OrderSide, SignedOrder},
 };
 
+/// Maximum number of order IDs accepted by a single cancel request
+pub const MAX_CANCEL_BATCH: usize = 3000;
+
 /// Orders namespace for order-related operations
 #[derive(Clone)]
 pub struct Orders {
-    pub(crate) client: Client,
-    pub(crate) base_url: Url,
-    pub(crate) wallet: Wallet,
-    pub(crate) credentials: Credentials,
-    pub(crate) signer: Signer,
-    pub(crate) chain_id: u64,
+    pub(crate) context: Arc<ClobContext>,
+    pub(crate) auth: AuthMode,
 }
 
 impl Orders {
     /// List user's orders
     pub fn list(&self) -> Request<Vec<OpenOrder>> {
-        Request::get(
-            self.client.clone(),
-            self.base_url.clone(),
-            "/data/orders",
-            AuthMode::L2 {
-                address: self.wallet.address(),
-                credentials: self.credentials.clone(),
-                signer: self.signer.clone(),
-            },
-            self.chain_id,
-        )
+        self.context
+            .request(Method::GET, "/data/orders", self.auth.clone())
     }
 
     /// Cancel an order
     pub fn cancel(&self, order_id: impl Into<String>) -> CancelOrderRequest {
         CancelOrderRequest {
-            client: self.client.clone(),
-            base_url: self.base_url.clone(),
-            auth: AuthMode::L2 {
-                address: self.wallet.address(),
-                credentials: self.credentials.clone(),
-                signer: self.signer.clone(),
-            },
-            chain_id: self.chain_id,
+            request: self
+                .context
+                .request(Method::DELETE, "/order", self.auth.clone()),
             order_id: order_id.into(),
         }
     }
+
+    /// Cancel several orders in a single request
+    pub fn cancel_many(
+        &self,
+        order_ids: impl IntoIterator<Item = impl Into<String>>,
+    ) -> CancelOrdersRequest {
+        CancelOrdersRequest {
+            request: self
+                .context
+                .request(Method::DELETE, "/orders", self.auth.clone()),
+            order_ids: order_ids.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// Cancel all open orders
+    pub fn cancel_all(&self) -> Request<CancelResponse> {
+        self.context
+            .request(Method::DELETE, "/cancel-all", self.auth.clone())
+    }
+
+    /// Cancel all open orders in a market
+    pub fn cancel_market(&self, condition_id: impl Into<String>) -> CancelMarketOrdersRequest {
+        CancelMarketOrdersRequest {
+            request: self.context.request(
+                Method::DELETE,
+                "/cancel-market-orders",
+                self.auth.clone(),
+            ),
+            market: condition_id.into(),
+        }
+    }
+
+    /// Check whether a resting order is scoring for liquidity rewards
+    pub fn scoring(&self, order_id: impl Into<String>) -> Request<OrderScoring> {
+        self.context
+            .request(Method::GET, "/order-scoring", self.auth.clone())
+            .query("order_id", order_id.into())
+    }
+
+    /// Check whether several resting orders are scoring for liquidity rewards
+    pub fn scoring_many(
+        &self,
+        order_ids: impl IntoIterator<Item = impl Into<String>>,
+    ) -> OrdersScoringRequest {
+        OrdersScoringRequest {
+            request: self
+                .context
+                .request(Method::POST, "/orders-scoring", self.auth.clone()),
+            order_ids: order_ids.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// Look up a single order by ID, to poll its fill state.
+    ///
+    /// Returns `Ok(None)` when the CLOB no longer knows the order, as with
+    /// some filled or canceled orders.
+    pub async fn get(&self, order_id: &str) -> Result<Option<OpenOrder>, ClobError> {
+        let result = self
+            .context
+            .request::<Option<OpenOrder>>(
+                Method::GET,
+                format!("/data/order/{}", order_id),
+                self.auth.clone(),
+            )
+            .send()
+            .await;
+
+        match result {
+            Err(ClobError::Api(ApiError::Api { status: 404, .. })) => Ok(None),
+            result => result,
+        }
+    }
+
+    /// List open orders in a market
+    pub async fn by_market(&self, condition_id: &str) -> Result<Vec<OpenOrder>, ClobError> {
+        let orders = self.list().query("market", condition_id).send().await?;
+        Ok(orders
+            .into_iter()
+            .filter(|order| order.market == condition_id)
+            .collect())
+    }
+
+    /// Cancel every open order on one outcome of a market (e.g. "Yes")
+    pub async fn cancel_by_market_outcome(
+        &self,
+        condition_id: &str,
+        outcome: &str,
+    ) -> Result<CancelResponse, ClobError> {
+        let order_ids: Vec<String> = self
+            .by_market(condition_id)
+            .await?
+            .into_iter()
+            .filter(|order| order.is_outcome(condition_id, outcome))
+            .map(|order| order.id)
+            .collect();
+
+        if order_ids.is_empty() {
+            return Ok(CancelResponse::default());
+        }
+
+        self.cancel_many(order_ids).send().await
+    }
 }
 
 /// Request builder for canceling an order
 pub struct CancelOrderRequest {
-    client: Client,
-    base_url: Url,
-    auth: AuthMode,
-    chain_id: u64,
+    request: Request<CancelResponse>,
     order_id: String,
 }
 
@@ -74,19 +159,78 @@ impl CancelOrderRequest {
             order_id: self.order_id,
         };
 
-        Request::delete(
-            self.client,
-            self.base_url,
-            "/order",
-            self.auth,
-            self.chain_id,
-        )
-        .body(&request)?
-        .send()
-        .await
+        self.request.body(&request)?.send().await
+    }
+}
+
+/// Request builder for canceling several orders
+pub struct CancelOrdersRequest {
+    request: Request<CancelResponse>,
+    order_ids: Vec<String>,
+}
+
+impl CancelOrdersRequest {
+    /// Order IDs that will be cancelled
+    pub fn order_ids(&self) -> &[String] {
+        &self.order_ids
+    }
+
+    /// Execute the cancel request
+    pub async fn send(self) -> Result<CancelResponse, ClobError> {
+        self.request.body(&self.order_ids)?.send().await
+    }
+}
+
+/// Request builder for canceling all orders in a market
+pub struct CancelMarketOrdersRequest {
+    request: Request<CancelResponse>,
+    market: String,
+}
+
+impl CancelMarketOrdersRequest {
+    /// Execute the cancel request
+    pub async fn send(self) -> Result<CancelResponse, ClobError> {
+        #[derive(serde::Serialize)]
+        struct CancelMarketRequest {
+            market: String,
+        }
+
+        let request = CancelMarketRequest {
+            market: self.market,
+        };
+
+        self.request.body(&request)?.send().await
+    }
+}
+
+/// Request builder for checking whether several orders are scoring
+pub struct OrdersScoringRequest {
+    request: Request<OrdersScoring>,
+    order_ids: Vec<String>,
+}
+
+impl OrdersScoringRequest {
+    /// Order IDs that will be checked
+    pub fn order_ids(&self) -> &[String] {
+        &self.order_ids
+    }
+
+    /// Execute the request, returning the scoring status keyed by order ID
+    pub async fn send(self) -> Result<OrdersScoring, ClobError> {
+        self.request.body(&self.order_ids)?.send().await
     }
 }
 
+/// Scoring status of a single order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OrderScoring {
+    /// Whether the order currently qualifies for liquidity rewards
+    pub scoring: bool,
+}
+
+/// Scoring status of several orders, keyed by order ID
+pub type OrdersScoring = HashMap<String, bool>;
+
 /// Open order from API
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all(deserialize = "camelCase"))]
@@ -94,28 +238,427 @@ pub struct OpenOrder {
     pub id: String,
     pub market: String,
     pub asset_id: String,
+    /// Outcome label of the order's token (e.g. "Yes")
+    #[serde(default)]
+    pub outcome: Option<String>,
     #[serde(flatten)]
     pub order: SignedOrder,
     pub status: String,
+    /// Size filled so far
+    #[serde(default, alias = "size_matched")]
+    pub size_matched: Option<String>,
+    /// IDs of the trades filling the order
+    #[serde(default, alias = "associate_trades")]
+    pub associate_trades: Vec<String>,
     pub created_at: String,
     pub updated_at: Option<String>,
 }
 
+impl OpenOrder {
+    /// Whether the order belongs to `outcome` (case-insensitive) of market `condition_id`
+    pub fn is_outcome(&self, condition_id: &str, outcome: &str) -> bool {
+        self.market == condition_id
+            && self
+                .outcome
+                .as_deref()
+                .is_some_and(|o| o.eq_ignore_ascii_case(outcome))
+    }
+}
+
 /// Response from posting an order
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all(deserialize = "camelCase"))]
 pub struct OrderResponse {
     pub success: bool,
     pub error_msg: Option<String>,
+    #[serde(alias = "orderID")]
     pub order_id: Option<String>,
     #[serde(default)]
     pub transaction_hashes: Vec<String>,
+    /// Order status after placement (e.g. "live", "matched", "delayed")
+    #[serde(default)]
+    pub status: Option<String>,
+    /// Amount given by the maker in matched fills (USDC for buys, shares for sells)
+    #[serde(default)]
+    pub making_amount: Option<String>,
+    /// Amount received by the maker in matched fills (shares for buys, USDC for sells)
+    #[serde(default)]
+    pub taking_amount: Option<String>,
 }
 
-/// Response from canceling an order
-#[derive(Debug, Clone, Serialize, Deserialize)]
+impl OrderResponse {
+    /// Split an order of `size` shares into the matched and resting parts
+    pub fn breakdown(&self, side: OrderSide, size: f64) -> PlacedOrder {
+        let amount = |value: &Option<String>| {
+            value
+                .as_deref()
+                .and_then(|v| v.parse::<f64>().ok())
+                .unwrap_or(0.0)
+        };
+        let making = amount(&self.making_amount);
+        let taking = amount(&self.taking_amount);
+
+        let (shares, usdc) = match side {
+            OrderSide::Buy => (taking, making),
+            OrderSide::Sell => (making, taking),
+        };
+
+        PlacedOrder {
+            order_id: self.order_id.clone(),
+            matched_size: shares,
+            resting_size: (size - shares).max(0.0),
+            avg_fill_price: (shares > 0.0).then(|| usdc / shares),
+        }
+    }
+}
+
+/// Matched vs. resting breakdown of a placed order
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlacedOrder {
+    pub order_id: Option<String>,
+    /// Shares matched on placement
+    pub matched_size: f64,
+    /// Shares left resting on the book
+    pub resting_size: f64,
+    /// Average fill price of the matched shares, if any matched
+    pub avg_fill_price: Option<f64>,
+}
+
+/// Response from canceling one or more orders
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all(deserialize = "camelCase"))]
 pub struct CancelResponse {
+    #[serde(default)]
     pub success: bool,
     pub error_msg: Option<String>,
+    /// IDs of cancelled orders
+    #[serde(default)]
+    pub canceled: Vec<String>,
+    /// IDs of orders that could not be cancelled, with the reason
+    #[serde(default, alias = "not_canceled")]
+    pub not_canceled: HashMap<String, String>,
+}
+
+impl CancelResponse {
+    /// Fold the response of another cancel request into this one
+    pub fn merge(&mut self, other: CancelResponse) {
+        self.success &= other.success;
+        self.error_msg = self.error_msg.take().or(other.error_msg);
+        self.canceled.extend(other.canceled);
+        self.not_canceled.extend(other.not_canceled);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use polyte_core::testing::{MockResponse, MockTransport};
+    use reqwest::Method;
+
+    use super::*;
+    use crate::{account::Signer, testing::account, Clob, ClobBuilder};
+
+    fn orders() -> Orders {
+        Clob::from_account(account()).unwrap().orders()
+    }
+
+    fn clob(transport: &Arc<MockTransport>) -> Clob {
+        ClobBuilder::new(account())
+            .transport(transport.clone())
+            .build()
+            .unwrap()
+    }
+
+    fn open_order(id: &str, market: &str, outcome: Option<&str>) -> OpenOrder {
+        serde_json::from_value(open_order_json(id, market, outcome)).unwrap()
+    }
+
+    fn open_order_json(id: &str, market: &str, outcome: Option<&str>) -> serde_json::Value {
+        let mut json = serde_json::json!({
+            "id": id,
+            "market": market,
+            "assetId": "123",
+            "salt": "1",
+            "maker": "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266",
+            "signer": "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266",
+            "taker": "0x0000000000000000000000000000000000000000",
+            "tokenId": "123",
+            "makerAmount": "52000000",
+            "takerAmount": "100000000",
+            "expiration": "0",
+            "nonce": "0",
+            "feeRateBps": "0",
+            "side": "BUY",
+            "signatureType": "eoa",
+            "signature": "0x",
+            "status": "LIVE",
+            "createdAt": "0"
+        });
+        if let Some(outcome) = outcome {
+            json["outcome"] = outcome.into();
+        }
+        json
+    }
+
+    #[test]
+    fn test_is_outcome() {
+        let order = open_order("1", "0xabc", Some("Yes"));
+        assert!(order.is_outcome("0xabc", "Yes"));
+        assert!(order.is_outcome("0xabc", "yes"));
+        assert!(!order.is_outcome("0xabc", "No"));
+        assert!(!order.is_outcome("0xdef", "Yes"));
+        assert!(!open_order("2", "0xabc", None).is_outcome("0xabc", "Yes"));
+    }
+
+    #[tokio::test]
+    async fn test_get_live_order() {
+        let mut order = open_order_json("0xabc", "0xdef", Some("Yes"));
+        order["size_matched"] = "40".into();
+        order["associate_trades"] = serde_json::json!(["trade-1"]);
+        let transport = MockTransport::always(MockResponse::json(&order));
+        let clob = clob(&transport);
+
+        let order = clob.orders().get("0xabc").await.unwrap().unwrap();
+        assert_eq!(order.id, "0xabc");
+        assert_eq!(order.status, "LIVE");
+        assert_eq!(order.size_matched.as_deref(), Some("40"));
+        assert_eq!(order.associate_trades, vec!["trade-1"]);
+
+        let request = &transport.requests()[0];
+        assert_eq!(request.method, Method::GET);
+        assert_eq!(request.path(), "/data/order/0xabc");
+        assert_eq!(request.header("poly_api_key"), Some("test_key"));
+    }
+
+    #[tokio::test]
+    async fn test_malformed_body_is_decode_error() {
+        let transport = MockTransport::always(MockResponse::ok(r#"[{"id": 1}]"#));
+        let clob = clob(&transport);
+
+        let err = clob.orders().list().send().await.unwrap_err();
+        assert!(matches!(err, ClobError::Decode { ref body, .. } if body == r#"[{"id": 1}]"#));
+    }
+
+    #[tokio::test]
+    async fn test_get_missing_order_is_none() {
+        let transport =
+            MockTransport::always(MockResponse::status(404, r#"{"error": "order not found"}"#));
+        let clob = clob(&transport);
+
+        assert!(clob.orders().get("0xabc").await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_cancel_sends_signed_delete() {
+        let transport =
+            MockTransport::always(MockResponse::ok(r#"{"success": true, "errorMsg": null}"#));
+        let clob = clob(&transport);
+
+        let response = clob.orders().cancel("0xabc").send().await.unwrap();
+        assert!(response.success);
+
+        let request = &transport.requests()[0];
+        assert_eq!(request.method, Method::DELETE);
+        assert_eq!(request.path(), "/order");
+
+        let body = request.body_text();
+        assert_eq!(body, r#"{"orderID":"0xabc"}"#);
+
+        // The signature must cover the exact body that was sent
+        let timestamp: u64 = request.header("poly_timestamp").unwrap().parse().unwrap();
+        let message = Signer::create_message(timestamp, "DELETE", "/order", Some(body));
+        let expected = Signer::new("c2VjcmV0").unwrap().sign(&message).unwrap();
+
+        assert_eq!(request.header("poly_signature"), Some(expected.as_str()));
+        assert_eq!(request.header("poly_api_key"), Some("test_key"));
+        assert_eq!(request.header("poly_passphrase"), Some("test_pass"));
+        assert_eq!(
+            request.header("poly_address"),
+            Some(format!("{:?}", account().address()).as_str())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_cancel_market_sends_market_body() {
+        let transport = MockTransport::always(MockResponse::ok(
+            r#"{"canceled": ["0x1"], "not_canceled": {}}"#,
+        ));
+        let clob = clob(&transport);
+
+        let response = clob.orders().cancel_market("0xabc").send().await.unwrap();
+        assert_eq!(response.canceled, ["0x1"]);
+
+        let request = &transport.requests()[0];
+        assert_eq!(request.method, Method::DELETE);
+        assert_eq!(request.path(), "/cancel-market-orders");
+        assert_eq!(request.body_text(), r#"{"market":"0xabc"}"#);
+    }
+
+    #[tokio::test]
+    async fn test_cancel_all_sends_delete() {
+        let transport =
+            MockTransport::always(MockResponse::ok(r#"{"canceled": [], "not_canceled": {}}"#));
+        let clob = clob(&transport);
+
+        clob.orders().cancel_all().send().await.unwrap();
+
+        let request = &transport.requests()[0];
+        assert_eq!(request.method, Method::DELETE);
+        assert_eq!(request.path(), "/cancel-all");
+        assert!(request.header("poly_signature").is_some());
+    }
+
+    #[test]
+    fn test_partial_cancel_response() {
+        let response: CancelResponse = serde_json::from_str(
+            r#"{"canceled": ["0x1", "0x2"], "not_canceled": {"0x3": "order not found"}}"#,
+        )
+        .unwrap();
+
+        assert_eq!(response.canceled, ["0x1", "0x2"]);
+        assert_eq!(
+            response.not_canceled.get("0x3").map(String::as_str),
+            Some("order not found")
+        );
+        assert!(response.error_msg.is_none());
+    }
+
+    #[test]
+    fn test_single_cancel_response() {
+        let response: CancelResponse =
+            serde_json::from_str(r#"{"success": true, "errorMsg": null}"#).unwrap();
+
+        assert!(response.success);
+        assert!(response.canceled.is_empty());
+        assert!(response.not_canceled.is_empty());
+    }
+
+    #[test]
+    fn test_cancel_many_request() {
+        let request = orders().cancel_many(["1", "2"]);
+        assert_eq!(request.order_ids(), ["1".to_string(), "2".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_scoring_sends_order_id() {
+        let transport = MockTransport::always(MockResponse::ok(r#"{"scoring": true}"#));
+        let clob = clob(&transport);
+
+        let scoring = clob.orders().scoring("0xabc").send().await.unwrap();
+        assert!(scoring.scoring);
+
+        let request = &transport.requests()[0];
+        assert_eq!(request.method, Method::GET);
+        assert_eq!(request.path(), "/order-scoring");
+        assert_eq!(request.query("order_id").as_deref(), Some("0xabc"));
+        assert!(request.header("poly_signature").is_some());
+    }
+
+    #[tokio::test]
+    async fn test_scoring_many_sends_ids_and_parses_map() {
+        let transport = MockTransport::always(MockResponse::ok(r#"{"0x1": true, "0x2": false}"#));
+        let clob = clob(&transport);
+
+        let scoring = clob
+            .orders()
+            .scoring_many(["0x1", "0x2"])
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(scoring.len(), 2);
+        assert_eq!(scoring.get("0x1"), Some(&true));
+        assert_eq!(scoring.get("0x2"), Some(&false));
+
+        let request = &transport.requests()[0];
+        assert_eq!(request.method, Method::POST);
+        assert_eq!(request.path(), "/orders-scoring");
+        assert_eq!(request.body_text(), r#"["0x1","0x2"]"#);
+    }
+
+    #[test]
+    fn test_partial_fill_breakdown() {
+        let response: OrderResponse = serde_json::from_str(
+            r#"{
+                "success": true,
+                "errorMsg": "",
+                "orderID": "0xabc",
+                "transactionsHashes": [],
+                "status": "live",
+                "makingAmount": "20.8",
+                "takingAmount": "40"
+            }"#,
+        )
+        .unwrap();
+
+        let placed = response.breakdown(OrderSide::Buy, 100.0);
+        assert_eq!(placed.order_id.as_deref(), Some("0xabc"));
+        assert_eq!(placed.matched_size, 40.0);
+        assert_eq!(placed.resting_size, 60.0);
+        assert!((placed.avg_fill_price.unwrap() - 0.52).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_sell_breakdown() {
+        let response: OrderResponse = serde_json::from_str(
+            r#"{"success": true, "orderID": "0xabc", "makingAmount": "10", "takingAmount": "4.5"}"#,
+        )
+        .unwrap();
+
+        let placed = response.breakdown(OrderSide::Sell, 10.0);
+        assert_eq!(placed.matched_size, 10.0);
+        assert_eq!(placed.resting_size, 0.0);
+        assert!((placed.avg_fill_price.unwrap() - 0.45).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_unmatched_breakdown() {
+        let response: OrderResponse =
+            serde_json::from_str(r#"{"success": true, "orderID": "0xabc", "status": "live"}"#)
+                .unwrap();
+
+        let placed = response.breakdown(OrderSide::Buy, 25.0);
+        assert_eq!(placed.matched_size, 0.0);
+        assert_eq!(placed.resting_size, 25.0);
+        assert_eq!(placed.avg_fill_price, None);
+    }
+
+    #[tokio::test]
+    async fn test_cancel_orders_chunked() {
+        // Report every ID of each request as cancelled
+        let transport = MockTransport::new(|request| {
+            let ids: Vec<String> = serde_json::from_value(request.body_json()).unwrap();
+            MockResponse::json(&serde_json::json!({ "canceled": ids, "not_canceled": {} }))
+        });
+        let clob = clob(&transport);
+
+        let ids: Vec<String> = (0..250).map(|i| format!("0x{:x}", i)).collect();
+        let response = clob.cancel_orders_chunked(ids.clone(), 100).await.unwrap();
+        assert_eq!(response.canceled, ids);
+        assert!(response.not_canceled.is_empty());
+
+        let mut sizes = Vec::new();
+        for request in transport.requests() {
+            assert_eq!(request.method, Method::DELETE);
+            assert_eq!(request.path(), "/orders");
+            sizes.push(request.body_json().as_array().unwrap().len());
+        }
+        assert_eq!(sizes, vec![100, 100, 50]);
+    }
+
+    #[test]
+    fn test_cancel_response_merge() {
+        let mut merged = CancelResponse {
+            canceled: vec!["0x1".to_string()],
+            ..Default::default()
+        };
+        merged.merge(CancelResponse {
+            canceled: vec!["0x2".to_string()],
+            not_canceled: HashMap::from([("0x3".to_string(), "not found".to_string())]),
+            ..Default::default()
+        });
+
+        assert_eq!(merged.canceled, vec!["0x1", "0x2"]);
+        assert_eq!(merged.not_canceled["0x3"], "not found");
+    }
 }
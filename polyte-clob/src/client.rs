@@ -1,25 +1,45 @@
-use polyte_core::{HttpClient, HttpClientBuilder, DEFAULT_POOL_SIZE, DEFAULT_TIMEOUT_MS};
-use reqwest::Client;
-use url::Url;
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    sync::{Arc, Mutex, PoisonError},
+    time::{Duration, Instant},
+};
+
+use alloy::primitives::{Address, U256};
+use polyte_core::{ApiError, HttpClientBuilder, HttpTransport, RateLimit, RetryPolicy};
+use reqwest::Method;
 
 use crate::{
     account::{Account, Credentials},
-    api::{account::AccountApi, orders::OrderResponse, Markets, Orders},
-    core::chain::Chain,
+    api::{
+        account::{AccountApi, Trade},
+        auth::Auth,
+        markets::{Market, OrderBook},
+        orders::{CancelResponse, OrderResponse, PlacedOrder, MAX_CANCEL_BATCH},
+        Markets, Orders,
+    },
+    core::{
+        chain::Chain,
+        positions::{PositionRequest, PositionTransaction},
+    },
     error::ClobError,
-    request::{AuthMode, Request},
+    request::{AuthMode, ClobContext},
+    risk::RiskConfig,
     types::*,
-    utils::{calculate_order_amounts, current_timestamp, generate_salt},
+    utils::{calculate_order_amounts, current_timestamp, generate_salt, Clock},
 };
 
-const DEFAULT_BASE_URL: &str = "https://clob.polymarket.com";
+/// How long a fetched fee rate is reused by default
+pub const DEFAULT_FEE_RATE_TTL: Duration = Duration::from_secs(60);
+/// Delay between trade status checks in [`Clob::await_settlement`]
+pub const SETTLEMENT_POLL_INTERVAL: Duration = Duration::from_secs(1);
 
 #[derive(Clone)]
 pub struct Clob {
-    pub(crate) client: Client,
-    pub(crate) base_url: Url,
-    pub(crate) chain_id: u64,
+    pub(crate) context: Arc<ClobContext>,
     pub(crate) account: Account,
+    pub(crate) risk: Option<RiskConfig>,
+    pub(crate) fee_rate: Arc<Mutex<Option<(FeeRate, Instant)>>>,
+    pub(crate) fee_rate_ttl: Duration,
 }
 
 impl Clob {
@@ -50,104 +70,263 @@ impl Clob {
         &self.account
     }
 
+    /// Rate limit reported by the most recent response that carried
+    /// `x-ratelimit-*` headers, if any
+    pub fn last_rate_limit(&self) -> Option<RateLimit> {
+        self.context.http.rate_limit.last()
+    }
+
     /// Get markets namespace
     pub fn markets(&self) -> Markets {
         Markets {
-            client: self.client.clone(),
-            base_url: self.base_url.clone(),
-            chain_id: self.chain_id,
+            context: self.context.clone(),
         }
     }
 
     /// Get orders namespace
     pub fn orders(&self) -> Orders {
         Orders {
-            client: self.client.clone(),
-            base_url: self.base_url.clone(),
-            wallet: self.account.wallet().clone(),
-            credentials: self.account.credentials().clone(),
-            signer: self.account.signer().clone(),
-            chain_id: self.chain_id,
+            context: self.context.clone(),
+            auth: self.context.l2(&self.account),
         }
     }
 
     /// Get account API namespace
     pub fn account_api(&self) -> AccountApi {
         AccountApi {
-            client: self.client.clone(),
-            base_url: self.base_url.clone(),
+            context: self.context.clone(),
+            auth: self.context.l2(&self.account),
+        }
+    }
+
+    /// Get API key namespace, authenticated with the wallet (L1)
+    pub fn auth(&self) -> Auth {
+        Auth {
+            context: self.context.clone(),
             wallet: self.account.wallet().clone(),
-            credentials: self.account.credentials().clone(),
-            signer: self.account.signer().clone(),
-            chain_id: self.chain_id,
         }
     }
 
+    /// Get the CLOB server's current Unix timestamp in seconds
+    pub async fn server_time(&self) -> Result<u64, ClobError> {
+        self.context
+            .request(Method::GET, "/time", AuthMode::None)
+            .send()
+            .await
+    }
+
+    /// Seconds added to the local clock for signed timestamps.
+    ///
+    /// Zero unless [`ClobBuilder::sync_time`] is enabled and a signed request
+    /// has measured the offset, or [`Clob::sync_time`] was called.
+    pub fn time_offset(&self) -> i64 {
+        self.context.clock.offset()
+    }
+
+    /// Measure the offset to the server's clock now and apply it to signed
+    /// timestamps, returning the new offset
+    pub async fn sync_time(&self) -> Result<i64, ClobError> {
+        let server_time = self.server_time().await?;
+        self.context.clock.set_server_time(server_time);
+        Ok(self.context.clock.offset())
+    }
+
+    /// Contract call carrying out `request` on this client's chain, see
+    /// [`PositionRequest::to_transaction`]
+    pub fn position_transaction(
+        &self,
+        request: &PositionRequest,
+    ) -> Result<PositionTransaction, ClobError> {
+        let chain_id = self.context.chain_id;
+        let chain = Chain::from_chain_id(chain_id)
+            .ok_or_else(|| ClobError::validation(format!("Unsupported chain ID: {}", chain_id)))?;
+        Ok(request.to_transaction(&chain.contracts()))
+    }
+
+    /// Build the call converting `amount` NO tokens (in base units) of the
+    /// questions at `outcome_indices` of neg-risk market `market_id`.
+    ///
+    /// Conversions are settled by the chain's `neg_risk_adapter` contract,
+    /// not the CLOB API, so the returned call must be signed and sent by the
+    /// wallet holding the positions.
+    pub fn convert_positions(
+        &self,
+        market_id: &str,
+        outcome_indices: &[usize],
+        amount: U256,
+    ) -> Result<PositionTransaction, ClobError> {
+        let request = PositionRequest::convert(market_id, outcome_indices, amount)?;
+        self.position_transaction(&request)
+    }
+
+    /// Create new API credentials for the account's wallet (nonce 0)
+    pub async fn create_api_key(&self) -> Result<Credentials, ClobError> {
+        self.auth().create_api_key(0).send().await
+    }
+
+    /// Derive the existing API credentials of the account's wallet (nonce 0)
+    pub async fn derive_api_key(&self) -> Result<Credentials, ClobError> {
+        self.auth().derive_api_key(0).send().await
+    }
+
     /// Create an unsigned order from parameters
     pub async fn create_order(&self, params: &CreateOrderParams) -> Result<Order, ClobError> {
         params.validate()?;
 
         // Fetch market info for tick size
         let market = self.markets().get(&params.token_id).send().await?;
+        let fee_rate = self.fee_rate_bps().await?;
+
+        self.build_order(params, &market, fee_rate, &mut RiskState::default())
+            .await
+    }
+
+    /// Check an order against its market and build it
+    async fn build_order(
+        &self,
+        params: &CreateOrderParams,
+        market: &Market,
+        fee_rate: FeeRate,
+        risk_state: &mut RiskState,
+    ) -> Result<Order, ClobError> {
+        params.validate_market_open(market)?;
         let tick_size = TickSize::try_from(market.minimum_tick_size)?;
+        params.validate_tick_size(tick_size)?;
+        params.validate_taker(market)?;
+        params.validate_funder(self.account.signature_type())?;
 
-        // Get fee rate
-        let fee_rate_response: serde_json::Value = self
-            .client
-            .get(self.base_url.join("/fee-rate")?)
+        if let Some(risk) = &self.risk {
+            self.check_risk(risk, params, &market.condition_id, risk_state)
+                .await?;
+        }
+
+        let fee_rate_bps = fee_rate.for_order(params.post_only).to_string();
+        let mut order = params.to_order(&self.account, tick_size, fee_rate_bps);
+        order.neg_risk = market.neg_risk.unwrap_or(params.neg_risk);
+        Ok(order)
+    }
+
+    /// Get the current fee rates, reusing a value fetched within the
+    /// configured TTL (see [`ClobBuilder::fee_rate_ttl`])
+    pub async fn fee_rate_bps(&self) -> Result<FeeRate, ClobError> {
+        let cached = *self.fee_rate.lock().unwrap_or_else(PoisonError::into_inner);
+        match cached {
+            Some((fee_rate, fetched_at)) if fetched_at.elapsed() < self.fee_rate_ttl => {
+                Ok(fee_rate)
+            }
+            _ => self.refresh_fee_rate().await,
+        }
+    }
+
+    /// Fetch the current fee rates, replacing the cached value
+    pub async fn refresh_fee_rate(&self) -> Result<FeeRate, ClobError> {
+        let response: serde_json::Value = self
+            .context
+            .request(Method::GET, "/fee-rate", AuthMode::None)
             .send()
-            .await?
-            .json()
             .await?;
 
-        let fee_rate_bps = fee_rate_response["feeRateBps"]
-            .as_str()
-            .unwrap_or("0")
-            .to_string();
+        let fee_rate = FeeRate::from_response(&response);
+        *self.fee_rate.lock().unwrap_or_else(PoisonError::into_inner) =
+            Some((fee_rate, Instant::now()));
+        Ok(fee_rate)
+    }
 
-        // Calculate amounts
-        let (maker_amount, taker_amount) =
-            calculate_order_amounts(params.price, params.size, params.side, tick_size);
-
-        Ok(Order {
-            salt: generate_salt(),
-            maker: self.account.address(),
-            signer: self.account.address(),
-            taker: alloy::primitives::Address::ZERO,
-            token_id: params.token_id.clone(),
-            maker_amount,
-            taker_amount,
-            expiration: params.expiration.unwrap_or(0).to_string(),
-            nonce: current_timestamp().to_string(),
-            fee_rate_bps,
-            side: params.side,
-            signature_type: SignatureType::default(),
-        })
+    /// Evaluate the configured pre-trade risk checks for an order.
+    ///
+    /// Open order counts and midpoints are fetched once into `state`, which
+    /// also counts the order as open once it passes, so that later orders of
+    /// the same batch see it.
+    async fn check_risk(
+        &self,
+        risk: &RiskConfig,
+        params: &CreateOrderParams,
+        condition_id: &str,
+        state: &mut RiskState,
+    ) -> Result<(), ClobError> {
+        risk.check_notional(params)?;
+
+        let open_orders = match risk.max_open_orders_per_market {
+            Some(_) => {
+                let count = match state.open_orders.entry(condition_id.to_string()) {
+                    Entry::Occupied(entry) => entry.into_mut(),
+                    Entry::Vacant(entry) => {
+                        entry.insert(self.orders().by_market(condition_id).await?.len())
+                    }
+                };
+                risk.check_open_orders(*count)?;
+                Some(count)
+            }
+            None => None,
+        };
+
+        if risk.max_price_deviation.is_some() {
+            let mid = match state.midpoints.entry(params.token_id.clone()) {
+                Entry::Occupied(entry) => *entry.get(),
+                Entry::Vacant(entry) => {
+                    let midpoint = self.markets().midpoint(&params.token_id).send().await?;
+                    let mid: f64 = midpoint.mid.parse().map_err(|_| {
+                        ClobError::validation(format!("Invalid midpoint price: {}", midpoint.mid))
+                    })?;
+                    *entry.insert(mid)
+                }
+            };
+            risk.check_price_deviation(params.price, mid)?;
+        }
+
+        if let Some(count) = open_orders {
+            *count += 1;
+        }
+        Ok(())
     }
 
     /// Sign an order
     pub async fn sign_order(&self, order: &Order) -> Result<SignedOrder, ClobError> {
-        self.account.sign_order(order, self.chain_id).await
+        self.account.sign_order(order, self.context.chain_id).await
     }
 
-    /// Post a signed order
+    /// Post a signed order.
+    ///
+    /// The order is sent as GTD when it has an expiration and GTC otherwise;
+    /// use [`Clob::post_order_with_type`] to pick the order type explicitly.
     pub async fn post_order(&self, signed_order: &SignedOrder) -> Result<OrderResponse, ClobError> {
-        let auth = AuthMode::L2 {
-            address: self.account.address(),
-            credentials: self.account.credentials().clone(),
-            signer: self.account.signer().clone(),
-        };
-
-        Request::post(
-            self.client.clone(),
-            self.base_url.clone(),
-            "/order".to_string(),
-            auth,
-            self.chain_id,
-        )
-        .body(signed_order)?
-        .send()
-        .await
+        self.post_order_with_type(signed_order, implied_order_type(signed_order))
+            .await
+    }
+
+    /// Post a signed order with an explicit order type
+    pub async fn post_order_with_type(
+        &self,
+        signed_order: &SignedOrder,
+        order_type: OrderType,
+    ) -> Result<OrderResponse, ClobError> {
+        let body = signed_order.to_request_body(&self.account.credentials().key, order_type);
+        self.send_order(&body).await
+    }
+
+    /// Post a signed order as post-only (maker-only).
+    ///
+    /// The exchange rejects the order instead of matching it if it would
+    /// cross the spread, which surfaces as [`ClobError::PostOnlyRejected`].
+    pub async fn post_order_post_only(
+        &self,
+        signed_order: &SignedOrder,
+        order_type: OrderType,
+    ) -> Result<OrderResponse, ClobError> {
+        let body = PostOrderBody {
+            post_only: true,
+            ..signed_order.to_request_body(&self.account.credentials().key, order_type)
+        };
+        post_only_result(self.send_order(&body).await)
+    }
+
+    /// Send the `/order` POST request
+    async fn send_order(&self, body: &PostOrderBody<'_>) -> Result<OrderResponse, ClobError> {
+        self.context
+            .request(Method::POST, "/order", self.context.l2(&self.account))
+            .body(body)?
+            .send()
+            .await
     }
 
     /// Create, sign, and post an order (convenience method)
@@ -157,7 +336,313 @@ impl Clob {
     ) -> Result<OrderResponse, ClobError> {
         let order = self.create_order(params).await?;
         let signed_order = self.sign_order(&order).await?;
-        self.post_order(&signed_order).await
+
+        let body = PostOrderBody {
+            post_only: params.post_only,
+            defer_exec: params.defer_exec,
+            ..signed_order.to_request_body(&self.account.credentials().key, params.order_type)
+        };
+        let result = self.send_order(&body).await;
+        if params.post_only {
+            post_only_result(result)
+        } else {
+            result
+        }
+    }
+
+    /// Create, sign, and post several orders in a single batch request.
+    ///
+    /// Every order is validated before anything is fetched or signed, and the
+    /// whole batch fails on the first invalid order with
+    /// [`ClobError::BatchOrder`] carrying its index. Markets, the fee rate and
+    /// the data behind risk checks are fetched once per batch, and risk checks
+    /// count the batch's earlier orders as open. Each order is posted with its own order
+    /// type, post-only and deferred execution flags. Responses are aligned
+    /// with `params`.
+    pub async fn place_orders(
+        &self,
+        params: &[CreateOrderParams],
+    ) -> Result<Vec<OrderResponse>, ClobError> {
+        for (index, order) in params.iter().enumerate() {
+            order.validate().map_err(|e| e.at_index(index))?;
+        }
+
+        let fee_rate = self.fee_rate_bps().await?;
+        let mut markets: HashMap<&str, Market> = HashMap::new();
+        let mut risk_state = RiskState::default();
+        let mut signed_orders = Vec::with_capacity(params.len());
+
+        for (index, order) in params.iter().enumerate() {
+            let market = match markets.entry(order.token_id.as_str()) {
+                Entry::Occupied(entry) => entry.into_mut(),
+                Entry::Vacant(entry) => {
+                    let market = self
+                        .markets()
+                        .get(&order.token_id)
+                        .send()
+                        .await
+                        .map_err(|e| e.at_index(index))?;
+                    entry.insert(market)
+                }
+            };
+
+            let unsigned = self
+                .build_order(order, market, fee_rate, &mut risk_state)
+                .await
+                .map_err(|e| e.at_index(index))?;
+            let signed = self
+                .sign_order(&unsigned)
+                .await
+                .map_err(|e| e.at_index(index))?;
+            signed_orders.push(signed);
+        }
+
+        let owner = &self.account.credentials().key;
+        let bodies: Vec<_> = signed_orders
+            .iter()
+            .zip(params)
+            .map(|(signed, order)| PostOrderBody {
+                post_only: order.post_only,
+                defer_exec: order.defer_exec,
+                ..signed.to_request_body(owner, order.order_type)
+            })
+            .collect();
+        self.send_orders(&bodies).await
+    }
+
+    /// Post several signed orders in a single batch request.
+    ///
+    /// Like [`Clob::post_order`], each order is sent as GTD when it has an
+    /// expiration and GTC otherwise.
+    pub async fn post_orders(
+        &self,
+        signed_orders: &[SignedOrder],
+    ) -> Result<Vec<OrderResponse>, ClobError> {
+        let owner = &self.account.credentials().key;
+        let bodies: Vec<_> = signed_orders
+            .iter()
+            .map(|order| order.to_request_body(owner, implied_order_type(order)))
+            .collect();
+        self.send_orders(&bodies).await
+    }
+
+    /// Send the `/orders` batch POST request
+    async fn send_orders(
+        &self,
+        bodies: &[PostOrderBody<'_>],
+    ) -> Result<Vec<OrderResponse>, ClobError> {
+        self.context
+            .request(Method::POST, "/orders", self.context.l2(&self.account))
+            .body(&bodies)?
+            .send()
+            .await
+    }
+
+    /// Cancel many orders in batches of at most `chunk_size` IDs.
+    ///
+    /// `chunk_size` is clamped to [`MAX_CANCEL_BATCH`]. Batches are sent one
+    /// after another, and when a response reports the rate limit budget as
+    /// spent the next batch waits for the window to reset. The batch
+    /// responses are merged into one [`CancelResponse`]; a failing batch
+    /// aborts the remaining ones.
+    pub async fn cancel_orders_chunked(
+        &self,
+        order_ids: impl IntoIterator<Item = impl Into<String>>,
+        chunk_size: usize,
+    ) -> Result<CancelResponse, ClobError> {
+        let order_ids: Vec<String> = order_ids.into_iter().map(Into::into).collect();
+        let chunk_size = chunk_size.clamp(1, MAX_CANCEL_BATCH);
+        let orders = self.orders();
+
+        let mut merged: Option<CancelResponse> = None;
+        for chunk in order_ids.chunks(chunk_size) {
+            if merged.is_some() {
+                if let Some(wait) = self
+                    .context
+                    .http
+                    .rate_limit
+                    .last()
+                    .and_then(|r| r.wait_time())
+                {
+                    tracing::debug!("Rate limit budget spent, waiting {:?}", wait);
+                    tokio::time::sleep(wait).await;
+                }
+            }
+
+            let response = orders.cancel_many(chunk.iter().cloned()).send().await?;
+            match merged.as_mut() {
+                Some(merged) => merged.merge(response),
+                None => merged = Some(response),
+            }
+        }
+
+        Ok(merged.unwrap_or_default())
+    }
+
+    /// Create, sign, and post a market order.
+    ///
+    /// The order book is walked from the best level to find the worst price
+    /// needed to fill `amount`, and the order is posted at that price with the
+    /// params' time-in-force. See [`CreateMarketOrderParams::market_price`]
+    /// for how thin books are handled.
+    pub async fn place_market_order(
+        &self,
+        params: &CreateMarketOrderParams,
+    ) -> Result<OrderResponse, ClobError> {
+        params.validate()?;
+
+        let book = self.markets().order_book(&params.token_id).send().await?;
+        let price = params.market_price(&book)?;
+
+        let market = self.markets().get(&params.token_id).send().await?;
+        let fee_rate = self.fee_rate_bps().await?;
+        let order = self
+            .build_order(
+                &params.to_limit(price),
+                &market,
+                fee_rate,
+                &mut RiskState::default(),
+            )
+            .await?;
+
+        let signed_order = self.sign_order(&order).await?;
+        self.post_order_with_type(&signed_order, params.order_type)
+            .await
+    }
+
+    /// Wait until the trades filling `order_id` are mined or confirmed on chain.
+    ///
+    /// The order is looked up first for its token and placement time, then
+    /// the account's trades of that token matched since placement are polled
+    /// every [`SETTLEMENT_POLL_INTERVAL`], across all pages, until every trade
+    /// the order took part in, as taker or as maker, has settled.
+    ///
+    /// Fills may settle in separate transactions, so the distinct transaction
+    /// hashes of all the order's trades are returned, oldest match first.
+    /// Fails with a validation error if the CLOB does not know the order,
+    /// with [`ClobError::SettlementFailed`] if one of the trades fails, and
+    /// with [`ClobError::SettlementTimeout`] once `timeout` elapses.
+    pub async fn await_settlement(
+        &self,
+        order_id: &str,
+        timeout: Duration,
+    ) -> Result<Vec<String>, ClobError> {
+        self.poll_settlement(order_id, timeout, SETTLEMENT_POLL_INTERVAL)
+            .await
+    }
+
+    async fn poll_settlement(
+        &self,
+        order_id: &str,
+        timeout: Duration,
+        interval: Duration,
+    ) -> Result<Vec<String>, ClobError> {
+        let deadline = tokio::time::Instant::now() + timeout;
+
+        let order = self
+            .orders()
+            .get(order_id)
+            .await?
+            .ok_or_else(|| ClobError::validation(format!("Order {} not found", order_id)))?;
+        let placed_at: u64 = order.created_at.parse().map_err(|_| {
+            ClobError::validation(format!(
+                "Order {} has an invalid creation time: {}",
+                order_id, order.created_at
+            ))
+        })?;
+        let request = self
+            .account_api()
+            .trades()
+            .asset_id(order.asset_id)
+            // Also catch trades matched within the placement second
+            .after(placed_at.saturating_sub(1));
+
+        loop {
+            let mut trades: Vec<Trade> = request
+                .clone()
+                .send()
+                .await?
+                .into_iter()
+                .filter(|trade| trade.involves_order(order_id))
+                .collect();
+
+            if let Some(trade) = trades.iter().find(|trade| trade.is_failed()) {
+                return Err(ClobError::SettlementFailed {
+                    order_id: order_id.to_string(),
+                    trade_id: trade.id.clone(),
+                });
+            }
+            if !trades.is_empty() && trades.iter().all(Trade::is_settled) {
+                trades.sort_by_key(|trade| trade.match_time.parse::<u64>().unwrap_or(0));
+                let mut hashes: Vec<String> = Vec::new();
+                for trade in trades {
+                    if !hashes.contains(&trade.transaction_hash) {
+                        hashes.push(trade.transaction_hash);
+                    }
+                }
+                return Ok(hashes);
+            }
+
+            if tokio::time::Instant::now() + interval > deadline {
+                return Err(ClobError::SettlementTimeout {
+                    order_id: order_id.to_string(),
+                    timeout,
+                });
+            }
+            tokio::time::sleep(interval).await;
+        }
+    }
+
+    /// Place an order and return how much matched vs. rested on the book
+    pub async fn place_order_detailed(
+        &self,
+        params: &CreateOrderParams,
+    ) -> Result<PlacedOrder, ClobError> {
+        let response = self.place_order(params).await?;
+        Ok(response.breakdown(params.side, params.size))
+    }
+}
+
+/// Data behind pre-trade risk checks, shared by the orders of a batch
+#[derive(Default)]
+struct RiskState {
+    /// Open orders per market condition ID, including accepted batch orders
+    open_orders: HashMap<String, usize>,
+    /// Midpoint per token ID
+    midpoints: HashMap<String, f64>,
+}
+
+/// Map the exchange's rejection of a crossing post-only order, reported either
+/// as an error status or as an unsuccessful response, to
+/// [`ClobError::PostOnlyRejected`]
+fn post_only_result(result: Result<OrderResponse, ClobError>) -> Result<OrderResponse, ClobError> {
+    match result {
+        Ok(response) if !response.success => match response.error_msg.as_deref() {
+            Some(message) if is_post_only_rejection(message) => {
+                Err(ClobError::PostOnlyRejected(message.to_string()))
+            }
+            _ => Ok(response),
+        },
+        Err(ClobError::Api(ApiError::Api {
+            status: 400,
+            message,
+            ..
+        })) if is_post_only_rejection(&message) => Err(ClobError::PostOnlyRejected(message)),
+        other => other,
+    }
+}
+
+fn is_post_only_rejection(message: &str) -> bool {
+    let message = message.to_ascii_lowercase();
+    message.contains("post-only") || message.contains("post only")
+}
+
+/// Order type of an order posted without one: GTD if it expires, GTC otherwise
+fn implied_order_type(signed_order: &SignedOrder) -> OrderType {
+    if signed_order.order.expiration == "0" {
+        OrderType::Gtc
+    } else {
+        OrderType::Gtd
     }
 }
 
@@ -168,10 +653,115 @@ pub struct CreateOrderParams {
     pub price: f64,
     pub size: f64,
     pub side: OrderSide,
+    /// Unix timestamp after which a GTD order expires
     pub expiration: Option<u64>,
+    /// Time-in-force
+    pub order_type: OrderType,
+    /// Rest on the book only; rejected instead of matching if it would cross
+    pub post_only: bool,
+    /// Counterparty allowed to fill the order (RFQ/private fills only)
+    pub taker: Option<Address>,
+    /// Skip the check that the market is open and accepting orders
+    pub force: bool,
+    /// Let the exchange queue matching instead of running it in the request.
+    ///
+    /// The response then only acknowledges that the order was accepted; fills
+    /// are reported later (e.g. on the user WebSocket channel) rather than in
+    /// the returned [`OrderResponse`].
+    pub defer_exec: bool,
+    /// Sign against the negative-risk exchange.
+    ///
+    /// [`Clob::create_order`] overrides this with the market's own flag
+    /// when the market reports one.
+    pub neg_risk: bool,
+    /// Address holding the funds, used as the order `maker` instead of the
+    /// account's funder. Requires a non-EOA signature type.
+    pub funder: Option<Address>,
+    /// Fixed order salt, see [`CreateOrderParams::with_salt`]
+    pub salt: Option<U256>,
 }
 
 impl CreateOrderParams {
+    /// GTC order for `size` shares of `token_id` at `price`, with every
+    /// optional flag off
+    pub fn new(token_id: impl Into<String>, price: f64, size: f64, side: OrderSide) -> Self {
+        Self {
+            token_id: token_id.into(),
+            price,
+            size,
+            side,
+            expiration: None,
+            order_type: OrderType::default(),
+            post_only: false,
+            taker: None,
+            force: false,
+            defer_exec: false,
+            neg_risk: false,
+            funder: None,
+            salt: None,
+        }
+    }
+
+    /// Set the time-in-force
+    pub fn order_type(mut self, order_type: OrderType) -> Self {
+        self.order_type = order_type;
+        self
+    }
+
+    /// Expire the order at a unix timestamp (GTD orders)
+    pub fn expiration(mut self, expiration: u64) -> Self {
+        self.expiration = Some(expiration);
+        self
+    }
+
+    /// Only rest on the book, never match on placement
+    pub fn post_only(mut self, post_only: bool) -> Self {
+        self.post_only = post_only;
+        self
+    }
+
+    /// Skip the check that the market is accepting orders
+    pub fn force(mut self, force: bool) -> Self {
+        self.force = force;
+        self
+    }
+
+    /// Sign against the negative-risk exchange
+    pub fn neg_risk(mut self, neg_risk: bool) -> Self {
+        self.neg_risk = neg_risk;
+        self
+    }
+
+    /// Restrict the order to a specific taker (RFQ/private fills)
+    pub fn taker(mut self, taker: Address) -> Self {
+        self.taker = Some(taker);
+        self
+    }
+
+    /// Trade this order on behalf of `funder` (proxy wallet or Gnosis Safe)
+    pub fn funder(mut self, funder: Address) -> Self {
+        self.funder = Some(funder);
+        self
+    }
+
+    /// Ask the exchange to defer execution instead of matching immediately
+    pub fn defer_exec(mut self, defer: bool) -> Self {
+        self.defer_exec = defer;
+        self
+    }
+
+    /// Use a fixed salt instead of a random one, so that retrying after a
+    /// timeout signs the exact same order and the exchange can reject the
+    /// duplicate instead of placing it twice.
+    ///
+    /// Salted orders are fully determined by the parameters, so two distinct
+    /// orders with identical parameters must use different salts, or the
+    /// second one is rejected as a duplicate.
+    pub fn with_salt(mut self, salt: U256) -> Self {
+        self.salt = Some(salt);
+        self
+    }
+
     pub fn validate(&self) -> Result<(), ClobError> {
         if self.price <= 0.0 || self.price > 1.0 {
             return Err(ClobError::validation(format!(
@@ -188,46 +778,286 @@ impl CreateOrderParams {
         if self.price.is_nan() || self.size.is_nan() {
             return Err(ClobError::validation("NaN values not allowed"));
         }
+        if self.post_only && matches!(self.order_type, OrderType::Fok | OrderType::Fak) {
+            return Err(ClobError::validation(format!(
+                "Post-only orders must be GTC or GTD, got {}",
+                self.order_type
+            )));
+        }
+        self.validate_expiration(current_timestamp())
+    }
+
+    /// Ensure the expiration is consistent with the order type
+    fn validate_expiration(&self, now: u64) -> Result<(), ClobError> {
+        let expiration = self.expiration.unwrap_or(0);
+        match self.order_type {
+            OrderType::Gtd if expiration <= now => Err(ClobError::validation(format!(
+                "GTD orders need an expiration in the future, got {}",
+                expiration
+            ))),
+            OrderType::Fok | OrderType::Fak if expiration != 0 => {
+                Err(ClobError::validation(format!(
+                    "{} orders cannot have an expiration, got {}",
+                    self.order_type, expiration
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Ensure the price is a multiple of the market's tick size
+    pub fn validate_tick_size(&self, tick_size: TickSize) -> Result<(), ClobError> {
+        const EPSILON: f64 = 1e-9;
+        if (self.price - tick_size.round_to_tick(self.price)).abs() > EPSILON {
+            return Err(ClobError::validation(format!(
+                "Price {} is not a multiple of the tick size {}",
+                self.price,
+                tick_size.as_f64()
+            )));
+        }
         Ok(())
     }
+
+    /// Ensure a non-zero taker is only used on RFQ-enabled markets
+    pub fn validate_taker(&self, market: &Market) -> Result<(), ClobError> {
+        let has_taker = self.taker.is_some_and(|taker| taker != Address::ZERO);
+        if has_taker && market.rfq_enabled != Some(true) {
+            return Err(ClobError::validation(format!(
+                "Market {} does not accept RFQ orders with a specific taker",
+                market.condition_id
+            )));
+        }
+        Ok(())
+    }
+
+    /// Ensure a funder is only set for signature types that use one, as
+    /// [`ClobBuilder::build`] does for the account's funder
+    pub fn validate_funder(&self, signature_type: SignatureType) -> Result<(), ClobError> {
+        if self.funder.is_some() && signature_type == SignatureType::Eoa {
+            return Err(ClobError::validation(
+                "A funder address requires a proxy or Gnosis Safe signature type",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Ensure the market is accepting orders, unless `force` is set
+    pub fn validate_market_open(&self, market: &Market) -> Result<(), ClobError> {
+        if !self.force && !market.is_accepting_orders() {
+            return Err(ClobError::validation(format!(
+                "Market {} is not accepting orders",
+                market.condition_id
+            )));
+        }
+        Ok(())
+    }
+
+    /// Build an unsigned order for the given maker
+    pub(crate) fn to_order(
+        &self,
+        account: &Account,
+        tick_size: TickSize,
+        fee_rate_bps: String,
+    ) -> Order {
+        let (maker_amount, taker_amount) =
+            calculate_order_amounts(self.price, self.size, self.side, tick_size);
+
+        Order {
+            salt: self
+                .salt
+                .map_or_else(generate_salt, |salt| salt.to_string()),
+            // EOA orders are made by the signer itself
+            maker: match account.signature_type() {
+                SignatureType::Eoa => account.address(),
+                _ => self.funder.unwrap_or_else(|| account.funder()),
+            },
+            signer: account.address(),
+            taker: self.taker.unwrap_or(Address::ZERO),
+            token_id: self.token_id.clone(),
+            maker_amount,
+            taker_amount,
+            expiration: self.expiration.unwrap_or(0).to_string(),
+            // Orders carry the maker's exchange nonce, which stays 0 unless
+            // the maker bumps it on-chain to cancel everything at once
+            nonce: "0".to_string(),
+            fee_rate_bps,
+            side: self.side,
+            signature_type: account.signature_type(),
+            neg_risk: self.neg_risk,
+        }
+    }
+}
+
+/// Parameters for creating a market order
+#[derive(Debug, Clone)]
+pub struct CreateMarketOrderParams {
+    pub token_id: String,
+    /// USDC to spend for a BUY, shares to sell for a SELL
+    pub amount: f64,
+    pub side: OrderSide,
+    /// Time-in-force, either [`OrderType::Fok`] or [`OrderType::Fak`]
+    pub order_type: OrderType,
+}
+
+impl CreateMarketOrderParams {
+    /// Market buy spending `amount` USDC, filled entirely or not at all
+    pub fn buy(token_id: impl Into<String>, amount: f64) -> Self {
+        Self {
+            token_id: token_id.into(),
+            amount,
+            side: OrderSide::Buy,
+            order_type: OrderType::Fok,
+        }
+    }
+
+    /// Market sell of `amount` shares, filled entirely or not at all
+    pub fn sell(token_id: impl Into<String>, amount: f64) -> Self {
+        Self {
+            token_id: token_id.into(),
+            amount,
+            side: OrderSide::Sell,
+            order_type: OrderType::Fok,
+        }
+    }
+
+    /// Set the time-in-force
+    pub fn order_type(mut self, order_type: OrderType) -> Self {
+        self.order_type = order_type;
+        self
+    }
+
+    pub fn validate(&self) -> Result<(), ClobError> {
+        if self.amount.is_nan() {
+            return Err(ClobError::validation("NaN values not allowed"));
+        }
+        if self.amount <= 0.0 {
+            return Err(ClobError::validation(format!(
+                "Amount must be positive, got {}",
+                self.amount
+            )));
+        }
+        if !matches!(self.order_type, OrderType::Fok | OrderType::Fak) {
+            return Err(ClobError::validation(format!(
+                "Market orders must be FOK or FAK, got {}",
+                self.order_type
+            )));
+        }
+        Ok(())
+    }
+
+    /// Walk the book from the best level and return the worst price needed
+    /// to fill `amount`.
+    ///
+    /// BUY orders consume asks until the USDC spent reaches `amount`; SELL
+    /// orders consume bids until the shares sold reach `amount`. When the book
+    /// is too thin, FOK orders fail with [`ClobError::InsufficientLiquidity`]
+    /// reporting how much was fillable, while FAK orders take whatever depth
+    /// exists and only fail on an empty book.
+    pub fn market_price(&self, book: &OrderBook) -> Result<f64, ClobError> {
+        let levels = match self.side {
+            OrderSide::Buy => &book.asks,
+            OrderSide::Sell => &book.bids,
+        };
+
+        let mut levels = levels
+            .iter()
+            .map(|level| {
+                let price: f64 = level.price.parse().map_err(|_| {
+                    ClobError::validation(format!("Invalid book price: {}", level.price))
+                })?;
+                let size: f64 = level.size.parse().map_err(|_| {
+                    ClobError::validation(format!("Invalid book size: {}", level.size))
+                })?;
+                Ok((price, size))
+            })
+            .collect::<Result<Vec<_>, ClobError>>()?;
+
+        // Best level first: lowest ask for a buy, highest bid for a sell
+        match self.side {
+            OrderSide::Buy => levels.sort_by(|a, b| a.0.total_cmp(&b.0)),
+            OrderSide::Sell => levels.sort_by(|a, b| b.0.total_cmp(&a.0)),
+        }
+
+        let mut filled = 0.0;
+        let mut worst_price = None;
+        for (price, size) in levels {
+            filled += match self.side {
+                OrderSide::Buy => price * size,
+                OrderSide::Sell => size,
+            };
+            worst_price = Some(price);
+            if filled >= self.amount {
+                return Ok(price);
+            }
+        }
+
+        match (self.order_type, worst_price) {
+            (OrderType::Fak, Some(price)) => Ok(price),
+            _ => Err(ClobError::InsufficientLiquidity {
+                requested: self.amount,
+                fillable: filled,
+            }),
+        }
+    }
+
+    /// Limit order equivalent at `price`, sized so a BUY never spends more
+    /// than `amount`
+    pub fn to_limit(&self, price: f64) -> CreateOrderParams {
+        let size = match self.side {
+            OrderSide::Buy => (self.amount / price * 100.0).floor() / 100.0,
+            OrderSide::Sell => self.amount,
+        };
+
+        CreateOrderParams::new(self.token_id.clone(), price, size, self.side)
+            .order_type(self.order_type)
+    }
 }
 
 /// Builder for CLOB client
 pub struct ClobBuilder {
-    base_url: String,
-    timeout_ms: u64,
-    pool_size: usize,
+    http: HttpClientBuilder,
+    base_url: Option<String>,
     chain: Chain,
     account: Account,
+    funder: Option<Address>,
+    signature_type: Option<SignatureType>,
+    risk: Option<RiskConfig>,
+    fee_rate_ttl: Duration,
+    sync_time: bool,
 }
 
 impl ClobBuilder {
     /// Create a new builder with an Account
     pub fn new(account: Account) -> Self {
         Self {
-            base_url: DEFAULT_BASE_URL.to_string(),
-            timeout_ms: DEFAULT_TIMEOUT_MS,
-            pool_size: DEFAULT_POOL_SIZE,
+            http: HttpClientBuilder::default(),
+            base_url: None,
             chain: Chain::PolygonMainnet,
             account,
+            funder: None,
+            signature_type: None,
+            risk: None,
+            fee_rate_ttl: DEFAULT_FEE_RATE_TTL,
+            sync_time: false,
         }
     }
 
-    /// Set base URL for the API
+    /// Set base URL for the API (default: the CLOB endpoint of the chain,
+    /// see [`Chain::endpoints`])
     pub fn base_url(mut self, url: impl Into<String>) -> Self {
-        self.base_url = url.into();
+        self.base_url = Some(url.into());
         self
     }
 
     /// Set request timeout in milliseconds
     pub fn timeout_ms(mut self, timeout: u64) -> Self {
-        self.timeout_ms = timeout;
+        self.http = self.http.timeout_ms(timeout);
         self
     }
 
     /// Set connection pool size
     pub fn pool_size(mut self, size: usize) -> Self {
-        self.pool_size = size;
+        self.http = self.http.pool_size(size);
         self
     }
 
@@ -237,18 +1067,1088 @@ impl ClobBuilder {
         self
     }
 
+    /// Set the maximum response body size in bytes, see
+    /// [`HttpClientBuilder::max_response_bytes`]
+    pub fn max_response_bytes(mut self, max: usize) -> Self {
+        self.http = self.http.max_response_bytes(max);
+        self
+    }
+
+    /// Set the retry policy for transient failures, see
+    /// [`HttpClientBuilder::retry_policy`].
+    ///
+    /// Order placement is never retried unless the policy opts into
+    /// retrying non-idempotent requests.
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.http = self.http.retry_policy(policy);
+        self
+    }
+
+    /// Set the `User-Agent` header, see [`HttpClientBuilder::user_agent`]
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.http = self.http.user_agent(user_agent);
+        self
+    }
+
+    /// Add a header sent with every request, see
+    /// [`HttpClientBuilder::default_header`]
+    pub fn default_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.http = self.http.default_header(name, value);
+        self
+    }
+
+    /// Route every request through an `http://`, `https://`, `socks5://`
+    /// or `socks5h://` proxy, see [`HttpClientBuilder::proxy`]
+    pub fn proxy(mut self, url: impl Into<String>) -> Self {
+        self.http = self.http.proxy(url);
+        self
+    }
+
+    /// Ignore proxies configured through the environment, see
+    /// [`HttpClientBuilder::no_proxy`]
+    pub fn no_proxy(mut self) -> Self {
+        self.http = self.http.no_proxy();
+        self
+    }
+
+    /// Send requests through a custom transport, see
+    /// [`HttpClientBuilder::transport`]
+    pub fn transport(mut self, transport: Arc<dyn HttpTransport>) -> Self {
+        self.http = self.http.transport(transport);
+        self
+    }
+
+    /// Send requests to a fallback base URL when the primary host keeps
+    /// failing, see [`HttpClientBuilder::fallback_base_url`]
+    pub fn fallback_base_url(mut self, url: impl Into<String>) -> Self {
+        self.http = self.http.fallback_base_url(url);
+        self
+    }
+
+    /// Trade on behalf of a funder address (proxy wallet or Gnosis Safe).
+    ///
+    /// Orders use the funder as `maker` and the account's wallet as
+    /// `signer`. Must be paired with a non-EOA [`ClobBuilder::signature_type`].
+    pub fn funder(mut self, funder: Address) -> Self {
+        self.funder = Some(funder);
+        self
+    }
+
+    /// Set the signature type of orders (default: the account's, usually
+    /// [`SignatureType::Eoa`]). Non-EOA types require a funder.
+    pub fn signature_type(mut self, signature_type: SignatureType) -> Self {
+        self.signature_type = Some(signature_type);
+        self
+    }
+
+    /// Set how long a fetched fee rate is reused when creating orders
+    /// (default: [`DEFAULT_FEE_RATE_TTL`])
+    pub fn fee_rate_ttl(mut self, ttl: Duration) -> Self {
+        self.fee_rate_ttl = ttl;
+        self
+    }
+
+    /// Correct signed timestamps by the offset to the server's clock
+    /// (default: false), for machines whose clock drifts.
+    ///
+    /// The offset is measured with `GET /time` once, before the first signed
+    /// request, and reported by [`Clob::time_offset`].
+    pub fn sync_time(mut self, sync: bool) -> Self {
+        self.sync_time = sync;
+        self
+    }
+
+    /// Enable pre-trade risk checks on order creation
+    pub fn risk(mut self, risk: RiskConfig) -> Self {
+        self.risk = Some(risk);
+        self
+    }
+
     /// Build the CLOB client
     pub fn build(self) -> Result<Clob, ClobError> {
-        let HttpClient { client, base_url } = HttpClientBuilder::new(&self.base_url)
-            .timeout_ms(self.timeout_ms)
-            .pool_size(self.pool_size)
-            .build()?;
+        let signature_type = self
+            .signature_type
+            .unwrap_or_else(|| self.account.signature_type());
+        let account = match (
+            signature_type,
+            self.funder.or_else(|| self.account.funder_address()),
+        ) {
+            (SignatureType::Eoa, None) => self.account,
+            (SignatureType::Eoa, Some(_)) => {
+                return Err(ClobError::validation(
+                    "A funder address requires a proxy or Gnosis Safe signature type",
+                ))
+            }
+            (signature_type, Some(funder)) => self.account.with_funder(funder, signature_type),
+            (signature_type, None) => {
+                return Err(ClobError::validation(format!(
+                    "{} signatures require a funder address",
+                    signature_type
+                )))
+            }
+        };
+
+        let base_url = self
+            .base_url
+            .unwrap_or_else(|| self.chain.endpoints().clob.to_string());
+        let http = self.http.base_url(base_url).build()?;
+        let clock = if self.sync_time {
+            Clock::synced()
+        } else {
+            Clock::default()
+        };
 
         Ok(Clob {
-            client,
-            base_url,
-            chain_id: self.chain.chain_id(),
-            account: self.account,
+            context: Arc::new(ClobContext::new(http, self.chain.chain_id(), clock)),
+            account,
+            risk: self.risk,
+            fee_rate: Arc::default(),
+            fee_rate_ttl: self.fee_rate_ttl,
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use alloy::primitives::address;
+    use polyte_core::testing::{MockResponse, MockTransport};
+
+    use super::*;
+    use crate::{testing::account, END_CURSOR};
+
+    fn params() -> CreateOrderParams {
+        CreateOrderParams::new("123", 0.52, 100.0, OrderSide::Buy)
+    }
+
+    fn market(rfq_enabled: Option<bool>) -> Market {
+        Market {
+            condition_id: "0xabc".to_string(),
+            question_id: "0xdef".to_string(),
+            tokens: Vec::new(),
+            rewards: None,
+            minimum_order_size: 5.0,
+            minimum_tick_size: 0.01,
+            description: String::new(),
+            category: None,
+            end_date_iso: None,
+            question: String::new(),
+            active: true,
+            closed: false,
+            archived: false,
+            neg_risk: None,
+            neg_risk_market_id: None,
+            enable_order_book: Some(true),
+            accepting_orders: Some(true),
+            rfq_enabled,
+        }
+    }
+
+    const SIGNER: Address = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+    const FUNDER: Address = address!("3C44CdDdB6a900fa2b585dd299e03d12FA4293BC");
+    const TAKER: Address = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+
+    #[test]
+    fn test_order_taker_defaults_to_zero() {
+        let order = params().to_order(&account(), TickSize::Hundredth, "0".to_string());
+        assert_eq!(order.taker, Address::ZERO);
+        assert_eq!(order.maker, SIGNER);
+        assert_eq!(order.signer, SIGNER);
+    }
+
+    #[test]
+    fn test_order_taker_reflects_param() {
+        let order =
+            params()
+                .taker(TAKER)
+                .to_order(&account(), TickSize::Hundredth, "0".to_string());
+        assert_eq!(order.taker, TAKER);
+    }
+
+    #[test]
+    fn test_order_maker_is_funder() {
+        let account = account().with_funder(FUNDER, SignatureType::PolyProxy);
+        let order = params().to_order(&account, TickSize::Hundredth, "0".to_string());
+
+        assert_eq!(order.maker, FUNDER);
+        assert_eq!(order.signer, SIGNER);
+        assert_ne!(order.maker, order.signer);
+        assert_eq!(order.signature_type, SignatureType::PolyProxy);
+    }
+
+    #[tokio::test]
+    async fn test_order_funder_param_overrides_account() {
+        let other = address!("90F79bf6EB2c4f870365E785982E1f101E93b906");
+        let account = account().with_funder(FUNDER, SignatureType::PolyGnosisSafe);
+        let order = params()
+            .funder(other)
+            .to_order(&account, TickSize::Hundredth, "0".to_string());
+
+        assert_eq!(order.maker, other);
+        assert_eq!(order.signer, SIGNER);
+
+        // EOA accounts make their own orders
+        let clob = ClobBuilder::new(crate::testing::account())
+            .transport(market_and_fees())
+            .build()
+            .unwrap();
+        let err = clob
+            .create_order(&params().funder(other))
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("requires a proxy"));
+    }
+
+    #[tokio::test]
+    async fn test_last_rate_limit_captured() {
+        let clob = ClobBuilder::new(account())
+            .transport(MockTransport::rate_limited(r#"{"mid": "0.5"}"#))
+            .build()
+            .unwrap();
+        assert_eq!(clob.last_rate_limit(), None);
+
+        clob.markets().midpoint("123").send().await.unwrap();
+        assert_eq!(
+            clob.last_rate_limit(),
+            Some(RateLimit {
+                limit: Some(100),
+                remaining: Some(42),
+                reset: Some(10),
+            })
+        );
+    }
+
+    #[test]
+    fn test_fee_rate_from_response() {
+        let parse = |json: serde_json::Value| FeeRate::from_response(&json);
+
+        assert_eq!(
+            parse(serde_json::json!({ "feeRateBps": "10" })),
+            FeeRate {
+                maker_bps: 10,
+                taker_bps: 10
+            }
+        );
+        assert_eq!(
+            parse(serde_json::json!({ "makerFeeRateBps": 0, "takerFeeRateBps": "25" })),
+            FeeRate {
+                maker_bps: 0,
+                taker_bps: 25
+            }
+        );
+        assert_eq!(parse(serde_json::json!({})), FeeRate::default());
+    }
+
+    #[tokio::test]
+    async fn test_post_only_orders_sign_maker_fee_rate() {
+        let market = market(None);
+        let transport = MockTransport::new(move |request| match request.path() {
+            "/fee-rate" => MockResponse::ok(r#"{"makerFeeRateBps": 0, "takerFeeRateBps": 25}"#),
+            _ => MockResponse::json(&market),
+        });
+        let clob = ClobBuilder::new(account())
+            .transport(transport)
+            .build()
+            .unwrap();
+
+        let taker = clob.create_order(&params()).await.unwrap();
+        let maker = clob.create_order(&params().post_only(true)).await.unwrap();
+        assert_eq!(taker.fee_rate_bps, "25");
+        assert_eq!(maker.fee_rate_bps, "0");
+    }
+
+    /// Serve a market for any `/markets/` request and a fee rate for `/fee-rate`
+    fn market_and_fees() -> Arc<MockTransport> {
+        let market = market(None);
+        MockTransport::new(move |request| match request.path() {
+            "/fee-rate" => MockResponse::ok(r#"{"feeRateBps": "10"}"#),
+            _ => MockResponse::json(&market),
+        })
+    }
+
+    fn fee_requests(transport: &MockTransport) -> usize {
+        transport
+            .requests()
+            .iter()
+            .filter(|request| request.path() == "/fee-rate")
+            .count()
+    }
+
+    #[tokio::test]
+    async fn test_risk_counts_earlier_batch_orders() {
+        let market = market(None);
+        let transport = MockTransport::new(move |request| match request.path() {
+            "/fee-rate" => MockResponse::ok(r#"{"feeRateBps": "10"}"#),
+            "/midpoint" => MockResponse::ok(r#"{"mid": "0.5"}"#),
+            "/data/orders" => MockResponse::json(&[open_order()]),
+            _ => MockResponse::json(&market),
+        });
+        let clob = ClobBuilder::new(account())
+            .transport(transport.clone())
+            .risk(
+                RiskConfig::new()
+                    .max_open_orders_per_market(3)
+                    .max_price_deviation(0.10),
+            )
+            .build()
+            .unwrap();
+
+        // One order is already open, so the third order of the batch is one
+        // too many
+        let err = clob
+            .place_orders(&[params(), params(), params()])
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            ClobError::BatchOrder { index: 2, ref source }
+                if matches!(**source, ClobError::RiskRejected(_))
+        ));
+
+        let requests = transport.requests();
+        let count = |path: &str| {
+            requests
+                .iter()
+                .filter(|request| request.path() == path)
+                .count()
+        };
+        assert_eq!(count("/data/orders"), 1);
+        assert_eq!(count("/midpoint"), 1);
+        assert_eq!(count("/orders"), 0);
+
+        let orders = requests
+            .iter()
+            .find(|request| request.path() == "/data/orders")
+            .unwrap();
+        assert_eq!(orders.query("market").as_deref(), Some("0xabc"));
+    }
+
+    #[tokio::test]
+    async fn test_fee_rate_cached_within_ttl() {
+        let transport = market_and_fees();
+        let clob = ClobBuilder::new(account())
+            .transport(transport.clone())
+            .build()
+            .unwrap();
+
+        let first = clob.create_order(&params()).await.unwrap();
+        let second = clob.create_order(&params()).await.unwrap();
+        assert_eq!(first.fee_rate_bps, "10");
+        assert_eq!(second.fee_rate_bps, "10");
+        assert_eq!(fee_requests(&transport), 1);
+
+        clob.refresh_fee_rate().await.unwrap();
+        assert_eq!(fee_requests(&transport), 2);
+    }
+
+    #[tokio::test]
+    async fn test_fee_rate_refetched_after_ttl() {
+        let transport = market_and_fees();
+        let clob = ClobBuilder::new(account())
+            .transport(transport.clone())
+            .fee_rate_ttl(Duration::ZERO)
+            .build()
+            .unwrap();
+
+        clob.fee_rate_bps().await.unwrap();
+        clob.fee_rate_bps().await.unwrap();
+        assert_eq!(fee_requests(&transport), 2);
+    }
+
+    #[tokio::test]
+    async fn test_salted_orders_are_identical() {
+        let account = account();
+        let salted = params().with_salt(U256::from(42u64));
+
+        // Salted orders never read the clock, so back-to-back builds match
+        let first = salted.to_order(&account, TickSize::Hundredth, "0".to_string());
+        let second = salted.to_order(&account, TickSize::Hundredth, "0".to_string());
+        assert_eq!(first.salt, "42");
+        assert_eq!(first.nonce, "0");
+
+        let first = account.sign_order(&first, 137).await.unwrap();
+        let second = account.sign_order(&second, 137).await.unwrap();
+        assert_eq!(
+            serde_json::to_vec(&first).unwrap(),
+            serde_json::to_vec(&second).unwrap()
+        );
+
+        let unsalted = params().to_order(&account, TickSize::Hundredth, "0".to_string());
+        assert_ne!(unsalted.salt, first.order.salt);
+        assert_eq!(unsalted.nonce, "0");
+    }
+
+    #[test]
+    fn test_builder_requires_funder_for_proxy_signatures() {
+        let err = ClobBuilder::new(account())
+            .signature_type(SignatureType::PolyProxy)
+            .build()
+            .err()
+            .unwrap();
+        assert!(err.to_string().contains("require a funder"));
+
+        let err = ClobBuilder::new(account())
+            .funder(FUNDER)
+            .build()
+            .err()
+            .unwrap();
+        assert!(err.to_string().contains("requires a proxy"));
+
+        let clob = ClobBuilder::new(account())
+            .funder(FUNDER)
+            .signature_type(SignatureType::PolyProxy)
+            .build()
+            .unwrap();
+        assert_eq!(clob.account.funder(), FUNDER);
+        assert_eq!(clob.account.address(), SIGNER);
+        assert_eq!(clob.account.signature_type(), SignatureType::PolyProxy);
+    }
+
+    #[test]
+    fn test_builder_default_base_url_follows_chain() {
+        let clob = ClobBuilder::new(account()).build().unwrap();
+        assert_eq!(
+            clob.context.http.base_url.host_str(),
+            Some("clob.polymarket.com")
+        );
+
+        let clob = ClobBuilder::new(account())
+            .chain(Chain::PolygonAmoy)
+            .build()
+            .unwrap();
+        assert_eq!(
+            clob.context.http.base_url.host_str(),
+            Some("clob-staging.polymarket.com")
+        );
+        assert_eq!(clob.context.chain_id, 80002);
+
+        let clob = ClobBuilder::new(account())
+            .chain(Chain::PolygonAmoy)
+            .base_url("https://clob.example.com")
+            .build()
+            .unwrap();
+        assert_eq!(
+            clob.context.http.base_url.host_str(),
+            Some("clob.example.com")
+        );
+    }
+
+    #[test]
+    fn test_taker_requires_rfq_market() {
+        let params = params().taker(TAKER);
+        assert!(params.validate_taker(&market(Some(true))).is_ok());
+        assert!(params.validate_taker(&market(Some(false))).is_err());
+        assert!(params.validate_taker(&market(None)).is_err());
+    }
+
+    #[test]
+    fn test_zero_taker_allowed_on_any_market() {
+        assert!(params().validate_taker(&market(None)).is_ok());
+        assert!(params()
+            .taker(Address::ZERO)
+            .validate_taker(&market(Some(false)))
+            .is_ok());
+    }
+
+    #[test]
+    fn test_batch_error_carries_index() {
+        let err = ClobError::validation("bad price").at_index(3);
+        assert!(matches!(err, ClobError::BatchOrder { index: 3, .. }));
+        assert!(err.to_string().contains("index 3"));
+    }
+
+    fn book(bids: &[(&str, &str)], asks: &[(&str, &str)]) -> OrderBook {
+        let levels = |levels: &[(&str, &str)]| {
+            levels
+                .iter()
+                .map(|(price, size)| crate::OrderLevel {
+                    price: price.to_string(),
+                    size: size.to_string(),
+                })
+                .collect()
+        };
+
+        OrderBook {
+            market: "0xabc".to_string(),
+            asset_id: "123".to_string(),
+            bids: levels(bids),
+            asks: levels(asks),
+            timestamp: "0".to_string(),
+            hash: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_market_buy_walks_asks() {
+        // Asks listed worst first, as the API returns them
+        let book = book(&[], &[("0.60", "100"), ("0.55", "100"), ("0.50", "100")]);
+
+        // 50 USDC fills entirely at the best ask
+        let params = CreateMarketOrderParams::buy("123", 50.0);
+        assert_eq!(params.market_price(&book).unwrap(), 0.50);
+
+        // 80 USDC exhausts the 0.50 level (50) and reaches into 0.55
+        let params = CreateMarketOrderParams::buy("123", 80.0);
+        assert_eq!(params.market_price(&book).unwrap(), 0.55);
+    }
+
+    #[test]
+    fn test_market_sell_walks_bids() {
+        let book = book(&[("0.40", "100"), ("0.45", "100")], &[]);
+
+        let params = CreateMarketOrderParams::sell("123", 150.0);
+        assert_eq!(params.market_price(&book).unwrap(), 0.40);
+    }
+
+    #[test]
+    fn test_fok_rejects_thin_book() {
+        let book = book(&[], &[("0.50", "100")]);
+
+        let err = CreateMarketOrderParams::buy("123", 80.0)
+            .market_price(&book)
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            ClobError::InsufficientLiquidity { requested, fillable }
+                if requested == 80.0 && fillable == 50.0
+        ));
+    }
+
+    #[test]
+    fn test_fak_takes_available_depth() {
+        let thin = book(&[], &[("0.50", "100")]);
+
+        let params = CreateMarketOrderParams::buy("123", 80.0).order_type(OrderType::Fak);
+        assert_eq!(params.market_price(&thin).unwrap(), 0.50);
+
+        let empty = book(&[], &[]);
+        assert!(params.market_price(&empty).is_err());
+    }
+
+    #[test]
+    fn test_market_order_requires_immediate_kind() {
+        assert!(CreateMarketOrderParams::buy("123", 10.0).validate().is_ok());
+        assert!(CreateMarketOrderParams::buy("123", 10.0)
+            .order_type(OrderType::Gtc)
+            .validate()
+            .is_err());
+        assert!(CreateMarketOrderParams::buy("123", 0.0).validate().is_err());
+    }
+
+    #[test]
+    fn test_market_buy_to_limit_stays_within_budget() {
+        let limit = CreateMarketOrderParams::buy("123", 10.0).to_limit(0.3);
+        assert_eq!(limit.price, 0.3);
+        assert_eq!(limit.size, 33.33);
+        assert!(limit.price * limit.size <= 10.0);
+
+        let limit = CreateMarketOrderParams::sell("123", 25.0).to_limit(0.4);
+        assert_eq!(limit.size, 25.0);
+    }
+
+    fn with_type(order_type: OrderType, expiration: Option<u64>) -> CreateOrderParams {
+        CreateOrderParams {
+            order_type,
+            expiration,
+            ..params()
+        }
+    }
+
+    #[test]
+    fn test_gtd_requires_future_expiration() {
+        let now = 1_700_000_000;
+        assert!(with_type(OrderType::Gtd, Some(now + 60))
+            .validate_expiration(now)
+            .is_ok());
+        assert!(with_type(OrderType::Gtd, Some(now - 60))
+            .validate_expiration(now)
+            .is_err());
+        assert!(with_type(OrderType::Gtd, None)
+            .validate_expiration(now)
+            .is_err());
+    }
+
+    #[test]
+    fn test_immediate_orders_reject_expiration() {
+        let now = 1_700_000_000;
+        for order_type in [OrderType::Fok, OrderType::Fak] {
+            assert!(with_type(order_type, None).validate_expiration(now).is_ok());
+            assert!(with_type(order_type, Some(0))
+                .validate_expiration(now)
+                .is_ok());
+            assert!(with_type(order_type, Some(now + 60))
+                .validate_expiration(now)
+                .is_err());
+        }
+    }
+
+    #[test]
+    fn test_post_order_body_order_type() {
+        let order = params().to_order(&account(), TickSize::Hundredth, "0".to_string());
+        let signed_order = SignedOrder {
+            order,
+            signature: "0x".to_string(),
+        };
+
+        for (order_type, expected) in [
+            (OrderType::Gtc, "GTC"),
+            (OrderType::Gtd, "GTD"),
+            (OrderType::Fok, "FOK"),
+            (OrderType::Fak, "FAK"),
+        ] {
+            let body = serde_json::to_value(PostOrderBody {
+                order: &signed_order,
+                owner: "key",
+                order_type,
+                post_only: false,
+                defer_exec: false,
+            })
+            .unwrap();
+            assert_eq!(body["orderType"], expected);
+            assert_eq!(body["order"]["signature"], "0x");
+        }
+    }
+
+    #[test]
+    fn test_post_only_flag_serialized() {
+        let order = params().to_order(&account(), TickSize::Hundredth, "0".to_string());
+        let signed_order = SignedOrder {
+            order,
+            signature: "0x".to_string(),
+        };
+
+        let body = serde_json::to_value(PostOrderBody {
+            order: &signed_order,
+            owner: "key",
+            order_type: OrderType::Gtc,
+            post_only: true,
+            defer_exec: false,
+        })
+        .unwrap();
+        assert_eq!(body["postOnly"], true);
+    }
+
+    #[test]
+    fn test_defer_exec_serialized_only_when_set() {
+        let order = params().to_order(&account(), TickSize::Hundredth, "0".to_string());
+        let signed_order = SignedOrder {
+            order,
+            signature: "0x".to_string(),
+        };
+
+        for (defer_exec, expected) in [(false, None), (true, Some(true))] {
+            let params = params().defer_exec(defer_exec);
+            let body = serde_json::to_value(PostOrderBody {
+                order: &signed_order,
+                owner: "key",
+                order_type: params.order_type,
+                post_only: params.post_only,
+                defer_exec: params.defer_exec,
+            })
+            .unwrap();
+            assert_eq!(body.get("deferExec").and_then(|v| v.as_bool()), expected);
+        }
+    }
+
+    #[test]
+    fn test_post_only_requires_resting_type() {
+        let params = params().post_only(true);
+        assert!(params.validate().is_ok());
+
+        let params = params.order_type(OrderType::Fok);
+        assert!(params.validate().is_err());
+    }
+
+    #[test]
+    fn test_post_only_rejection_is_typed() {
+        let message = "invalid post-only order: order crosses book";
+        let rejection = |message: &str| {
+            ClobError::Api(ApiError::Api {
+                status: 400,
+                code: None,
+                message: message.to_string(),
+                raw: String::new(),
+            })
+        };
+
+        let err = post_only_result(Err(rejection(message))).unwrap_err();
+        assert!(matches!(err, ClobError::PostOnlyRejected(ref m) if m == message));
+
+        let response: OrderResponse = serde_json::from_value(serde_json::json!({
+            "success": false,
+            "errorMsg": message,
+        }))
+        .unwrap();
+        let err = post_only_result(Ok(response)).unwrap_err();
+        assert!(matches!(err, ClobError::PostOnlyRejected(_)));
+
+        // Unrelated failures are left untouched
+        let err = post_only_result(Err(rejection("bad price"))).unwrap_err();
+        assert_eq!(err.status(), Some(400));
+        assert!(matches!(err, ClobError::Api(ApiError::Api { .. })));
+    }
+
+    #[test]
+    fn test_accepting_market_allowed() {
+        assert!(params().validate_market_open(&market(None)).is_ok());
+    }
+
+    #[test]
+    fn test_closed_market_rejected() {
+        let mut closed = market(None);
+        closed.closed = true;
+        closed.accepting_orders = Some(false);
+
+        let err = params().validate_market_open(&closed).unwrap_err();
+        assert!(err.to_string().contains("not accepting orders"));
+    }
+
+    #[test]
+    fn test_force_skips_market_open_check() {
+        let mut closed = market(None);
+        closed.closed = true;
+
+        let params = params().force(true);
+        assert!(params.validate_market_open(&closed).is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_public_get_uses_shared_retrying_client() {
+        let transport = MockTransport::after_unavailable(r#"{"mid": "0.5"}"#);
+        let clob = ClobBuilder::new(account())
+            .transport(transport.clone())
+            .build()
+            .unwrap();
+
+        let midpoint = clob.markets().midpoint("123").send().await.unwrap();
+        assert_eq!(midpoint.mid, "0.5");
+        assert_eq!(transport.request_count(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_failed_order_not_sent_to_fallback() {
+        let market = market(None);
+        let transport =
+            MockTransport::new(
+                move |request| match (request.url.host_str(), request.path()) {
+                    (_, "/fee-rate") => MockResponse::ok(r#"{"feeRateBps": "0"}"#),
+                    (Some("primary.example.com"), "/order") => MockResponse::status(503, "{}"),
+                    (_, "/order") => MockResponse::ok(r#"{"success": true}"#),
+                    _ => MockResponse::json(&market),
+                },
+            );
+        let clob = ClobBuilder::new(account())
+            .base_url("https://primary.example.com")
+            .fallback_base_url("https://fallback.example.com")
+            .transport(transport.clone())
+            .build()
+            .unwrap();
+
+        // The primary may have placed the order despite the 503
+        let err = clob.place_order(&params()).await.unwrap_err();
+        assert!(matches!(
+            err,
+            ClobError::Api(ApiError::Api { status: 503, .. })
+        ));
+
+        let posts: Vec<_> = transport
+            .requests()
+            .into_iter()
+            .filter(|request| request.path() == "/order")
+            .collect();
+        assert_eq!(posts.len(), 1);
+        assert_eq!(posts[0].url.host_str(), Some("primary.example.com"));
+    }
+
+    /// `0xorder` as returned by the CLOB, placed at 1700000000
+    fn placed_order() -> MockResponse {
+        MockResponse::json(&open_order())
+    }
+
+    /// An order of the account in market `0xabc`
+    fn open_order() -> serde_json::Value {
+        serde_json::json!({
+            "id": "0xorder",
+            "market": "0xabc",
+            "assetId": "123",
+            "salt": "1",
+            "maker": SIGNER,
+            "signer": SIGNER,
+            "taker": "0x0000000000000000000000000000000000000000",
+            "tokenId": "123",
+            "makerAmount": "5200000",
+            "takerAmount": "10000000",
+            "expiration": "0",
+            "nonce": "0",
+            "feeRateBps": "0",
+            "side": "BUY",
+            "signatureType": "eoa",
+            "signature": "0x",
+            "status": "MATCHED",
+            "createdAt": "1700000000"
+        })
+    }
+
+    /// Trades of the account in two pages, with the order's fills split
+    /// across them and an unrelated trade on the first
+    fn trade_pages(status: &str, transaction_hash: &str) -> [MockResponse; 2] {
+        let trade = |id: &str, taker: &str, maker: &str, time: &str, status: &str, hash: &str| {
+            serde_json::json!({
+                "id": id,
+                "taker_order_id": taker,
+                "market": "0xabc",
+                "asset_id": "123",
+                "side": "BUY",
+                "size": "10",
+                "fee_rate_bps": "0",
+                "price": "0.52",
+                "status": status,
+                "match_time": time,
+                "outcome": "Yes",
+                "owner": SIGNER,
+                "transaction_hash": hash,
+                "maker_orders": [{
+                    "order_id": maker,
+                    "asset_id": "123",
+                    "matched_amount": "10",
+                    "price": "0.52",
+                    "outcome": "Yes"
+                }]
+            })
+        };
+        [
+            MockResponse::json(&serde_json::json!({
+                "next_cursor": "page2",
+                "data": [
+                    trade("trade-0", "0xother", "0xmaker", "1700000000", "CONFIRMED", "0xdead"),
+                    trade("trade-2", "0xtaker", "0xorder", "1700000005", status, "0xbeef"),
+                ]
+            })),
+            MockResponse::json(&serde_json::json!({
+                "next_cursor": END_CURSOR,
+                "data": [
+                    trade("trade-1", "0xorder", "0xmaker", "1700000001", status, transaction_hash),
+                ]
+            })),
+        ]
+    }
+
+    /// Serve the order, then one round of trade pages per status
+    fn settlement(rounds: &[(&str, &str)]) -> Arc<MockTransport> {
+        MockTransport::sequence(
+            std::iter::once(placed_order()).chain(
+                rounds
+                    .iter()
+                    .flat_map(|(status, hash)| trade_pages(status, hash)),
+            ),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_await_settlement_returns_transaction_hashes() {
+        let transport = settlement(&[
+            ("MATCHED", ""),
+            ("MINED", "0xfeed"),
+            ("CONFIRMED", "0xfeed"),
+        ]);
+        let clob = ClobBuilder::new(account())
+            .transport(transport.clone())
+            .build()
+            .unwrap();
+
+        let hashes = clob
+            .poll_settlement("0xorder", Duration::from_secs(5), Duration::from_millis(10))
+            .await
+            .unwrap();
+        // Taker and maker fills from both pages, oldest first, without the
+        // unrelated trade
+        assert_eq!(hashes, ["0xfeed", "0xbeef"]);
+
+        let requests = transport.requests();
+        assert_eq!(requests[0].path(), "/data/order/0xorder");
+        assert_eq!(requests[1].path(), "/data/trades");
+        assert_eq!(requests[1].query("asset_id").as_deref(), Some("123"));
+        assert_eq!(requests[1].query("after").as_deref(), Some("1699999999"));
+        assert_eq!(requests[2].query("next_cursor").as_deref(), Some("page2"));
+    }
+
+    #[tokio::test]
+    async fn test_await_settlement_failure_and_timeout() {
+        let transport = settlement(&[("MATCHED", ""), ("FAILED", "")]);
+        let clob = ClobBuilder::new(account())
+            .transport(transport)
+            .build()
+            .unwrap();
+        let err = clob
+            .poll_settlement("0xorder", Duration::from_secs(5), Duration::from_millis(10))
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(err, ClobError::SettlementFailed { ref trade_id, .. } if trade_id == "trade-2")
+        );
+
+        let transport = MockTransport::new(|request| {
+            let [first, second] = trade_pages("MATCHED", "");
+            if request.path() == "/data/order/0xorder" {
+                placed_order()
+            } else if request.query("next_cursor").as_deref() == Some("page2") {
+                second
+            } else {
+                first
+            }
+        });
+        let clob = ClobBuilder::new(account())
+            .transport(transport)
+            .build()
+            .unwrap();
+        let err = clob
+            .poll_settlement(
+                "0xorder",
+                Duration::from_millis(50),
+                Duration::from_millis(10),
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ClobError::SettlementTimeout { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_await_settlement_unknown_order() {
+        let clob = ClobBuilder::new(account())
+            .transport(MockTransport::always(MockResponse::status(404, "")))
+            .build()
+            .unwrap();
+        let err = clob
+            .await_settlement("0xorder", Duration::from_secs(5))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ClobError::Api(ApiError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_transport_receives_signed_request() {
+        let transport = MockTransport::always(MockResponse::ok(
+            r#"{"canceled": ["0xabc"], "not_canceled": {}}"#,
+        ));
+        let clob = ClobBuilder::new(account())
+            .transport(transport.clone())
+            .build()
+            .unwrap();
+
+        let response = clob.orders().cancel("0xabc").send().await.unwrap();
+        assert_eq!(response.canceled, ["0xabc"]);
+
+        let requests = transport.requests();
+        let [request] = requests.as_slice() else {
+            panic!("expected a single request, got {}", requests.len());
+        };
+        assert_eq!(request.method, reqwest::Method::DELETE);
+        assert_eq!(request.url.as_str(), "https://clob.polymarket.com/order");
+
+        let body = request.body_text();
+        assert_eq!(body, r#"{"orderID":"0xabc"}"#);
+
+        let headers = &request.headers;
+        assert_eq!(headers["poly_address"], format!("{:?}", SIGNER).as_str());
+        assert_eq!(headers["poly_api_key"], "test_key");
+        assert_eq!(headers["poly_passphrase"], "test_pass");
+
+        // The signature covers the exact body that was sent
+        let timestamp: u64 = headers["poly_timestamp"].to_str().unwrap().parse().unwrap();
+        let message =
+            crate::account::Signer::create_message(timestamp, "DELETE", "/order", Some(body));
+        let signer = crate::account::Signer::new("c2VjcmV0").unwrap();
+        assert_eq!(
+            headers["poly_signature"],
+            signer.sign(&message).unwrap().as_str()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_sync_time_offsets_signed_timestamps() {
+        let server_time = current_timestamp() + 600;
+        let transport = MockTransport::new(move |request| match request.path() {
+            "/time" => MockResponse::ok(server_time.to_string()),
+            _ => MockResponse::ok(r#"{"canceled": [], "not_canceled": {}}"#),
+        });
+        let clob = ClobBuilder::new(account())
+            .transport(transport.clone())
+            .sync_time(true)
+            .build()
+            .unwrap();
+        assert_eq!(clob.time_offset(), 0);
+
+        clob.orders().cancel_all().send().await.unwrap();
+        clob.orders().cancel_all().send().await.unwrap();
+        assert!((599..=600).contains(&clob.time_offset()));
+
+        // The server time is fetched once, before the first signed request
+        let requests = transport.requests();
+        let paths: Vec<&str> = requests.iter().map(|r| r.path()).collect();
+        assert_eq!(paths, ["/time", "/cancel-all", "/cancel-all"]);
+
+        let timestamp: u64 = requests[1]
+            .header("poly_timestamp")
+            .unwrap()
+            .parse()
+            .unwrap();
+        assert!((server_time..=server_time + 1).contains(&timestamp));
+    }
+
+    #[test]
+    fn test_hundredth_tick_size() {
+        let mut order = params();
+        assert!(order.validate_tick_size(TickSize::Hundredth).is_ok());
+
+        order.price = 0.525;
+        let err = order.validate_tick_size(TickSize::Hundredth).unwrap_err();
+        assert!(err.to_string().contains("tick size 0.01"));
+    }
+
+    #[test]
+    fn test_thousandth_tick_size() {
+        let mut order = params();
+        order.price = 0.525;
+        assert!(order.validate_tick_size(TickSize::Thousandth).is_ok());
+
+        order.price = 0.5255;
+        let err = order.validate_tick_size(TickSize::Thousandth).unwrap_err();
+        assert!(err.to_string().contains("tick size 0.001"));
+    }
+
+    #[test]
+    fn test_round_to_tick() {
+        assert_eq!(TickSize::Hundredth.round_to_tick(0.526), 0.53);
+        assert_eq!(TickSize::Thousandth.round_to_tick(0.5254), 0.525);
+        assert_eq!(TickSize::Tenth.round_to_tick(0.04), 0.0);
+    }
+
+    #[tokio::test]
+    async fn test_post_orders_body_matches_golden_payload() {
+        let transport = MockTransport::always(MockResponse::ok(
+            r#"[{"success": true, "errorMsg": "", "orderID": "0x1"}, {"success": true, "errorMsg": "", "orderID": "0x2"}]"#,
+        ));
+        let clob = ClobBuilder::new(account())
+            .transport(transport.clone())
+            .build()
+            .unwrap();
+
+        let single: serde_json::Value =
+            serde_json::from_str(include_str!("../tests/fixtures/post_order_body.json")).unwrap();
+        let gtc: SignedOrder = serde_json::from_value(single["order"].clone()).unwrap();
+        let mut gtd = gtc.clone();
+        gtd.order.expiration = "1900000000".to_string();
+
+        let responses = clob.post_orders(&[gtc, gtd]).await.unwrap();
+        assert_eq!(responses.len(), 2);
+
+        let requests = transport.requests();
+        let [request] = requests.as_slice() else {
+            panic!("expected a single request, got {}", requests.len());
+        };
+        assert_eq!(request.path(), "/orders");
+
+        let expected: serde_json::Value =
+            serde_json::from_str(include_str!("../tests/fixtures/post_orders_body.json")).unwrap();
+        assert_eq!(request.body_json(), expected);
+    }
+}
@@ -1,3 +1,5 @@
+use std::fmt;
+
 use alloy::primitives::{address, Address};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -23,6 +25,32 @@ impl Chain {
         }
     }
 
+    /// Get the API endpoints for this chain
+    pub const fn endpoints(&self) -> Endpoints {
+        match self {
+            Chain::PolygonMainnet => Endpoints::POLYGON_MAINNET,
+            Chain::PolygonAmoy => Endpoints::POLYGON_AMOY,
+        }
+    }
+
+    /// Get the block explorer base URL
+    pub const fn explorer_url(&self) -> &'static str {
+        match self {
+            Chain::PolygonMainnet => "https://polygonscan.com",
+            Chain::PolygonAmoy => "https://amoy.polygonscan.com",
+        }
+    }
+
+    /// Get the block explorer URL of a transaction
+    pub fn explorer_tx_url(&self, hash: &str) -> String {
+        format!("{}/tx/{}", self.explorer_url(), hash)
+    }
+
+    /// Get the block explorer URL of an address
+    pub fn explorer_address_url(&self, address: impl fmt::Display) -> String {
+        format!("{}/address/{}", self.explorer_url(), address)
+    }
+
     /// Create Chain from chain ID
     pub const fn from_chain_id(chain_id: u64) -> Option<Self> {
         match chain_id {
@@ -63,6 +91,33 @@ impl Contracts {
     };
 }
 
+/// Polymarket API base URLs for different chains
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Endpoints {
+    pub clob: &'static str,
+    pub gamma: &'static str,
+    pub data: &'static str,
+}
+
+impl Endpoints {
+    /// Polygon mainnet endpoints (chain ID 137)
+    pub const POLYGON_MAINNET: Self = Self {
+        clob: "https://clob.polymarket.com",
+        gamma: "https://gamma-api.polymarket.com",
+        data: "https://data-api.polymarket.com",
+    };
+
+    /// Polygon Amoy testnet endpoints (chain ID 80002).
+    ///
+    /// Only the CLOB has a staging deployment; Gamma and Data serve
+    /// mainnet markets.
+    pub const POLYGON_AMOY: Self = Self {
+        clob: "https://clob-staging.polymarket.com",
+        gamma: "https://gamma-api.polymarket.com",
+        data: "https://data-api.polymarket.com",
+    };
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -81,6 +136,32 @@ mod tests {
         assert_eq!(Chain::from_chain_id(999), None);
     }
 
+    #[test]
+    fn test_explorer_tx_url() {
+        let hash = "0x5c504ed432cb51138bcf09aa5e8a410dd4a1e204ef84bfed1be16dfba1b22060";
+        assert_eq!(
+            Chain::PolygonMainnet.explorer_tx_url(hash),
+            format!("https://polygonscan.com/tx/{}", hash)
+        );
+        assert_eq!(
+            Chain::PolygonAmoy.explorer_tx_url(hash),
+            format!("https://amoy.polygonscan.com/tx/{}", hash)
+        );
+    }
+
+    #[test]
+    fn test_explorer_address_url() {
+        let exchange = Contracts::POLYGON_MAINNET.exchange;
+        assert_eq!(
+            Chain::PolygonMainnet.explorer_address_url(exchange),
+            "https://polygonscan.com/address/0x4bFb41d5B3570DeFd03C39a9A4D8dE6Bd8B8982E"
+        );
+        assert_eq!(
+            Chain::PolygonAmoy.explorer_address_url("0xabc"),
+            "https://amoy.polygonscan.com/address/0xabc"
+        );
+    }
+
     #[test]
     fn test_chain_contracts() {
         let mainnet_contracts = Chain::PolygonMainnet.contracts();
@@ -93,6 +174,18 @@ mod tests {
         assert_eq!(amoy_contracts.exchange, Contracts::POLYGON_AMOY.exchange);
     }
 
+    #[test]
+    fn test_chain_endpoints() {
+        assert_eq!(
+            Chain::PolygonMainnet.endpoints().clob,
+            "https://clob.polymarket.com"
+        );
+        assert_eq!(
+            Chain::PolygonAmoy.endpoints().clob,
+            "https://clob-staging.polymarket.com"
+        );
+    }
+
     #[test]
     fn test_polygon_mainnet_addresses() {
         let contracts = Contracts::POLYGON_MAINNET;
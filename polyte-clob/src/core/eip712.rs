@@ -5,11 +5,7 @@ use alloy::{
     sol_types::SolStruct,
 };
 
-use crate::{
-    core::chain::Chain,
-    error::ClobError,
-    types::{Order, SignatureType},
-};
+use crate::{core::chain::Chain, error::ClobError, types::Order};
 
 sol! {
     #[derive(Debug, PartialEq, Eq)]
@@ -37,23 +33,34 @@ sol! {
     }
 }
 
-/// Sign an order with EIP-712
-pub async fn sign_order<S: AlloySigner>(
-    order: &Order,
-    signer: &S,
-    chain_id: u64,
-) -> Result<String, ClobError> {
+/// EIP-712 domain of the exchange an order is signed against.
+///
+/// Negative-risk markets settle on their own exchange contract, so orders
+/// on them must be signed against it rather than the regular exchange.
+fn order_domain(chain_id: u64, neg_risk: bool) -> Result<EIP712Domain, ClobError> {
     let chain = Chain::from_chain_id(chain_id)
         .ok_or_else(|| ClobError::Crypto(format!("Unsupported chain ID: {}", chain_id)))?;
     let contracts = chain.contracts();
 
-    // Create EIP-712 domain
-    let domain = EIP712Domain {
+    Ok(EIP712Domain {
         name: "Polymarket CTF Exchange".to_string(),
         version: "1".to_string(),
         chainId: U256::from(chain_id),
-        verifyingContract: contracts.neg_risk_exchange,
-    };
+        verifyingContract: if neg_risk {
+            contracts.neg_risk_exchange
+        } else {
+            contracts.exchange
+        },
+    })
+}
+
+/// Sign an order with EIP-712
+pub async fn sign_order<S: AlloySigner>(
+    order: &Order,
+    signer: &S,
+    chain_id: u64,
+) -> Result<String, ClobError> {
+    let domain = order_domain(chain_id, order.neg_risk)?;
 
     // Convert order to struct
     let order_struct = OrderStruct {
@@ -78,11 +85,7 @@ pub async fn sign_order<S: AlloySigner>(
             crate::types::OrderSide::Buy => 0,
             crate::types::OrderSide::Sell => 1,
         },
-        signatureType: match order.signature_type {
-            SignatureType::Eoa => 0,
-            SignatureType::PolyProxy => 1,
-            SignatureType::PolyGnosisSafe => 2,
-        },
+        signatureType: order.signature_type.as_u8(),
     };
 
     // Compute struct hash
@@ -150,3 +153,33 @@ pub async fn sign_clob_auth<S: AlloySigner>(
 
     Ok(format!("0x{}", hex::encode(signature.as_bytes())))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::chain::Contracts;
+
+    #[test]
+    fn test_order_domain_selects_exchange() {
+        let regular = order_domain(137, false).unwrap();
+        let neg_risk = order_domain(137, true).unwrap();
+
+        assert_eq!(
+            regular.verifyingContract,
+            Contracts::POLYGON_MAINNET.exchange
+        );
+        assert_eq!(
+            neg_risk.verifyingContract,
+            Contracts::POLYGON_MAINNET.neg_risk_exchange
+        );
+        assert_ne!(
+            keccak256(regular.eip712_hash_struct()),
+            keccak256(neg_risk.eip712_hash_struct())
+        );
+    }
+
+    #[test]
+    fn test_order_domain_unsupported_chain() {
+        assert!(matches!(order_domain(1, false), Err(ClobError::Crypto(_))));
+    }
+}
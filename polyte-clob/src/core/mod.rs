@@ -1,2 +1,3 @@
 pub mod chain;
 pub mod eip712;
+pub mod positions;
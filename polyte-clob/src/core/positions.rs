@@ -0,0 +1,312 @@
+use alloy::{
+    primitives::{Address, Bytes, B256, U256},
+    sol,
+    sol_types::SolCall,
+};
+use serde::Serialize;
+
+use crate::{core::chain::Contracts, error::ClobError};
+
+sol! {
+    /// Polymarket's adapter wrapping the conditional tokens of neg-risk markets
+    interface NegRiskAdapter {
+        function splitPosition(bytes32 conditionId, uint256 amount);
+        function mergePositions(bytes32 conditionId, uint256 amount);
+        function convertPositions(bytes32 marketId, uint256 indexSet, uint256 amount);
+    }
+
+    /// Gnosis Conditional Tokens Framework
+    interface ConditionalTokens {
+        function splitPosition(
+            address collateralToken,
+            bytes32 parentCollectionId,
+            bytes32 conditionId,
+            uint256[] partition,
+            uint256 amount
+        );
+        function mergePositions(
+            address collateralToken,
+            bytes32 parentCollectionId,
+            bytes32 conditionId,
+            uint256[] partition,
+            uint256 amount
+        );
+    }
+}
+
+/// Operation on outcome positions, settled on-chain rather than through the
+/// CLOB API.
+///
+/// Amounts are in base units (6 decimals, see
+/// [`USDC_DECIMALS`](crate::USDC_DECIMALS)). Build the contract call with
+/// [`PositionRequest::to_transaction`] and send it from the wallet holding
+/// the positions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PositionRequest {
+    /// Turn `amount` of collateral into `amount` of every outcome token
+    Split {
+        condition_id: B256,
+        amount: U256,
+        neg_risk: bool,
+    },
+    /// Turn `amount` of every outcome token back into `amount` of collateral
+    Merge {
+        condition_id: B256,
+        amount: U256,
+        neg_risk: bool,
+    },
+    /// Turn `amount` NO tokens of each question in `index_set` into YES
+    /// tokens of every other question of the neg-risk market, plus
+    /// collateral when more than one NO is converted
+    Convert {
+        market_id: B256,
+        index_set: U256,
+        amount: U256,
+    },
+}
+
+impl PositionRequest {
+    /// Split collateral into the outcome tokens of `condition_id`
+    pub fn split(condition_id: &str, amount: U256) -> Result<Self, ClobError> {
+        Ok(Self::Split {
+            condition_id: parse_id("condition ID", condition_id)?,
+            amount: positive(amount)?,
+            neg_risk: false,
+        })
+    }
+
+    /// Merge the outcome tokens of `condition_id` back into collateral
+    pub fn merge(condition_id: &str, amount: U256) -> Result<Self, ClobError> {
+        Ok(Self::Merge {
+            condition_id: parse_id("condition ID", condition_id)?,
+            amount: positive(amount)?,
+            neg_risk: false,
+        })
+    }
+
+    /// Convert NO tokens of the questions at `outcome_indices` of the
+    /// neg-risk market `market_id` (a market's `negRiskMarketId`)
+    pub fn convert(
+        market_id: &str,
+        outcome_indices: &[usize],
+        amount: U256,
+    ) -> Result<Self, ClobError> {
+        if outcome_indices.is_empty() {
+            return Err(ClobError::validation(
+                "Converting positions needs at least one outcome index",
+            ));
+        }
+
+        let mut index_set = U256::ZERO;
+        for &index in outcome_indices {
+            if index >= 256 {
+                return Err(ClobError::validation(format!(
+                    "Outcome index {} is out of range",
+                    index
+                )));
+            }
+            if index_set.bit(index) {
+                return Err(ClobError::validation(format!(
+                    "Outcome index {} is repeated",
+                    index
+                )));
+            }
+            index_set.set_bit(index, true);
+        }
+
+        Ok(Self::Convert {
+            market_id: parse_id("market ID", market_id)?,
+            index_set,
+            amount: positive(amount)?,
+        })
+    }
+
+    /// Route a split or merge through the neg-risk adapter, as required for
+    /// markets of a neg-risk group. No effect on conversions.
+    pub fn neg_risk(mut self, neg_risk: bool) -> Self {
+        if let Self::Split { neg_risk: flag, .. } | Self::Merge { neg_risk: flag, .. } = &mut self {
+            *flag = neg_risk;
+        }
+        self
+    }
+
+    /// Contract call carrying out the request.
+    ///
+    /// Neg-risk splits and merges and all conversions call
+    /// `contracts.neg_risk_adapter`; the others call the conditional tokens
+    /// contract directly with the binary partition of the condition. The
+    /// called contract must be approved beforehand: to spend collateral for
+    /// a split, and as an operator of the conditional tokens for merges and
+    /// conversions through the adapter.
+    pub fn to_transaction(&self, contracts: &Contracts) -> PositionTransaction {
+        match *self {
+            Self::Split {
+                condition_id,
+                amount,
+                neg_risk: true,
+            } => PositionTransaction::new(
+                contracts.neg_risk_adapter,
+                NegRiskAdapter::splitPositionCall {
+                    conditionId: condition_id,
+                    amount,
+                }
+                .abi_encode(),
+            ),
+            Self::Merge {
+                condition_id,
+                amount,
+                neg_risk: true,
+            } => PositionTransaction::new(
+                contracts.neg_risk_adapter,
+                NegRiskAdapter::mergePositionsCall {
+                    conditionId: condition_id,
+                    amount,
+                }
+                .abi_encode(),
+            ),
+            Self::Split {
+                condition_id,
+                amount,
+                neg_risk: false,
+            } => PositionTransaction::new(
+                contracts.conditional_tokens,
+                ConditionalTokens::splitPositionCall {
+                    collateralToken: contracts.collateral,
+                    parentCollectionId: B256::ZERO,
+                    conditionId: condition_id,
+                    partition: binary_partition(),
+                    amount,
+                }
+                .abi_encode(),
+            ),
+            Self::Merge {
+                condition_id,
+                amount,
+                neg_risk: false,
+            } => PositionTransaction::new(
+                contracts.conditional_tokens,
+                ConditionalTokens::mergePositionsCall {
+                    collateralToken: contracts.collateral,
+                    parentCollectionId: B256::ZERO,
+                    conditionId: condition_id,
+                    partition: binary_partition(),
+                    amount,
+                }
+                .abi_encode(),
+            ),
+            Self::Convert {
+                market_id,
+                index_set,
+                amount,
+            } => PositionTransaction::new(
+                contracts.neg_risk_adapter,
+                NegRiskAdapter::convertPositionsCall {
+                    marketId: market_id,
+                    indexSet: index_set,
+                    amount,
+                }
+                .abi_encode(),
+            ),
+        }
+    }
+}
+
+/// Unsigned contract call for a [`PositionRequest`], to be signed and sent
+/// by the wallet holding the positions
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PositionTransaction {
+    /// Contract to call
+    pub to: Address,
+    /// ABI-encoded calldata
+    pub data: Bytes,
+}
+
+impl PositionTransaction {
+    fn new(to: Address, data: Vec<u8>) -> Self {
+        Self {
+            to,
+            data: data.into(),
+        }
+    }
+}
+
+/// Index sets of the two outcome slots of a binary condition
+fn binary_partition() -> Vec<U256> {
+    vec![U256::from(1), U256::from(2)]
+}
+
+fn parse_id(name: &str, id: &str) -> Result<B256, ClobError> {
+    id.trim()
+        .parse()
+        .map_err(|_| ClobError::validation(format!("Invalid {}: {}", name, id)))
+}
+
+fn positive(amount: U256) -> Result<U256, ClobError> {
+    if amount.is_zero() {
+        return Err(ClobError::validation("Amount must be positive"));
+    }
+    Ok(amount)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONDITION_ID: &str = "0x5f65177b394277fd294cd75650044e32ba009a95022d88a0c1d565897d72f8f1";
+    const MARKET_ID: &str = "0xe3b423dfad8c22ff75c9899c4e8176f628cf4ad4caa00481764d320e7415f700";
+
+    #[test]
+    fn test_split_through_conditional_tokens() {
+        let amount = U256::from(5_000_000);
+        let request = PositionRequest::split(CONDITION_ID, amount).unwrap();
+        let contracts = Contracts::POLYGON_MAINNET;
+        let tx = request.to_transaction(&contracts);
+
+        assert_eq!(tx.to, contracts.conditional_tokens);
+        let call = ConditionalTokens::splitPositionCall::abi_decode(&tx.data).unwrap();
+        assert_eq!(call.collateralToken, contracts.collateral);
+        assert_eq!(call.parentCollectionId, B256::ZERO);
+        assert_eq!(call.conditionId, CONDITION_ID.parse::<B256>().unwrap());
+        assert_eq!(call.partition, binary_partition());
+        assert_eq!(call.amount, amount);
+    }
+
+    #[test]
+    fn test_neg_risk_merge_through_adapter() {
+        let request = PositionRequest::merge(CONDITION_ID, U256::from(1))
+            .unwrap()
+            .neg_risk(true);
+        let contracts = Contracts::POLYGON_MAINNET;
+        let tx = request.to_transaction(&contracts);
+
+        assert_eq!(tx.to, contracts.neg_risk_adapter);
+        assert_eq!(
+            tx.data[..4],
+            NegRiskAdapter::mergePositionsCall::SELECTOR[..]
+        );
+    }
+
+    #[test]
+    fn test_convert_index_set() {
+        let request = PositionRequest::convert(MARKET_ID, &[0, 2], U256::from(10)).unwrap();
+        let tx = request.to_transaction(&Contracts::POLYGON_AMOY);
+
+        assert_eq!(tx.to, Contracts::POLYGON_AMOY.neg_risk_adapter);
+        let call = NegRiskAdapter::convertPositionsCall::abi_decode(&tx.data).unwrap();
+        assert_eq!(call.marketId, MARKET_ID.parse::<B256>().unwrap());
+        assert_eq!(call.indexSet, U256::from(0b101));
+        assert_eq!(call.amount, U256::from(10));
+
+        // Conversions always go through the adapter
+        assert_eq!(request.clone().neg_risk(false), request);
+    }
+
+    #[test]
+    fn test_invalid_requests() {
+        assert!(PositionRequest::split("0xabc", U256::from(1)).is_err());
+        assert!(PositionRequest::merge(CONDITION_ID, U256::ZERO).is_err());
+        assert!(PositionRequest::convert(MARKET_ID, &[], U256::from(1)).is_err());
+        assert!(PositionRequest::convert(MARKET_ID, &[1, 1], U256::from(1)).is_err());
+        assert!(PositionRequest::convert(MARKET_ID, &[256], U256::from(1)).is_err());
+    }
+}
@@ -1,4 +1,6 @@
-use polyte_core::ApiError;
+use std::time::Duration;
+
+use polyte_core::{body_snippet, ApiError, RequestError};
 use thiserror::Error;
 
 use crate::types::ParseTickSizeError;
@@ -10,6 +12,15 @@ pub enum ClobError {
     #[error(transparent)]
     Api(#[from] ApiError),
 
+    /// Response body did not match the expected schema
+    #[error("Failed to decode response: {source}")]
+    Decode {
+        #[source]
+        source: serde_json::Error,
+        /// Start of the raw response body
+        body: String,
+    },
+
     /// Cryptographic operation failed
     #[error("Crypto error: {0}")]
     Crypto(String),
@@ -21,18 +32,85 @@ pub enum ClobError {
     /// Invalid tick size
     #[error(transparent)]
     InvalidTickSize(#[from] ParseTickSizeError),
+
+    /// Order rejected by a pre-trade risk check
+    #[error("Risk check rejected order: {0}")]
+    RiskRejected(String),
+
+    /// Post-only order rejected because it would have matched immediately
+    #[error("Post-only order rejected: {0}")]
+    PostOnlyRejected(String),
+
+    /// Not enough depth on the book to fill a market order
+    #[error("Insufficient liquidity: requested {requested}, only {fillable} fillable")]
+    InsufficientLiquidity { requested: f64, fillable: f64 },
+
+    /// A trade filling the order failed to settle on chain
+    #[error("Settlement of order {order_id} failed in trade {trade_id}")]
+    SettlementFailed { order_id: String, trade_id: String },
+
+    /// The order did not settle on chain before the timeout
+    #[error("Order {order_id} not settled after {timeout:?}")]
+    SettlementTimeout { order_id: String, timeout: Duration },
+
+    /// An order in a batch failed, identified by its index in the batch
+    #[error("Order at index {index} failed: {source}")]
+    BatchOrder {
+        index: usize,
+        #[source]
+        source: Box<ClobError>,
+    },
 }
 
 impl ClobError {
-    /// Create error from HTTP response
-    pub(crate) async fn from_response(response: reqwest::Response) -> Self {
-        Self::Api(ApiError::from_response(response).await)
+    /// Attach the index of the failing order in a batch
+    pub(crate) fn at_index(self, index: usize) -> Self {
+        Self::BatchOrder {
+            index,
+            source: Box::new(self),
+        }
     }
 
     /// Create validation error
     pub(crate) fn validation(msg: impl Into<String>) -> Self {
         Self::Api(ApiError::Validation(msg.into()))
     }
+
+    /// HTTP status of the failed response, when known
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            Self::Api(err) => err.status(),
+            Self::BatchOrder { source, .. } => source.status(),
+            _ => None,
+        }
+    }
+
+    /// Error code returned by the CLOB, if any
+    pub fn code(&self) -> Option<&str> {
+        match self {
+            Self::Api(err) => err.code(),
+            Self::BatchOrder { source, .. } => source.code(),
+            _ => None,
+        }
+    }
+
+    /// Whether the CLOB rejected the request for exceeding its rate limit
+    pub fn is_rate_limited(&self) -> bool {
+        self.status() == Some(429)
+    }
+}
+
+impl RequestError for ClobError {
+    async fn from_response(response: reqwest::Response) -> Self {
+        Self::Api(ApiError::from_response(response).await)
+    }
+
+    fn from_decode(source: serde_json::Error, body: &[u8]) -> Self {
+        Self::Decode {
+            source,
+            body: body_snippet(body),
+        }
+    }
 }
 
 impl From<alloy::signers::Error> for ClobError {
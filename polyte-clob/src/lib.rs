@@ -26,13 +26,7 @@
 //!         .build()?;
 //!
 //!     // Place an order
-//!     let params = CreateOrderParams {
-//!         token_id: "token_id".to_string(),
-//!         price: 0.52,
-//!         size: 100.0,
-//!         side: OrderSide::Buy,
-//!         expiration: None,
-//!     };
+//!     let params = CreateOrderParams::new("token_id", 0.52, 100.0, OrderSide::Buy);
 //!
 //!     let response = clob.place_order(&params).await?;
 //!     println!("Order ID: {:?}", response.order_id);
@@ -47,25 +41,42 @@ pub mod client;
 pub mod core;
 pub mod error;
 pub mod request;
+pub mod risk;
+#[cfg(test)]
+pub(crate) mod testing;
 pub mod types;
 pub mod utils;
 
 #[cfg(feature = "ws")]
 pub mod ws;
 
-pub use core::chain::{Chain, Contracts};
+pub use core::chain::{Chain, Contracts, Endpoints};
+pub use core::positions::{PositionRequest, PositionTransaction};
 
 pub use account::{Account, AccountConfig, Credentials, Signer, Wallet};
 pub use api::{
-    account::{BalanceAllowanceResponse, Trade},
+    account::{
+        from_base_units, AssetType, BalanceAllowanceRequest, BalanceAllowanceResponse, Trade,
+        TradesPage, TradesRequest, END_CURSOR, USDC_DECIMALS,
+    },
     markets::{
         ListMarketsResponse, Market, MarketToken, MidpointResponse, OrderBook, OrderLevel,
-        PriceResponse,
+        PricePoint, PriceResponse, PricesHistory, QuoteIssue, QuoteSanity, PRICE_HISTORY_INTERVALS,
+    },
+    orders::{
+        CancelResponse, OpenOrder, OrderResponse, OrderScoring, OrdersScoring, PlacedOrder,
+        MAX_CANCEL_BATCH,
     },
-    orders::{CancelResponse, OpenOrder, OrderResponse},
 };
-pub use client::{Clob, ClobBuilder, CreateOrderParams};
+pub use client::{
+    Clob, ClobBuilder, CreateMarketOrderParams, CreateOrderParams, SETTLEMENT_POLL_INTERVAL,
+};
 pub use error::ClobError;
+pub use polyte_core::RetryPolicy;
+pub use risk::RiskConfig;
+#[allow(deprecated)]
+pub use types::OrderKind;
 pub use types::{
-    Order, OrderKind, OrderSide, ParseTickSizeError, SignatureType, SignedOrder, TickSize,
+    FeeRate, Order, OrderSide, OrderType, ParseOrderSideError, ParseTickSizeError, PostOrderBody,
+    SignatureType, SignedOrder, TickSize,
 };
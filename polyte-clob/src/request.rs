@@ -1,15 +1,16 @@
-use std::marker::PhantomData;
+use std::{marker::PhantomData, sync::Arc};
 
 use alloy::primitives::Address;
-use polyte_core::request::QueryBuilder;
-use reqwest::{Client, Method, Response};
+use polyte_core::{
+    request::QueryBuilder, HttpClient, HttpTransport, RateLimitTracker, RetryPolicy,
+};
+use reqwest::{Method, Response};
 use serde::de::DeserializeOwned;
-use url::Url;
 
 use crate::{
-    account::{Credentials, Signer, Wallet},
+    account::{Account, Credentials, Signer, Wallet},
     error::ClobError,
-    utils::current_timestamp,
+    utils::Clock,
 };
 
 /// Authentication mode for requests
@@ -19,19 +20,35 @@ pub enum AuthMode {
     L1 {
         wallet: Wallet,
         nonce: u32,
-        timestamp: u64,
+        clock: Clock,
     },
+    /// API key authentication.
+    ///
+    /// Sends `POLY_ADDRESS`, `POLY_SIGNATURE`, `POLY_TIMESTAMP`,
+    /// `POLY_API_KEY` and `POLY_PASSPHRASE`. Unlike L1, no endpoint expects
+    /// a `POLY_NONCE`: the HMAC over timestamp, method, path and body
+    /// already binds the request.
     L2 {
         address: Address,
         credentials: Credentials,
         signer: Signer,
+        clock: Clock,
     },
 }
 
+impl AuthMode {
+    /// Clock used for the signed timestamp, if the request is signed
+    fn clock(&self) -> Option<&Clock> {
+        match self {
+            Self::None => None,
+            Self::L1 { clock, .. } | Self::L2 { clock, .. } => Some(clock),
+        }
+    }
+}
+
 /// Generic request builder for CLOB API
 pub struct Request<T> {
-    pub(crate) client: Client,
-    pub(crate) base_url: Url,
+    pub(crate) http: Arc<HttpClient>,
     pub(crate) path: String,
     pub(crate) method: Method,
     pub(crate) query: Vec<(String, String)>,
@@ -41,70 +58,89 @@ pub struct Request<T> {
     pub(crate) _marker: PhantomData<T>,
 }
 
-impl<T> Request<T> {
-    /// Create a new GET request
-    pub(crate) fn get(
-        client: Client,
-        base_url: Url,
-        path: impl Into<String>,
-        auth: AuthMode,
-        chain_id: u64,
-    ) -> Self {
-        Self {
-            client,
-            base_url,
-            path: path.into(),
-            method: Method::GET,
-            query: Vec::new(),
-            body: None,
-            auth,
-            chain_id,
-            _marker: PhantomData,
-        }
-    }
+/// Connection settings shared by a [`crate::Clob`] and its namespaces
+#[derive(Clone)]
+pub(crate) struct ClobContext {
+    pub(crate) http: Arc<HttpClient>,
+    pub(crate) chain_id: u64,
+    pub(crate) clock: Clock,
+}
 
-    /// Create a new POST request
-    pub(crate) fn post(
-        client: Client,
-        base_url: Url,
-        path: String,
-        auth: AuthMode,
-        chain_id: u64,
-    ) -> Self {
+impl ClobContext {
+    /// Context sending requests through `http` for chain `chain_id`
+    pub(crate) fn new(http: HttpClient, chain_id: u64, clock: Clock) -> Self {
         Self {
-            client,
-            base_url,
-            path,
-            method: Method::POST,
-            query: Vec::new(),
-            body: None,
-            auth,
+            http: Arc::new(http),
             chain_id,
-            _marker: PhantomData,
+            clock,
         }
     }
 
-    /// Create a new DELETE request
-    pub(crate) fn delete(
-        client: Client,
-        base_url: Url,
+    /// Request to `path` with the client's response size limit, rate limit
+    /// tracking, retry policy and transport
+    pub(crate) fn request<T>(
+        &self,
+        method: Method,
         path: impl Into<String>,
         auth: AuthMode,
-        chain_id: u64,
-    ) -> Self {
-        Self {
-            client,
-            base_url,
+    ) -> Request<T> {
+        Request {
+            http: self.http.clone(),
             path: path.into(),
-            method: Method::DELETE,
+            method,
             query: Vec::new(),
             body: None,
             auth,
-            chain_id,
+            chain_id: self.chain_id,
             _marker: PhantomData,
         }
     }
 
+    /// Wallet (L1) authentication with `nonce`
+    pub(crate) fn l1(&self, wallet: &Wallet, nonce: u32) -> AuthMode {
+        AuthMode::L1 {
+            wallet: wallet.clone(),
+            nonce,
+            clock: self.clock.clone(),
+        }
+    }
+
+    /// API key (L2) authentication of `account`
+    pub(crate) fn l2(&self, account: &Account) -> AuthMode {
+        AuthMode::L2 {
+            address: account.address(),
+            credentials: account.credentials().clone(),
+            signer: account.signer().clone(),
+            clock: self.clock.clone(),
+        }
+    }
+}
+
+impl<T> Request<T> {
+    /// Set the maximum response body size in bytes (unlimited if `None`)
+    pub fn max_response_bytes(mut self, max: Option<usize>) -> Self {
+        Arc::make_mut(&mut self.http).max_response_bytes = max;
+        self
+    }
+
+    /// Record rate limit headers of the response into `tracker`
+    pub fn rate_limit(mut self, tracker: RateLimitTracker) -> Self {
+        Arc::make_mut(&mut self.http).rate_limit = tracker;
+        self
+    }
+
+    /// Set the retry policy for transient failures
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        Arc::make_mut(&mut self.http).retry = policy;
+        self
+    }
+
+    /// Send the request through `transport`
+    pub fn transport(mut self, transport: Arc<dyn HttpTransport>) -> Self {
+        Arc::make_mut(&mut self.http).transport = transport;
+        self
+    }
+
     /// Set request body
     pub fn body<B: serde::Serialize>(mut self, body: &B) -> Result<Self, ClobError> {
         self.body = Some(serde_json::to_value(body)?);
@@ -121,86 +157,60 @@ impl<T> QueryBuilder for Request<T> {
 impl<T: DeserializeOwned> Request<T> {
     /// Execute the request and deserialize response
     pub async fn send(self) -> Result<T, ClobError> {
+        let http = self.http.clone();
         let response = self.send_raw().await?;
-
-        // Get text for debugging
-        let text = response.text().await?;
-
-        tracing::debug!("Response body: {}", text);
-
-        // Deserialize and provide better error context
-        serde_json::from_str(&text).map_err(|e| {
-            tracing::error!("Deserialization failed: {}", e);
-            tracing::error!("Failed to deserialize: {}", text);
-            e.into()
-        })
+        http.read_json(response).await
     }
 
     /// Execute the request and return raw response
     pub async fn send_raw(self) -> Result<Response, ClobError> {
-        let url = self.base_url.join(&self.path)?;
-
-        // Build the base request
-        let mut request = match self.method {
-            Method::GET => self.client.get(url),
-            Method::POST => {
-                let mut req = self.client.post(url);
-                if let Some(body) = &self.body {
-                    req = req.header("Content-Type", "application/json").json(body);
-                }
-                req
-            }
-            Method::DELETE => {
-                let mut req = self.client.delete(url);
-                if let Some(body) = &self.body {
-                    req = req.header("Content-Type", "application/json").json(body);
-                }
-                req
-            }
-            _ => return Err(ClobError::validation("Unsupported HTTP method")),
-        };
-
-        // Add query parameters
-        if !self.query.is_empty() {
-            request = request.query(&self.query);
+        if let Some(clock) = self.auth.clock() {
+            let http = self.http.clone();
+            clock
+                .sync(|| async move { http.send_json(Method::GET, "/time", &[], None).await })
+                .await?;
         }
 
-        // Add authentication headers
-        request = self.add_auth_headers(request).await?;
-
-        tracing::debug!("Sending {} request to: {:?}", self.method, request);
+        // Serialize the body once so the L2 signature covers the exact bytes sent
+        let body = self.body.as_ref().map(|b| b.to_string());
 
-        // Execute request
-        let response = request.send().await?;
-        let status = response.status();
-
-        tracing::debug!("Response status: {}", status);
+        self.http
+            .send_with(self.method.clone(), &self.path, |mut request| {
+                if let Some(body) = &body {
+                    request = request
+                        .header("Content-Type", "application/json")
+                        .body(body.clone());
+                }
 
-        if !status.is_success() {
-            let error = ClobError::from_response(response).await;
-            tracing::error!("Request failed: {:?}", error);
-            return Err(error);
-        }
+                // Add query parameters
+                if !self.query.is_empty() {
+                    request = request.query(&self.query);
+                }
 
-        Ok(response)
+                // Sign every attempt so retries carry a fresh timestamp
+                self.add_auth_headers(request, body.as_deref())
+            })
+            .await
     }
 
-    /// Add authentication headers based on auth mode
+    /// Add authentication headers based on auth mode, signing `body` for L2
     async fn add_auth_headers(
         &self,
         mut request: reqwest::RequestBuilder,
+        body: Option<&str>,
     ) -> Result<reqwest::RequestBuilder, ClobError> {
         match &self.auth {
             AuthMode::None => Ok(request),
             AuthMode::L1 {
                 wallet,
                 nonce,
-                timestamp,
+                clock,
             } => {
                 use crate::core::eip712::sign_clob_auth;
 
+                let timestamp = clock.now();
                 let signature =
-                    sign_clob_auth(wallet.signer(), self.chain_id, *timestamp, *nonce).await?;
+                    sign_clob_auth(wallet.signer(), self.chain_id, timestamp, *nonce).await?;
 
                 request = request
                     .header("POLY_ADDRESS", format!("{:?}", wallet.address()))
@@ -214,15 +224,11 @@ impl<T: DeserializeOwned> Request<T> {
                 address,
                 credentials,
                 signer,
+                clock,
             } => {
-                let timestamp = current_timestamp();
-                let body_str = self.body.as_ref().map(|b| b.to_string());
-                let message = Signer::create_message(
-                    timestamp,
-                    self.method.as_str(),
-                    &self.path,
-                    body_str.as_deref(),
-                );
+                let timestamp = clock.now();
+                let message =
+                    Signer::create_message(timestamp, self.method.as_str(), &self.path, body);
                 let signature = signer.sign(&message)?;
 
                 request = request
@@ -237,3 +243,129 @@ impl<T: DeserializeOwned> Request<T> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use polyte_core::testing::{MockResponse, MockTransport};
+    use reqwest::Client;
+
+    use super::*;
+
+    fn context() -> ClobContext {
+        let http = polyte_core::HttpClientBuilder::new("https://clob.polymarket.com")
+            .build()
+            .unwrap();
+        ClobContext::new(http, 137, Clock::default())
+    }
+
+    #[test]
+    fn test_put_with_body() {
+        let request = context()
+            .request::<serde_json::Value>(Method::PUT, "/order", AuthMode::None)
+            .body(&serde_json::json!({ "orderID": "0xabc", "size": "10" }))
+            .unwrap();
+
+        assert_eq!(request.method, Method::PUT);
+        assert_eq!(request.path, "/order");
+        assert_eq!(
+            request.body,
+            Some(serde_json::json!({ "orderID": "0xabc", "size": "10" }))
+        );
+    }
+
+    #[test]
+    fn test_patch_with_body() {
+        let request = context()
+            .request::<serde_json::Value>(Method::PATCH, "/order", AuthMode::None)
+            .body(&serde_json::json!({ "orderID": "0xabc" }))
+            .unwrap();
+
+        assert_eq!(request.method, Method::PATCH);
+        assert!(request.body.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_unauthenticated_post_not_retried() {
+        // Every request goes through the shared retry loop, which only
+        // repeats requests that are safe to send twice
+        let transport = MockTransport::always(MockResponse::status(503, ""));
+        let http = polyte_core::HttpClientBuilder::new("https://clob.polymarket.com")
+            .transport(transport.clone())
+            .build()
+            .unwrap();
+        let result = ClobContext::new(http, 137, Clock::default())
+            .request::<serde_json::Value>(Method::POST, "/order", AuthMode::None)
+            .send()
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(ClobError::Api(polyte_core::ApiError::Api {
+                status: 503,
+                ..
+            }))
+        ));
+        assert_eq!(transport.request_count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_l2_header_set() {
+        let signer = Signer::new("c2VjcmV0").unwrap();
+        let request = context().request::<serde_json::Value>(
+            Method::GET,
+            "/data/orders",
+            AuthMode::L2 {
+                address: Address::ZERO,
+                credentials: Credentials {
+                    key: "key".to_string(),
+                    secret: "c2VjcmV0".to_string(),
+                    passphrase: "passphrase".to_string(),
+                },
+                signer: signer.clone(),
+                clock: Clock::default(),
+            },
+        );
+
+        let built = request
+            .add_auth_headers(
+                Client::new().get("https://clob.polymarket.com/data/orders"),
+                None,
+            )
+            .await
+            .unwrap()
+            .build()
+            .unwrap();
+        let headers = built.headers();
+
+        let mut names: Vec<&str> = headers
+            .keys()
+            .map(|name| name.as_str())
+            .filter(|name| name.starts_with("poly_"))
+            .collect();
+        names.sort_unstable();
+        assert_eq!(
+            names,
+            vec![
+                "poly_address",
+                "poly_api_key",
+                "poly_passphrase",
+                "poly_signature",
+                "poly_timestamp",
+            ]
+        );
+
+        assert_eq!(headers["poly_api_key"], "key");
+        assert_eq!(headers["poly_passphrase"], "passphrase");
+        assert_eq!(
+            headers["poly_address"],
+            format!("{:?}", Address::ZERO).as_str()
+        );
+
+        let timestamp: u64 = headers["poly_timestamp"].to_str().unwrap().parse().unwrap();
+        let message = Signer::create_message(timestamp, "GET", "/data/orders", None);
+        assert_eq!(
+            headers["poly_signature"],
+            signer.sign(&message).unwrap().as_str()
+        );
+    }
+}
@@ -0,0 +1,156 @@
+//! Pre-trade risk checks.
+//!
+//! [`RiskConfig`] holds optional guardrails that are evaluated before an order
+//! is built. Each guardrail is disabled unless explicitly configured.
+
+use crate::{client::CreateOrderParams, error::ClobError};
+
+/// Pre-trade guardrails for order creation
+///
+/// # Example
+///
+/// ```
+/// use polyte_clob::RiskConfig;
+///
+/// let risk = RiskConfig::new()
+///     .max_order_notional(1_000.0)
+///     .max_open_orders_per_market(5)
+///     .max_price_deviation(0.10);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct RiskConfig {
+    /// Maximum order notional (price * size) in USDC
+    pub max_order_notional: Option<f64>,
+    /// Maximum number of open orders per market
+    pub max_open_orders_per_market: Option<usize>,
+    /// Maximum relative distance from the midpoint (e.g. 0.10 for 10%)
+    pub max_price_deviation: Option<f64>,
+}
+
+impl RiskConfig {
+    /// Create a risk configuration with all guardrails disabled
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reject orders whose notional (price * size) exceeds `max`
+    pub fn max_order_notional(mut self, max: f64) -> Self {
+        self.max_order_notional = Some(max);
+        self
+    }
+
+    /// Reject orders when the market already has `max` open orders
+    pub fn max_open_orders_per_market(mut self, max: usize) -> Self {
+        self.max_open_orders_per_market = Some(max);
+        self
+    }
+
+    /// Reject orders priced more than `ratio` away from the current midpoint
+    pub fn max_price_deviation(mut self, ratio: f64) -> Self {
+        self.max_price_deviation = Some(ratio);
+        self
+    }
+
+    /// Check the order notional against the configured limit
+    pub fn check_notional(&self, params: &CreateOrderParams) -> Result<(), ClobError> {
+        let Some(max) = self.max_order_notional else {
+            return Ok(());
+        };
+
+        let notional = params.price * params.size;
+        if notional > max {
+            return Err(ClobError::RiskRejected(format!(
+                "order notional {} exceeds maximum {}",
+                notional, max
+            )));
+        }
+        Ok(())
+    }
+
+    /// Check the number of open orders in the market against the configured limit
+    pub fn check_open_orders(&self, open_orders: usize) -> Result<(), ClobError> {
+        let Some(max) = self.max_open_orders_per_market else {
+            return Ok(());
+        };
+
+        if open_orders >= max {
+            return Err(ClobError::RiskRejected(format!(
+                "market already has {} open orders (maximum {})",
+                open_orders, max
+            )));
+        }
+        Ok(())
+    }
+
+    /// Check the order price against the current midpoint
+    pub fn check_price_deviation(&self, price: f64, midpoint: f64) -> Result<(), ClobError> {
+        let Some(max) = self.max_price_deviation else {
+            return Ok(());
+        };
+
+        if midpoint <= 0.0 {
+            return Ok(());
+        }
+
+        let deviation = (price - midpoint).abs() / midpoint;
+        if deviation > max {
+            return Err(ClobError::RiskRejected(format!(
+                "price {} is {:.2}% away from midpoint {} (maximum {:.2}%)",
+                price,
+                deviation * 100.0,
+                midpoint,
+                max * 100.0
+            )));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::OrderSide;
+
+    fn params(price: f64, size: f64) -> CreateOrderParams {
+        CreateOrderParams::new("123", price, size, OrderSide::Buy)
+    }
+
+    #[test]
+    fn test_disabled_by_default() {
+        let risk = RiskConfig::new();
+        assert!(risk.check_notional(&params(0.99, 1_000_000.0)).is_ok());
+        assert!(risk.check_open_orders(1_000).is_ok());
+        assert!(risk.check_price_deviation(0.99, 0.01).is_ok());
+    }
+
+    #[test]
+    fn test_max_order_notional() {
+        let risk = RiskConfig::new().max_order_notional(100.0);
+        assert!(risk.check_notional(&params(0.5, 200.0)).is_ok());
+
+        let err = risk.check_notional(&params(0.5, 201.0)).unwrap_err();
+        assert!(matches!(err, ClobError::RiskRejected(_)));
+    }
+
+    #[test]
+    fn test_max_open_orders_per_market() {
+        let risk = RiskConfig::new().max_open_orders_per_market(3);
+        assert!(risk.check_open_orders(2).is_ok());
+
+        let err = risk.check_open_orders(3).unwrap_err();
+        assert!(matches!(err, ClobError::RiskRejected(_)));
+    }
+
+    #[test]
+    fn test_max_price_deviation() {
+        let risk = RiskConfig::new().max_price_deviation(0.10);
+        assert!(risk.check_price_deviation(0.54, 0.50).is_ok());
+        assert!(risk.check_price_deviation(0.46, 0.50).is_ok());
+
+        let err = risk.check_price_deviation(0.60, 0.50).unwrap_err();
+        assert!(matches!(err, ClobError::RiskRejected(_)));
+
+        let err = risk.check_price_deviation(0.40, 0.50).unwrap_err();
+        assert!(matches!(err, ClobError::RiskRejected(_)));
+    }
+}
@@ -0,0 +1,17 @@
+//! Fixtures shared by the crate's unit tests
+
+use crate::{Account, Credentials};
+
+/// Account for the first Hardhat test key with placeholder API credentials
+pub(crate) fn account() -> Account {
+    let credentials = Credentials {
+        key: "test_key".to_string(),
+        secret: "c2VjcmV0".to_string(),
+        passphrase: "test_pass".to_string(),
+    };
+    Account::new(
+        "0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80",
+        credentials,
+    )
+    .unwrap()
+}
@@ -1,7 +1,8 @@
 use std::fmt;
 
 use alloy::primitives::Address;
-use serde::{Deserialize, Serialize};
+use rust_decimal::Decimal;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 use thiserror::Error;
 
 /// Error when parsing a tick size from an invalid value
@@ -9,8 +10,13 @@ use thiserror::Error;
 #[error("invalid tick size: {0}. Valid values are 0.1, 0.01, 0.001, or 0.0001")]
 pub struct ParseTickSizeError(String);
 
+/// Error when parsing an order side from an invalid value
+#[derive(Error, Debug, Clone, PartialEq)]
+#[error("invalid order side: {0}. Valid values are BUY or SELL")]
+pub struct ParseOrderSideError(String);
+
 /// Order side (buy or sell)
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "UPPERCASE")]
 pub enum OrderSide {
     Buy,
@@ -26,11 +32,28 @@ impl fmt::Display for OrderSide {
     }
 }
 
-/// Order type/kind
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+impl std::str::FromStr for OrderSide {
+    type Err = ParseOrderSideError;
+
+    /// Parse an order side, ignoring case and surrounding whitespace
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let side = s.trim();
+        if side.eq_ignore_ascii_case("buy") {
+            Ok(Self::Buy)
+        } else if side.eq_ignore_ascii_case("sell") {
+            Ok(Self::Sell)
+        } else {
+            Err(ParseOrderSideError(s.to_string()))
+        }
+    }
+}
+
+/// Order type (time-in-force)
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "UPPERCASE")]
-pub enum OrderKind {
+pub enum OrderType {
     /// Good-till-Cancelled
+    #[default]
     Gtc,
     /// Fill-or-Kill
     Fok,
@@ -40,7 +63,7 @@ pub enum OrderKind {
     Fak,
 }
 
-impl fmt::Display for OrderKind {
+impl fmt::Display for OrderType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::Gtc => write!(f, "GTC"),
@@ -51,6 +74,10 @@ impl fmt::Display for OrderKind {
     }
 }
 
+/// Former name of [`OrderType`]
+#[deprecated(note = "renamed to `OrderType`")]
+pub type OrderKind = OrderType;
+
 /// Signature type
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
@@ -61,6 +88,17 @@ pub enum SignatureType {
     PolyGnosisSafe,
 }
 
+impl SignatureType {
+    /// Numeric code of the signature type, as signed and sent to the API
+    pub fn as_u8(&self) -> u8 {
+        match self {
+            Self::Eoa => 0,
+            Self::PolyProxy => 1,
+            Self::PolyGnosisSafe => 2,
+        }
+    }
+}
+
 impl fmt::Display for SignatureType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -85,6 +123,15 @@ pub enum TickSize {
 }
 
 impl TickSize {
+    /// All supported tick sizes, from coarsest to finest
+    pub const ALL: [TickSize; 4] = [
+        Self::Tenth,
+        Self::Hundredth,
+        Self::Thousandth,
+        Self::TenThousandth,
+    ];
+
+    /// Tick size as a float, e.g. `0.01`
     pub fn as_f64(&self) -> f64 {
         match self {
             Self::Tenth => 0.1,
@@ -94,6 +141,12 @@ impl TickSize {
         }
     }
 
+    /// Exact tick size, e.g. `0.01`
+    pub fn as_decimal(&self) -> Decimal {
+        Decimal::new(1, self.decimals())
+    }
+
+    /// Number of decimals of prices on this tick
     pub fn decimals(&self) -> u32 {
         match self {
             Self::Tenth => 1,
@@ -102,19 +155,27 @@ impl TickSize {
             Self::TenThousandth => 4,
         }
     }
+
+    /// Snap `price` to the nearest multiple of this tick size
+    pub fn round_to_tick(&self, price: f64) -> f64 {
+        crate::utils::round_price(price, *self)
+    }
 }
 
 impl TryFrom<&str> for TickSize {
     type Error = ParseTickSizeError;
 
+    /// Parse a decimal tick such as `"0.01"`; trailing zeros (`"0.010"`) are
+    /// accepted, any other value is an error
     fn try_from(s: &str) -> Result<Self, Self::Error> {
-        match s {
-            "0.1" => Ok(Self::Tenth),
-            "0.01" => Ok(Self::Hundredth),
-            "0.001" => Ok(Self::Thousandth),
-            "0.0001" => Ok(Self::TenThousandth),
-            _ => Err(ParseTickSizeError(s.to_string())),
-        }
+        let value = s
+            .trim()
+            .parse::<Decimal>()
+            .map_err(|_| ParseTickSizeError(s.to_string()))?;
+        Self::ALL
+            .into_iter()
+            .find(|tick| tick.as_decimal() == value)
+            .ok_or_else(|| ParseTickSizeError(s.to_string()))
     }
 }
 
@@ -145,29 +206,282 @@ impl std::str::FromStr for TickSize {
     }
 }
 
-/// Unsigned order
+/// Unsigned order.
+///
+/// Serializes to the JSON the CLOB expects: camelCase keys, checksummed
+/// addresses, `salt` as a number and `signatureType` as its numeric code.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all(deserialize = "camelCase"))]
 pub struct Order {
+    #[serde(
+        serialize_with = "serialize_salt",
+        deserialize_with = "deserialize_salt"
+    )]
     pub salt: String,
+    #[serde(serialize_with = "serialize_checksummed")]
     pub maker: Address,
+    #[serde(serialize_with = "serialize_checksummed")]
     pub signer: Address,
+    #[serde(serialize_with = "serialize_checksummed")]
     pub taker: Address,
+    #[serde(rename = "tokenId")]
     pub token_id: String,
+    #[serde(rename = "makerAmount")]
     pub maker_amount: String,
+    #[serde(rename = "takerAmount")]
     pub taker_amount: String,
     pub expiration: String,
     pub nonce: String,
+    #[serde(rename = "feeRateBps")]
     pub fee_rate_bps: String,
     pub side: OrderSide,
+    #[serde(
+        rename = "signatureType",
+        serialize_with = "serialize_signature_type",
+        deserialize_with = "deserialize_signature_type"
+    )]
     pub signature_type: SignatureType,
+    /// Whether the market is negative-risk, which selects the exchange
+    /// contract the order is signed against. Not sent to the API.
+    #[serde(skip)]
+    pub neg_risk: bool,
 }
 
 /// Signed order
 #[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all(deserialize = "camelCase"))]
 pub struct SignedOrder {
     #[serde(flatten)]
     pub order: Order,
     pub signature: String,
 }
+
+impl SignedOrder {
+    /// Wrap the order into the body of a `POST /order` request.
+    ///
+    /// `owner` is the API key of the account posting the order.
+    pub fn to_request_body<'a>(
+        &'a self,
+        owner: &'a str,
+        order_type: OrderType,
+    ) -> PostOrderBody<'a> {
+        PostOrderBody {
+            order: self,
+            owner,
+            order_type,
+            post_only: false,
+            defer_exec: false,
+        }
+    }
+}
+
+/// Body of a `POST /order` request, see [`SignedOrder::to_request_body`]
+#[derive(Debug, Clone, Serialize)]
+pub struct PostOrderBody<'a> {
+    pub order: &'a SignedOrder,
+    pub owner: &'a str,
+    #[serde(rename = "orderType")]
+    pub order_type: OrderType,
+    #[serde(rename = "postOnly")]
+    pub post_only: bool,
+    #[serde(rename = "deferExec", skip_serializing_if = "std::ops::Not::not")]
+    pub defer_exec: bool,
+}
+
+/// Send addresses in their EIP-55 checksummed form
+fn serialize_checksummed<S: Serializer>(
+    address: &Address,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&address.to_checksum(None))
+}
+
+/// Send salts that fit in 64 bits as JSON numbers, larger ones as strings
+fn serialize_salt<S: Serializer>(salt: &str, serializer: S) -> Result<S::Ok, S::Error> {
+    match salt.parse::<u64>() {
+        Ok(salt) => serializer.serialize_u64(salt),
+        Err(_) => serializer.serialize_str(salt),
+    }
+}
+
+/// Read a salt given as a number or a string
+fn deserialize_salt<'de, D: Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+    struct SaltVisitor;
+
+    impl de::Visitor<'_> for SaltVisitor {
+        type Value = String;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("a salt as an integer or a string")
+        }
+
+        fn visit_u64<E: de::Error>(self, salt: u64) -> Result<String, E> {
+            Ok(salt.to_string())
+        }
+
+        fn visit_str<E: de::Error>(self, salt: &str) -> Result<String, E> {
+            Ok(salt.to_string())
+        }
+    }
+
+    deserializer.deserialize_any(SaltVisitor)
+}
+
+fn serialize_signature_type<S: Serializer>(
+    signature_type: &SignatureType,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u8(signature_type.as_u8())
+}
+
+/// Read a signature type given as its numeric code or its name
+fn deserialize_signature_type<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<SignatureType, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Raw {
+        Code(u8),
+        Name(SignatureType),
+    }
+
+    match Raw::deserialize(deserializer)? {
+        Raw::Code(0) => Ok(SignatureType::Eoa),
+        Raw::Code(1) => Ok(SignatureType::PolyProxy),
+        Raw::Code(2) => Ok(SignatureType::PolyGnosisSafe),
+        Raw::Code(code) => Err(de::Error::custom(format!(
+            "unknown signature type {}",
+            code
+        ))),
+        Raw::Name(signature_type) => Ok(signature_type),
+    }
+}
+
+/// Fee rates charged by the exchange, in basis points
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct FeeRate {
+    pub maker_bps: u32,
+    pub taker_bps: u32,
+}
+
+impl FeeRate {
+    /// Parse a `/fee-rate` response.
+    ///
+    /// Reads `makerFeeRateBps`/`takerFeeRateBps`, falling back to the single
+    /// `feeRateBps` for both; values may be numbers or strings, and missing
+    /// ones count as 0.
+    pub fn from_response(response: &serde_json::Value) -> Self {
+        let bps = |key: &str| {
+            let value = response.get(key)?;
+            value
+                .as_u64()
+                .or_else(|| value.as_str()?.parse().ok())
+                .and_then(|bps| u32::try_from(bps).ok())
+        };
+        let base = bps("feeRateBps").unwrap_or(0);
+
+        Self {
+            maker_bps: bps("makerFeeRateBps").unwrap_or(base),
+            taker_bps: bps("takerFeeRateBps").unwrap_or(base),
+        }
+    }
+
+    /// Rate an order pays: post-only orders always rest and pay the maker
+    /// rate, anything else may cross the book and pays the taker rate
+    pub fn for_order(&self, post_only: bool) -> u32 {
+        if post_only {
+            self.maker_bps
+        } else {
+            self.taker_bps
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tick_size_supported_values() {
+        let cases = [
+            ("0.1", 0.1, TickSize::Tenth),
+            ("0.01", 0.01, TickSize::Hundredth),
+            ("0.001", 0.001, TickSize::Thousandth),
+            ("0.0001", 0.0001, TickSize::TenThousandth),
+        ];
+
+        for (text, value, tick) in cases {
+            assert_eq!(text.parse::<TickSize>(), Ok(tick));
+            assert_eq!(TickSize::try_from(value), Ok(tick));
+            assert_eq!(tick.as_f64(), value);
+            assert_eq!(tick.as_decimal().to_string(), text);
+        }
+        assert_eq!(" 0.010 ".parse::<TickSize>(), Ok(TickSize::Hundredth));
+    }
+
+    #[test]
+    fn test_tick_size_rejects_unsupported_values() {
+        for text in ["0.05", "1", "0", "tick", ""] {
+            assert_eq!(
+                text.parse::<TickSize>(),
+                Err(ParseTickSizeError(text.to_string()))
+            );
+        }
+
+        let err = TickSize::try_from(0.005).unwrap_err();
+        assert!(matches!(
+            crate::ClobError::from(err),
+            crate::ClobError::InvalidTickSize(_)
+        ));
+    }
+
+    fn signed_order() -> SignedOrder {
+        SignedOrder {
+            order: Order {
+                salt: "479249096354".to_string(),
+                maker: "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266"
+                    .parse()
+                    .unwrap(),
+                signer: "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266"
+                    .parse()
+                    .unwrap(),
+                taker: Address::ZERO,
+                token_id:
+                    "71321045679252212594626385532706912750332728571942532289631379312455583992563"
+                        .to_string(),
+                maker_amount: "52000000".to_string(),
+                taker_amount: "100000000".to_string(),
+                expiration: "0".to_string(),
+                nonce: "0".to_string(),
+                fee_rate_bps: "0".to_string(),
+                side: OrderSide::Buy,
+                signature_type: SignatureType::PolyProxy,
+                neg_risk: false,
+            },
+            signature: "0x5f4e1a2b".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_post_order_body_matches_golden_payload() {
+        let order = signed_order();
+        let body = order.to_request_body("f4f247b7-4ac7-ff29-a152-04fda0a8755a", OrderType::Gtc);
+
+        let expected: serde_json::Value =
+            serde_json::from_str(include_str!("../tests/fixtures/post_order_body.json")).unwrap();
+        assert_eq!(serde_json::to_value(&body).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_signed_order_round_trips() {
+        let json = serde_json::to_value(signed_order()).unwrap();
+        let order: SignedOrder = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(serde_json::to_value(&order).unwrap(), json);
+
+        // Orders read back from the API may carry string salts and names
+        let mut legacy = json;
+        legacy["salt"] = "479249096354".into();
+        legacy["signatureType"] = "poly-proxy".into();
+        let order: SignedOrder = serde_json::from_value(legacy).unwrap();
+        assert_eq!(order.order.salt, "479249096354");
+        assert_eq!(order.order.signature_type, SignatureType::PolyProxy);
+    }
+}
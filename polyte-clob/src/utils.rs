@@ -1,9 +1,21 @@
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::{
+    future::Future,
+    sync::{
+        atomic::{AtomicI64, Ordering},
+        Arc,
+    },
+    time::{SystemTime, UNIX_EPOCH},
+};
 
 use rand::Rng;
 use rust_decimal::{prelude::ToPrimitive, Decimal, RoundingStrategy};
+use tokio::sync::OnceCell;
 
-use crate::types::{OrderSide, TickSize};
+use crate::{
+    api::account::USDC_DECIMALS,
+    error::ClobError,
+    types::{OrderSide, TickSize},
+};
 
 /// Get current Unix timestamp in seconds
 pub fn current_timestamp() -> u64 {
@@ -13,10 +25,95 @@ pub fn current_timestamp() -> u64 {
         .as_secs()
 }
 
+/// Clock used for signed timestamps, corrected by the server's clock offset.
+///
+/// Clones share the same offset, so one measured by a [`crate::Clob`] applies
+/// to every request it builds.
+#[derive(Debug, Clone, Default)]
+pub struct Clock {
+    offset: Arc<AtomicI64>,
+    sync: Option<Arc<OnceCell<()>>>,
+}
+
+impl Clock {
+    /// Clock that measures the offset before its first signature
+    pub(crate) fn synced() -> Self {
+        Self {
+            offset: Arc::default(),
+            sync: Some(Arc::default()),
+        }
+    }
+
+    /// Seconds added to the local clock
+    pub fn offset(&self) -> i64 {
+        self.offset.load(Ordering::Relaxed)
+    }
+
+    /// Set the offset from a server timestamp taken now
+    pub(crate) fn set_server_time(&self, server_time: u64) {
+        let offset = server_time as i64 - current_timestamp() as i64;
+        self.offset.store(offset, Ordering::Relaxed);
+    }
+
+    /// Current Unix timestamp in seconds with the offset applied
+    pub fn now(&self) -> u64 {
+        current_timestamp().saturating_add_signed(self.offset())
+    }
+
+    /// Measure the offset with `server_time` unless it was measured already.
+    ///
+    /// Does nothing for clocks that were not created with [`Clock::synced`].
+    pub(crate) async fn sync<F, Fut>(&self, server_time: F) -> Result<(), ClobError>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<u64, ClobError>>,
+    {
+        if let Some(once) = &self.sync {
+            once.get_or_try_init(|| async {
+                self.set_server_time(server_time().await?);
+                Ok::<_, ClobError>(())
+            })
+            .await?;
+        }
+        Ok(())
+    }
+}
+
+/// Number of decimals share sizes are quoted in
+const SIZE_DECIMALS: u32 = 2;
+
+/// Round `price` to the nearest multiple of `tick_size`.
+///
+/// Uses the same banker's rounding as [`calculate_order_amounts`], so the
+/// result is the price the order amounts are computed from.
+pub fn round_price(price: f64, tick_size: TickSize) -> f64 {
+    to_decimal(price)
+        .round_dp_with_strategy(tick_size.decimals(), RoundingStrategy::MidpointNearestEven)
+        .to_f64()
+        .unwrap_or_default()
+}
+
+/// Round `size` down to a multiple of `min_size`.
+///
+/// Sizes are truncated rather than rounded so an order never grows past
+/// what the caller asked for. A non-positive `min_size` only truncates to
+/// the 2 decimals shares are quoted in.
+pub fn round_size(size: f64, min_size: f64) -> f64 {
+    let size = to_decimal(size).round_dp_with_strategy(SIZE_DECIMALS, RoundingStrategy::ToZero);
+    let step = to_decimal(min_size);
+    let rounded = if step > Decimal::ZERO {
+        (size / step).floor() * step
+    } else {
+        size
+    };
+    rounded.to_f64().unwrap_or_default()
+}
+
 /// Calculate maker and taker amounts for an order using precise decimal arithmetic.
 ///
 /// This function uses `rust_decimal` to avoid floating-point precision issues
-/// that can occur with f64 arithmetic in financial calculations.
+/// that can occur with f64 arithmetic in financial calculations. See
+/// [`calculate_order_amounts_decimal`] to skip the f64 conversion entirely.
 ///
 /// # Arguments
 ///
@@ -27,7 +124,9 @@ pub fn current_timestamp() -> u64 {
 ///
 /// # Returns
 ///
-/// A tuple of (maker_amount, taker_amount) as strings suitable for the CLOB API.
+/// A tuple of (maker_amount, taker_amount) as strings suitable for the CLOB API,
+/// in base units of [`USDC_DECIMALS`] decimals (USDC and conditional tokens
+/// share them).
 /// - For BUY orders: maker = cost (USDC), taker = shares
 /// - For SELL orders: maker = shares, taker = cost (USDC)
 pub fn calculate_order_amounts(
@@ -36,35 +135,36 @@ pub fn calculate_order_amounts(
     side: OrderSide,
     tick_size: TickSize,
 ) -> (String, String) {
-    const SIZE_DECIMALS: u32 = 2; // shares are in 2 decimals
+    calculate_order_amounts_decimal(to_decimal(price), to_decimal(size), side, tick_size)
+}
 
+/// Calculate maker and taker amounts from decimal price and size.
+///
+/// Same as [`calculate_order_amounts`], for callers that already hold exact
+/// decimal values.
+pub fn calculate_order_amounts_decimal(
+    price: Decimal,
+    size: Decimal,
+    side: OrderSide,
+    tick_size: TickSize,
+) -> (String, String) {
     let tick_decimals = tick_size.decimals();
 
-    // Convert to Decimal for precise arithmetic
-    // Using from_f64_retain to preserve the exact f64 representation
-    let price_decimal = Decimal::try_from(price).unwrap_or_else(|_| {
-        // Fallback: parse from string representation for edge cases
-        Decimal::from_str_exact(&price.to_string()).unwrap_or(Decimal::ZERO)
-    });
-    let size_decimal = Decimal::try_from(size)
-        .unwrap_or_else(|_| Decimal::from_str_exact(&size.to_string()).unwrap_or(Decimal::ZERO));
-
     // Round price to tick size using banker's rounding (round half to even)
     let price_rounded =
-        price_decimal.round_dp_with_strategy(tick_decimals, RoundingStrategy::MidpointNearestEven);
+        price.round_dp_with_strategy(tick_decimals, RoundingStrategy::MidpointNearestEven);
 
-    // Round size to 2 decimals
-    let size_rounded =
-        size_decimal.round_dp_with_strategy(SIZE_DECIMALS, RoundingStrategy::MidpointNearestEven);
+    // Truncate size to 2 decimals so the order never grows
+    let size_rounded = size.round_dp_with_strategy(SIZE_DECIMALS, RoundingStrategy::ToZero);
 
-    // Calculate cost with precise decimal multiplication
-    let cost = price_rounded * size_rounded;
-    let cost_rounded =
-        cost.round_dp_with_strategy(SIZE_DECIMALS, RoundingStrategy::MidpointNearestEven);
+    // The cost is exact at tick + size decimals, which is the precision of
+    // resting orders on the book; truncating only guards the invariant
+    let cost = (price_rounded * size_rounded)
+        .round_dp_with_strategy(tick_decimals + SIZE_DECIMALS, RoundingStrategy::ToZero);
 
-    // Convert to raw amounts (multiply by 10^decimals and take integer part)
-    let share_amount = decimal_to_raw_amount(size_rounded, SIZE_DECIMALS);
-    let cost_amount = decimal_to_raw_amount(cost_rounded, SIZE_DECIMALS);
+    // Convert to base units (multiply by 10^6 and take integer part)
+    let share_amount = decimal_to_raw_amount(size_rounded, USDC_DECIMALS);
+    let cost_amount = decimal_to_raw_amount(cost, USDC_DECIMALS);
 
     match side {
         OrderSide::Buy => {
@@ -78,6 +178,16 @@ pub fn calculate_order_amounts(
     }
 }
 
+/// Convert an f64 to a Decimal.
+///
+/// Parses the shortest string representation first, so `0.1` becomes exactly
+/// `0.1` instead of the nearest binary value `0.1000000000000000055...`.
+fn to_decimal(value: f64) -> Decimal {
+    Decimal::from_str_exact(&value.to_string())
+        .or_else(|_| Decimal::try_from(value))
+        .unwrap_or(Decimal::ZERO)
+}
+
 /// Convert a Decimal to a raw integer amount string.
 ///
 /// Multiplies by 10^decimals and takes the floor to get the integer representation.
@@ -90,9 +200,12 @@ fn decimal_to_raw_amount(value: Decimal, decimals: u32) -> String {
         .unwrap_or_else(|| raw.to_string().split('.').next().unwrap_or("0").to_string())
 }
 
-/// Generate random salt for orders
+/// Generate random salt for orders.
+///
+/// Salts stay below 2^53 so the JSON number sent to the API is exact in
+/// any JSON parser.
 pub fn generate_salt() -> String {
-    rand::rng().random::<u128>().to_string()
+    rand::rng().random_range(0..1u64 << 53).to_string()
 }
 
 #[cfg(test)]
@@ -104,9 +217,9 @@ mod tests {
         let (maker, taker) =
             calculate_order_amounts(0.52, 100.0, OrderSide::Buy, TickSize::Hundredth);
 
-        // BUY: maker = cost (5200), taker = shares (10000)
-        assert_eq!(maker, "5200");
-        assert_eq!(taker, "10000");
+        // BUY: maker = cost (52 USDC), taker = shares (100)
+        assert_eq!(maker, "52000000");
+        assert_eq!(taker, "100000000");
     }
 
     #[test]
@@ -114,9 +227,9 @@ mod tests {
         let (maker, taker) =
             calculate_order_amounts(0.52, 100.0, OrderSide::Sell, TickSize::Hundredth);
 
-        // SELL: maker = shares (10000), taker = cost (5200)
-        assert_eq!(maker, "10000");
-        assert_eq!(taker, "5200");
+        // SELL: maker = shares (100), taker = cost (52 USDC)
+        assert_eq!(maker, "100000000");
+        assert_eq!(taker, "52000000");
     }
 
     #[test]
@@ -124,9 +237,9 @@ mod tests {
         let (maker, taker) = calculate_order_amounts(0.5, 50.0, OrderSide::Buy, TickSize::Tenth);
 
         // price=0.5, size=50 => cost=25.0
-        // BUY: maker = cost (2500), taker = shares (5000)
-        assert_eq!(maker, "2500");
-        assert_eq!(taker, "5000");
+        // BUY: maker = cost (25 USDC), taker = shares (50)
+        assert_eq!(maker, "25000000");
+        assert_eq!(taker, "50000000");
     }
 
     #[test]
@@ -135,9 +248,9 @@ mod tests {
             calculate_order_amounts(0.523, 100.0, OrderSide::Buy, TickSize::Thousandth);
 
         // price=0.523, size=100 => cost=52.3
-        // BUY: maker = cost (5230), taker = shares (10000)
-        assert_eq!(maker, "5230");
-        assert_eq!(taker, "10000");
+        // BUY: maker = cost (52.3 USDC), taker = shares (100)
+        assert_eq!(maker, "52300000");
+        assert_eq!(taker, "100000000");
     }
 
     #[test]
@@ -146,9 +259,9 @@ mod tests {
             calculate_order_amounts(0.5234, 100.0, OrderSide::Buy, TickSize::TenThousandth);
 
         // price=0.5234, size=100 => cost=52.34
-        // BUY: maker = cost (5234), taker = shares (10000)
-        assert_eq!(maker, "5234");
-        assert_eq!(taker, "10000");
+        // BUY: maker = cost (52.34 USDC), taker = shares (100)
+        assert_eq!(maker, "52340000");
+        assert_eq!(taker, "100000000");
     }
 
     #[test]
@@ -158,20 +271,19 @@ mod tests {
             calculate_order_amounts(0.526, 100.0, OrderSide::Buy, TickSize::Hundredth);
 
         // price rounds to 0.53, size=100 => cost=53.0
-        assert_eq!(maker, "5300");
-        assert_eq!(taker, "10000");
+        assert_eq!(maker, "53000000");
+        assert_eq!(taker, "100000000");
     }
 
     #[test]
     fn test_calculate_order_amounts_size_rounding() {
-        // Size 100.567 should round to 100.57
+        // Size 100.567 is truncated to 100.56
         let (maker, taker) =
             calculate_order_amounts(0.50, 100.567, OrderSide::Buy, TickSize::Hundredth);
 
-        // price=0.50, size rounds to 100.57 => cost=50.285
-        // With banker's rounding: 50.285 rounds to 50.28 (8 is even)
-        assert_eq!(maker, "5028");
-        assert_eq!(taker, "10057");
+        // price=0.50, size truncates to 100.56 => cost=50.28
+        assert_eq!(maker, "50280000");
+        assert_eq!(taker, "100560000");
     }
 
     #[test]
@@ -180,8 +292,8 @@ mod tests {
             calculate_order_amounts(0.01, 100.0, OrderSide::Buy, TickSize::Hundredth);
 
         // price=0.01, size=100 => cost=1.0
-        assert_eq!(maker, "100");
-        assert_eq!(taker, "10000");
+        assert_eq!(maker, "1000000");
+        assert_eq!(taker, "100000000");
     }
 
     #[test]
@@ -190,8 +302,8 @@ mod tests {
             calculate_order_amounts(0.99, 100.0, OrderSide::Buy, TickSize::Hundredth);
 
         // price=0.99, size=100 => cost=99.0
-        assert_eq!(maker, "9900");
-        assert_eq!(taker, "10000");
+        assert_eq!(maker, "99000000");
+        assert_eq!(taker, "100000000");
     }
 
     #[test]
@@ -199,10 +311,9 @@ mod tests {
         let (maker, taker) =
             calculate_order_amounts(0.50, 0.01, OrderSide::Buy, TickSize::Hundredth);
 
-        // price=0.50, size=0.01 => cost=0.005
-        // With banker's rounding: 0.005 rounds to 0.00 (0 is even)
-        assert_eq!(maker, "0");
-        assert_eq!(taker, "1");
+        // price=0.50, size=0.01 => cost=0.005, exact at 4 decimals
+        assert_eq!(maker, "5000");
+        assert_eq!(taker, "10000");
     }
 
     #[test]
@@ -211,8 +322,8 @@ mod tests {
             calculate_order_amounts(0.50, 10000.0, OrderSide::Buy, TickSize::Hundredth);
 
         // price=0.50, size=10000 => cost=5000.0
-        assert_eq!(maker, "500000");
-        assert_eq!(taker, "1000000");
+        assert_eq!(maker, "5000000000");
+        assert_eq!(taker, "10000000000");
     }
 
     #[test]
@@ -275,19 +386,19 @@ mod tests {
         // Test banker's rounding (round half to even)
         // 0.555 rounds to 0.56 (6 is even)
         let (maker, _) = calculate_order_amounts(0.555, 100.0, OrderSide::Buy, TickSize::Hundredth);
-        assert_eq!(maker, "5600"); // 0.56 * 100 = 56.0 => 5600
+        assert_eq!(maker, "56000000"); // 0.56 * 100 = 56.0
 
         // 0.554 rounds down to 0.55
         let (maker, _) = calculate_order_amounts(0.554, 100.0, OrderSide::Buy, TickSize::Hundredth);
-        assert_eq!(maker, "5500"); // 0.55 * 100 = 55.0 => 5500
+        assert_eq!(maker, "55000000"); // 0.55 * 100 = 55.0
 
         // 0.545 rounds to 0.54 (4 is even) - banker's rounding
         let (maker, _) = calculate_order_amounts(0.545, 100.0, OrderSide::Buy, TickSize::Hundredth);
-        assert_eq!(maker, "5400"); // 0.54 * 100 = 54.0 => 5400
+        assert_eq!(maker, "54000000"); // 0.54 * 100 = 54.0
 
         // 0.556 rounds up to 0.56
         let (maker, _) = calculate_order_amounts(0.556, 100.0, OrderSide::Buy, TickSize::Hundredth);
-        assert_eq!(maker, "5600"); // 0.56 * 100 = 56.0 => 5600
+        assert_eq!(maker, "56000000"); // 0.56 * 100 = 56.0
     }
 
     #[test]
@@ -312,19 +423,152 @@ mod tests {
         // 0.33 * 100.0 = 33.0 exactly, but intermediate f64 ops can introduce error
         let (maker, taker) =
             calculate_order_amounts(0.33, 100.0, OrderSide::Buy, TickSize::Hundredth);
-        assert_eq!(maker, "3300");
-        assert_eq!(taker, "10000");
+        assert_eq!(maker, "33000000");
+        assert_eq!(taker, "100000000");
 
         // Another precision-sensitive case: 0.07 * 1000.0
         let (maker, taker) =
             calculate_order_amounts(0.07, 1000.0, OrderSide::Buy, TickSize::Hundredth);
-        assert_eq!(maker, "7000"); // Should be exactly 7000, not 6999 or 7001
-        assert_eq!(taker, "100000");
+        assert_eq!(maker, "70000000"); // Exactly 70 USDC, not 69.999999
+        assert_eq!(taker, "1000000000");
 
         // Test with small values that stress precision
         let (maker, taker) =
             calculate_order_amounts(0.01, 0.01, OrderSide::Buy, TickSize::Hundredth);
-        assert_eq!(maker, "0"); // 0.01 * 0.01 = 0.0001, rounds to 0.00 => 0
-        assert_eq!(taker, "1");
+        assert_eq!(maker, "100"); // 0.01 * 0.01 = 0.0001 USDC
+        assert_eq!(taker, "10000");
+    }
+
+    #[test]
+    fn test_round_price() {
+        assert_eq!(round_price(0.526, TickSize::Hundredth), 0.53);
+        assert_eq!(round_price(0.545, TickSize::Hundredth), 0.54);
+        assert_eq!(round_price(0.5255, TickSize::Thousandth), 0.526);
+        assert_eq!(round_price(0.07, TickSize::Tenth), 0.1);
+    }
+
+    #[test]
+    fn test_round_size() {
+        assert_eq!(round_size(100.567, 0.01), 100.56);
+        assert_eq!(round_size(12.34, 5.0), 10.0);
+        assert_eq!(round_size(4.99, 5.0), 0.0);
+        assert_eq!(round_size(0.29, 0.0), 0.29);
+    }
+
+    #[test]
+    fn test_decimal_amounts_match_f64() {
+        let (maker, taker) = calculate_order_amounts_decimal(
+            Decimal::new(56, 2),
+            Decimal::new(2104, 2),
+            OrderSide::Buy,
+            TickSize::Hundredth,
+        );
+        assert_eq!((maker.as_str(), taker.as_str()), ("11782400", "21040000"));
+        assert_eq!(
+            calculate_order_amounts(0.56, 21.04, OrderSide::Buy, TickSize::Hundredth),
+            (maker, taker)
+        );
+    }
+
+    #[test]
+    fn test_amounts_for_reference_orders() {
+        // Price/size pairs from py-clob-client's order builder tests whose
+        // products are not exact in f64 (e.g. 0.29 * 100.0 = 28.999999999999996)
+        let cases = [
+            (
+                0.29,
+                100.0,
+                OrderSide::Buy,
+                TickSize::Hundredth,
+                "29000000",
+                "100000000",
+            ),
+            (
+                0.58,
+                19.1,
+                OrderSide::Buy,
+                TickSize::Hundredth,
+                "11078000",
+                "19100000",
+            ),
+            (
+                0.56,
+                21.04,
+                OrderSide::Sell,
+                TickSize::Hundredth,
+                "21040000",
+                "11782400",
+            ),
+            (
+                0.057,
+                0.7,
+                OrderSide::Buy,
+                TickSize::Thousandth,
+                "39900",
+                "700000",
+            ),
+            (
+                0.0056,
+                21.04,
+                OrderSide::Sell,
+                TickSize::TenThousandth,
+                "21040000",
+                "117824",
+            ),
+            (
+                0.82,
+                101.0,
+                OrderSide::Sell,
+                TickSize::Tenth,
+                "101000000",
+                "80800000",
+            ),
+        ];
+
+        for (price, size, side, tick_size, maker, taker) in cases {
+            assert_eq!(
+                calculate_order_amounts(price, size, side, tick_size),
+                (maker.to_string(), taker.to_string()),
+                "price {price}, size {size}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_clock_offset_added_to_timestamps() {
+        let clock = Clock::default();
+        assert_eq!(clock.offset(), 0);
+
+        clock.set_server_time(current_timestamp() + 120);
+        let offset = clock.offset();
+        assert!((119..=120).contains(&offset));
+
+        let now = clock.now();
+        let local = current_timestamp();
+        assert!(now >= local + 119 && now <= local + 121);
+
+        // Clones share the measured offset
+        assert_eq!(clock.clone().offset(), offset);
+    }
+
+    #[tokio::test]
+    async fn test_clock_syncs_once() {
+        let clock = Clock::synced();
+        let server_time = current_timestamp() - 300;
+
+        clock.sync(|| async { Ok(server_time) }).await.unwrap();
+        assert!((-301..=-300).contains(&clock.offset()));
+
+        clock
+            .sync(|| async { Err(ClobError::validation("server time fetched twice")) })
+            .await
+            .unwrap();
+
+        // Clocks that were not asked to sync never fetch
+        Clock::default()
+            .sync(|| async { Err(ClobError::validation("server time fetched without sync")) })
+            .await
+            .unwrap();
+        assert_eq!(Clock::default().offset(), 0);
     }
 }
@@ -4,6 +4,8 @@ use std::fmt;
 
 use serde::{Deserialize, Serialize};
 
+use crate::account::redact_key;
+
 /// API credentials for WebSocket user channel authentication.
 ///
 /// These credentials can be obtained from your Polymarket account settings
@@ -59,9 +61,9 @@ impl ApiCredentials {
 impl fmt::Debug for ApiCredentials {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("ApiCredentials")
-            .field("api_key", &"<redacted>")
-            .field("secret", &"<redacted>")
-            .field("passphrase", &"<redacted>")
+            .field("api_key", &redact_key(&self.api_key))
+            .field("secret", &"***")
+            .field("passphrase", &"***")
             .finish()
     }
 }
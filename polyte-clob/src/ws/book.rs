@@ -0,0 +1,583 @@
+//! Local order book maintained from market channel messages.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use rust_decimal::{prelude::ToPrimitive, Decimal};
+
+use super::{
+    error::WebSocketError,
+    market::{BookMessage, MarketMessage, OrderSummary, PriceChange},
+};
+use crate::types::OrderSide;
+
+/// Order book of a single asset, kept in sync from `book` snapshots and
+/// `price_change` deltas.
+///
+/// The best bid and ask are cached on every update, so top-of-book reads and
+/// the signals derived from them are O(1).
+#[derive(Debug, Clone, Default)]
+pub struct LocalOrderBook {
+    bids: BTreeMap<Decimal, Decimal>,
+    asks: BTreeMap<Decimal, Decimal>,
+    best_bid: Option<(Decimal, Decimal)>,
+    best_ask: Option<(Decimal, Decimal)>,
+    hash: Option<String>,
+    timestamp: Option<i64>,
+}
+
+impl LocalOrderBook {
+    /// Create an empty order book
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build an order book from a snapshot
+    pub fn from_snapshot(book: &BookMessage) -> Self {
+        let mut local = Self::new();
+        local.apply_snapshot(book);
+        local
+    }
+
+    /// Replace the whole book with a snapshot
+    pub fn apply_snapshot(&mut self, book: &BookMessage) {
+        self.bids = parse_levels(&book.bids);
+        self.asks = parse_levels(&book.asks);
+        self.hash = Some(book.hash.clone());
+        self.timestamp = book.timestamp_ms().ok();
+        self.refresh_best_bid();
+        self.refresh_best_ask();
+    }
+
+    /// Apply an incremental update to one price level.
+    ///
+    /// A size of zero removes the level. Changes with an unknown side or an
+    /// unparsable price or size are ignored.
+    pub fn apply_price_change(&mut self, change: &PriceChange) {
+        let (Some(side), Ok(price), Ok(size)) = (
+            change.side(),
+            change.price.parse::<Decimal>(),
+            change.size.parse::<Decimal>(),
+        ) else {
+            tracing::warn!("Ignoring malformed price change: {:?}", change);
+            return;
+        };
+
+        let levels = match side {
+            OrderSide::Buy => &mut self.bids,
+            OrderSide::Sell => &mut self.asks,
+        };
+        if size.is_zero() {
+            levels.remove(&price);
+        } else {
+            levels.insert(price, size);
+        }
+
+        self.hash = Some(change.hash.clone());
+        match side {
+            OrderSide::Buy => self.refresh_best_bid(),
+            OrderSide::Sell => self.refresh_best_ask(),
+        }
+    }
+
+    /// Hash of the last snapshot or change applied
+    pub fn hash(&self) -> Option<&str> {
+        self.hash.as_deref()
+    }
+
+    /// Timestamp in milliseconds of the last snapshot or change applied
+    /// through [`OrderBookState`]
+    pub fn timestamp(&self) -> Option<i64> {
+        self.timestamp
+    }
+
+    /// Best bid as `(price, size)`
+    pub fn best_bid(&self) -> Option<(f64, f64)> {
+        self.best_bid.and_then(to_f64_level)
+    }
+
+    /// Best ask as `(price, size)`
+    pub fn best_ask(&self) -> Option<(f64, f64)> {
+        self.best_ask.and_then(to_f64_level)
+    }
+
+    /// Mid point between the best bid and ask
+    pub fn midpoint(&self) -> Option<f64> {
+        let (bid, _) = self.best_bid()?;
+        let (ask, _) = self.best_ask()?;
+        Some((bid + ask) / 2.0)
+    }
+
+    /// Up to `levels` price levels per side, best first
+    pub fn depth(&self, levels: usize) -> BookDepth {
+        BookDepth {
+            bids: self
+                .bids
+                .iter()
+                .rev()
+                .take(levels)
+                .filter_map(|(p, s)| to_f64_level((*p, *s)))
+                .collect(),
+            asks: self
+                .asks
+                .iter()
+                .take(levels)
+                .filter_map(|(p, s)| to_f64_level((*p, *s)))
+                .collect(),
+        }
+    }
+
+    /// Whether the top of book agrees with the best prices a price change
+    /// reports. Prices that are missing or unparsable are not checked.
+    fn matches_top(&self, change: &PriceChange) -> bool {
+        let agrees = |reported: Option<&String>, local: Option<(Decimal, Decimal)>| {
+            match reported.and_then(|price| price.parse::<Decimal>().ok()) {
+                // An empty side is reported as a zero price
+                Some(price) if price.is_zero() => local.is_none(),
+                Some(price) => local.is_some_and(|(p, _)| p == price),
+                None => true,
+            }
+        };
+        agrees(change.best_bid.as_ref(), self.best_bid)
+            && agrees(change.best_ask.as_ref(), self.best_ask)
+    }
+
+    /// Share of top-of-book size resting on the bid: `bid / (bid + ask)`.
+    ///
+    /// Ranges from 0.0 (all size on the ask) to 1.0 (all size on the bid),
+    /// and is 0.5 when both sides are empty.
+    pub fn imbalance(&self) -> f64 {
+        let bid = self.best_bid().map_or(0.0, |(_, size)| size);
+        let ask = self.best_ask().map_or(0.0, |(_, size)| size);
+
+        if bid + ask == 0.0 {
+            0.5
+        } else {
+            bid / (bid + ask)
+        }
+    }
+
+    /// Size-weighted mid price of the top of book.
+    ///
+    /// Each side's price is weighted by the size on the opposite side, so
+    /// the microprice leans towards the side that is more likely to trade
+    /// through. `None` unless both sides have a level.
+    pub fn microprice(&self) -> Option<f64> {
+        let (bid_price, bid_size) = self.best_bid()?;
+        let (ask_price, ask_size) = self.best_ask()?;
+
+        let total = bid_size + ask_size;
+        if total == 0.0 {
+            return None;
+        }
+        Some((bid_price * ask_size + ask_price * bid_size) / total)
+    }
+
+    fn refresh_best_bid(&mut self) {
+        self.best_bid = self.bids.last_key_value().map(|(p, s)| (*p, *s));
+    }
+
+    fn refresh_best_ask(&mut self) {
+        self.best_ask = self.asks.first_key_value().map(|(p, s)| (*p, *s));
+    }
+}
+
+/// Top price levels of both sides of a book, as `(price, size)`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BookDepth {
+    /// Bid levels, highest price first
+    pub bids: Vec<(f64, f64)>,
+    /// Ask levels, lowest price first
+    pub asks: Vec<(f64, f64)>,
+}
+
+/// Order books of every asset on a market channel subscription.
+///
+/// Feed each [`MarketMessage`] to [`OrderBookState::apply`]: `book`
+/// snapshots replace an asset's book and `price_change` deltas update it.
+/// A delta for an asset without a snapshot, one older than the last update
+/// applied to the asset, or one whose reported best bid/ask disagrees with
+/// the local book, marks the asset as out of sync. Out of order deltas are
+/// not applied. The server `hash` is opaque and cannot be recomputed
+/// locally, so it is recorded per asset alongside the timestamp and sync is
+/// checked against the reported best prices. Applying a fresh snapshot, e.g.
+/// after [`WebSocket::resubscribe`](super::WebSocket::resubscribe), clears
+/// the flag.
+#[derive(Debug, Clone, Default)]
+pub struct OrderBookState {
+    books: HashMap<String, LocalOrderBook>,
+    out_of_sync: HashSet<String>,
+}
+
+impl OrderBookState {
+    /// Create an empty state
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Apply a market channel message. Messages other than `book` and
+    /// `price_change` are ignored.
+    pub fn apply(&mut self, message: &MarketMessage) {
+        self.apply_message(message);
+    }
+
+    /// Apply a market channel message, failing with
+    /// [`WebSocketError::SequenceGap`] for the first asset it leaves out of
+    /// sync.
+    ///
+    /// The rest of the message is still applied; every asset needing a
+    /// fresh snapshot is listed by [`Self::assets_to_resync`].
+    pub fn try_apply(&mut self, message: &MarketMessage) -> Result<(), WebSocketError> {
+        match self.apply_message(message) {
+            Some(asset_id) => Err(WebSocketError::SequenceGap { asset_id }),
+            None => Ok(()),
+        }
+    }
+
+    /// Apply a message, returning the first asset it left out of sync
+    fn apply_message(&mut self, message: &MarketMessage) -> Option<String> {
+        match message {
+            MarketMessage::Book(book) => {
+                self.out_of_sync.remove(&book.asset_id);
+                self.books
+                    .entry(book.asset_id.clone())
+                    .or_default()
+                    .apply_snapshot(book);
+                None
+            }
+            MarketMessage::PriceChange(message) => {
+                let timestamp = message.timestamp_ms().ok();
+                let mut gap = None;
+                for change in &message.price_changes {
+                    if !self.apply_price_change(change, timestamp) && gap.is_none() {
+                        gap = Some(change.asset_id.clone());
+                    }
+                }
+                gap
+            }
+            _ => None,
+        }
+    }
+
+    /// Apply a change, returning whether the asset's book is still in sync
+    fn apply_price_change(&mut self, change: &PriceChange, timestamp: Option<i64>) -> bool {
+        let Some(book) = self.books.get_mut(&change.asset_id) else {
+            tracing::warn!("Price change for {} before any snapshot", change.asset_id);
+            self.out_of_sync.insert(change.asset_id.clone());
+            return false;
+        };
+
+        if let (Some(timestamp), Some(last)) = (timestamp, book.timestamp) {
+            if timestamp < last {
+                tracing::warn!(
+                    "Out of order price change for {}: {} before {}",
+                    change.asset_id,
+                    timestamp,
+                    last
+                );
+                self.out_of_sync.insert(change.asset_id.clone());
+                return false;
+            }
+        }
+
+        book.apply_price_change(change);
+        if timestamp.is_some() {
+            book.timestamp = timestamp;
+        }
+        if !book.matches_top(change) {
+            tracing::warn!("Order book for {} out of sync", change.asset_id);
+            self.out_of_sync.insert(change.asset_id.clone());
+            return false;
+        }
+        true
+    }
+
+    /// Order book of an asset, once a snapshot has been received
+    pub fn book(&self, asset_id: &str) -> Option<&LocalOrderBook> {
+        self.books.get(asset_id)
+    }
+
+    /// Best bid of an asset as `(price, size)`
+    pub fn best_bid(&self, asset_id: &str) -> Option<(f64, f64)> {
+        self.book(asset_id)?.best_bid()
+    }
+
+    /// Best ask of an asset as `(price, size)`
+    pub fn best_ask(&self, asset_id: &str) -> Option<(f64, f64)> {
+        self.book(asset_id)?.best_ask()
+    }
+
+    /// Mid point between the best bid and ask of an asset
+    pub fn midpoint(&self, asset_id: &str) -> Option<f64> {
+        self.book(asset_id)?.midpoint()
+    }
+
+    /// Up to `levels` price levels per side of an asset's book
+    pub fn depth(&self, asset_id: &str, levels: usize) -> Option<BookDepth> {
+        Some(self.book(asset_id)?.depth(levels))
+    }
+
+    /// Hash of the last snapshot or change applied to an asset's book
+    pub fn hash(&self, asset_id: &str) -> Option<&str> {
+        self.book(asset_id)?.hash()
+    }
+
+    /// Timestamp in milliseconds of the last snapshot or change applied to
+    /// an asset's book
+    pub fn timestamp(&self, asset_id: &str) -> Option<i64> {
+        self.book(asset_id)?.timestamp()
+    }
+
+    /// Whether any asset needs a fresh snapshot
+    pub fn resync_needed(&self) -> bool {
+        !self.out_of_sync.is_empty()
+    }
+
+    /// Assets that need a fresh snapshot, e.g. by resubscribing
+    pub fn assets_to_resync(&self) -> impl Iterator<Item = &str> {
+        self.out_of_sync.iter().map(String::as_str)
+    }
+}
+
+/// Parse snapshot levels, skipping empty or malformed ones
+fn parse_levels(levels: &[OrderSummary]) -> BTreeMap<Decimal, Decimal> {
+    levels
+        .iter()
+        .filter_map(|level| {
+            let price = level.price.parse::<Decimal>().ok()?;
+            let size = level.size.parse::<Decimal>().ok()?;
+            (!size.is_zero()).then_some((price, size))
+        })
+        .collect()
+}
+
+fn to_f64_level((price, size): (Decimal, Decimal)) -> Option<(f64, f64)> {
+    Some((price.to_f64()?, size.to_f64()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ws::market::PriceChangeMessage;
+
+    fn level(price: &str, size: &str) -> OrderSummary {
+        OrderSummary {
+            price: price.to_string(),
+            size: size.to_string(),
+        }
+    }
+
+    fn snapshot(bids: Vec<OrderSummary>, asks: Vec<OrderSummary>) -> BookMessage {
+        BookMessage {
+            event_type: "book".to_string(),
+            asset_id: "1".to_string(),
+            market: "0xabc".to_string(),
+            timestamp: "0".to_string(),
+            hash: "0x1".to_string(),
+            bids,
+            asks,
+            last_trade_price: None,
+        }
+    }
+
+    fn change(side: &str, price: &str, size: &str) -> PriceChange {
+        PriceChange {
+            asset_id: "1".to_string(),
+            price: price.to_string(),
+            size: size.to_string(),
+            side: side.into(),
+            hash: "0x2".to_string(),
+            best_bid: None,
+            best_ask: None,
+        }
+    }
+
+    fn book() -> LocalOrderBook {
+        LocalOrderBook::from_snapshot(&snapshot(
+            vec![level("0.48", "50"), level("0.50", "300")],
+            vec![level("0.54", "80"), level("0.52", "100")],
+        ))
+    }
+
+    #[test]
+    fn test_best_levels() {
+        let book = book();
+        assert_eq!(book.best_bid(), Some((0.50, 300.0)));
+        assert_eq!(book.best_ask(), Some((0.52, 100.0)));
+        assert_eq!(book.hash(), Some("0x1"));
+    }
+
+    #[test]
+    fn test_imbalance() {
+        // 300 on the bid vs 100 on the ask
+        assert_eq!(book().imbalance(), 0.75);
+        assert_eq!(LocalOrderBook::new().imbalance(), 0.5);
+    }
+
+    #[test]
+    fn test_microprice() {
+        // (0.50 * 100 + 0.52 * 300) / 400
+        let microprice = book().microprice().unwrap();
+        assert!((microprice - 0.515).abs() < 1e-12);
+        assert_eq!(LocalOrderBook::new().microprice(), None);
+    }
+
+    #[test]
+    fn test_signals_follow_price_changes() {
+        let mut book = book();
+
+        // Remove the best bid: 0.48 x 50 becomes the top
+        book.apply_price_change(&change("BUY", "0.50", "0"));
+        assert_eq!(book.best_bid(), Some((0.48, 50.0)));
+
+        // A better ask replaces the top of the ask side
+        book.apply_price_change(&change("SELL", "0.51", "150"));
+        assert_eq!(book.best_ask(), Some((0.51, 150.0)));
+
+        assert_eq!(book.imbalance(), 0.25);
+        let microprice = book.microprice().unwrap();
+        assert!((microprice - (0.48 * 150.0 + 0.51 * 50.0) / 200.0).abs() < 1e-12);
+        assert_eq!(book.hash(), Some("0x2"));
+    }
+
+    #[test]
+    fn test_midpoint_and_depth() {
+        let book = book();
+        assert!((book.midpoint().unwrap() - 0.51).abs() < 1e-12);
+
+        let depth = book.depth(1);
+        assert_eq!(depth.bids, vec![(0.50, 300.0)]);
+        assert_eq!(depth.asks, vec![(0.52, 100.0)]);
+
+        let depth = book.depth(5);
+        assert_eq!(depth.bids, vec![(0.50, 300.0), (0.48, 50.0)]);
+        assert_eq!(depth.asks, vec![(0.52, 100.0), (0.54, 80.0)]);
+    }
+
+    fn price_change_message(changes: Vec<PriceChange>) -> MarketMessage {
+        MarketMessage::PriceChange(PriceChangeMessage {
+            event_type: "price_change".to_string(),
+            market: "0xabc".to_string(),
+            price_changes: changes,
+            timestamp: "0".to_string(),
+        })
+    }
+
+    fn reported(mut change: PriceChange, best_bid: &str, best_ask: &str) -> PriceChange {
+        change.best_bid = Some(best_bid.to_string());
+        change.best_ask = Some(best_ask.to_string());
+        change
+    }
+
+    #[test]
+    fn test_state_applies_snapshot_and_changes() {
+        let mut state = OrderBookState::new();
+        state.apply(&MarketMessage::Book(snapshot(
+            vec![level("0.48", "50"), level("0.50", "300")],
+            vec![level("0.54", "80"), level("0.52", "100")],
+        )));
+
+        state.apply(&price_change_message(vec![
+            reported(change("BUY", "0.51", "20"), "0.51", "0.52"),
+            reported(change("SELL", "0.52", "0"), "0.51", "0.54"),
+            reported(change("SELL", "0.53", "40"), "0.51", "0.53"),
+        ]));
+
+        assert_eq!(state.best_bid("1"), Some((0.51, 20.0)));
+        assert_eq!(state.best_ask("1"), Some((0.53, 40.0)));
+        assert!((state.midpoint("1").unwrap() - 0.52).abs() < 1e-12);
+        assert_eq!(
+            state.depth("1", 2),
+            Some(BookDepth {
+                bids: vec![(0.51, 20.0), (0.50, 300.0)],
+                asks: vec![(0.53, 40.0), (0.54, 80.0)],
+            })
+        );
+        assert_eq!(state.hash("1"), Some("0x2"));
+        assert!(!state.resync_needed());
+    }
+
+    #[test]
+    fn test_state_resync_without_snapshot() {
+        let mut state = OrderBookState::new();
+        state.apply(&price_change_message(vec![change("BUY", "0.50", "10")]));
+
+        assert!(state.resync_needed());
+        assert_eq!(state.assets_to_resync().collect::<Vec<_>>(), vec!["1"]);
+        assert_eq!(state.best_bid("1"), None);
+
+        // A snapshot brings the asset back in sync
+        state.apply(&MarketMessage::Book(snapshot(
+            vec![level("0.50", "10")],
+            vec![],
+        )));
+        assert!(!state.resync_needed());
+    }
+
+    #[test]
+    fn test_state_resync_on_top_of_book_mismatch() {
+        let mut state = OrderBookState::new();
+        state.apply(&MarketMessage::Book(snapshot(
+            vec![level("0.50", "300")],
+            vec![level("0.52", "100")],
+        )));
+
+        // The server reports a best bid the local book never saw
+        state.apply(&price_change_message(vec![reported(
+            change("SELL", "0.53", "10"),
+            "0.505",
+            "0.52",
+        )]));
+
+        assert!(state.resync_needed());
+    }
+
+    #[test]
+    fn test_state_gap_on_out_of_order_change() {
+        let mut state = OrderBookState::new();
+        let mut book = snapshot(vec![level("0.50", "300")], vec![level("0.52", "100")]);
+        book.timestamp = "2000".to_string();
+        state.try_apply(&MarketMessage::Book(book.clone())).unwrap();
+        assert_eq!(state.timestamp("1"), Some(2_000));
+
+        let at = |timestamp: &str, change: PriceChange| {
+            let mut message = price_change_message(vec![change]);
+            if let MarketMessage::PriceChange(message) = &mut message {
+                message.timestamp = timestamp.to_string();
+            }
+            message
+        };
+
+        state
+            .try_apply(&at("2500", change("BUY", "0.51", "10")))
+            .unwrap();
+        assert_eq!(state.timestamp("1"), Some(2_500));
+
+        // A delta older than the last one applied arrived out of order
+        let err = state
+            .try_apply(&at("2400", change("BUY", "0.49", "10")))
+            .unwrap_err();
+        assert!(matches!(err, WebSocketError::SequenceGap { ref asset_id } if asset_id == "1"));
+        assert!(state.resync_needed());
+        assert_eq!(state.assets_to_resync().collect::<Vec<_>>(), vec!["1"]);
+
+        // The stale delta was not applied
+        assert_eq!(
+            state.depth("1", 5).unwrap().bids,
+            vec![(0.51, 10.0), (0.50, 300.0)]
+        );
+        assert_eq!(state.timestamp("1"), Some(2_500));
+
+        // A fresh snapshot brings the asset back in sync
+        book.timestamp = "3000".to_string();
+        state.try_apply(&MarketMessage::Book(book)).unwrap();
+        assert!(!state.resync_needed());
+    }
+
+    #[test]
+    fn test_malformed_change_ignored() {
+        let mut book = book();
+        book.apply_price_change(&change("HOLD", "0.60", "10"));
+        book.apply_price_change(&change("BUY", "abc", "10"));
+        assert_eq!(book.best_bid(), Some((0.50, 300.0)));
+    }
+}
@@ -4,19 +4,38 @@ use std::{
     time::Duration,
 };
 
-use futures_util::{SinkExt, Stream, StreamExt};
-use tokio::{net::TcpStream, time::interval};
+use futures_util::{
+    future::try_join_all,
+    stream::{repeat, select_all, Repeat, SelectAll, SplitSink, SplitStream, Zip},
+    SinkExt, Stream, StreamExt,
+};
+use tokio::{
+    net::TcpStream,
+    time::{interval, sleep_until, Instant},
+};
 use tokio_tungstenite::{connect_async, tungstenite::Message, MaybeTlsStream, WebSocketStream};
 
 use super::{
     auth::ApiCredentials,
     error::WebSocketError,
     market::MarketMessage,
-    subscription::{ChannelType, MarketSubscription, UserSubscription, WS_MARKET_URL, WS_USER_URL},
+    subscription::{
+        ChannelType, MarketSubscription, UserSubscription, MAX_ASSETS_PER_SUBSCRIPTION,
+        WS_MARKET_URL, WS_USER_URL,
+    },
     user::UserMessage,
     Channel,
 };
 
+type WsStream = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+/// Read half of a connection tagged with the connection's index
+type TaggedStream = Zip<Repeat<usize>, SplitStream<WsStream>>;
+
+/// Default number of ping intervals without any received frame before the
+/// connection is considered dead
+pub const DEFAULT_HEARTBEAT_MULTIPLIER: u32 = 3;
+
 /// WebSocket client for Polymarket real-time updates.
 ///
 /// Provides streaming access to market data (order book, prices) and user-specific
@@ -40,13 +59,16 @@ use super::{
 /// }
 /// ```
 pub struct WebSocket {
-    inner: WebSocketStream<MaybeTlsStream<TcpStream>>,
+    inner: WsStream,
     channel_type: ChannelType,
 }
 
 impl WebSocket {
     /// Connect to the market channel for public order book and price updates.
     ///
+    /// A single connection accepts at most [`MAX_ASSETS_PER_SUBSCRIPTION`] assets;
+    /// use [`WebSocketBuilder::connect_market`] to subscribe to more.
+    ///
     /// # Arguments
     ///
     /// * `asset_ids` - Token IDs to subscribe to
@@ -124,6 +146,44 @@ impl WebSocket {
         Ok(())
     }
 
+    /// Subscribe the market channel to `asset_ids` again to receive a fresh
+    /// `book` snapshot for each, e.g. after an
+    /// [`OrderBookState`](super::OrderBookState) reports a sequence gap.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use futures_util::StreamExt;
+    /// use polyte_clob::ws::{Channel, OrderBookState, WebSocket, WebSocketError};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let mut ws = WebSocket::connect_market(vec!["asset_id".to_string()]).await?;
+    ///     let mut books = OrderBookState::new();
+    ///
+    ///     while let Some(msg) = ws.next().await {
+    ///         if let Channel::Market(msg) = msg? {
+    ///             if let Err(WebSocketError::SequenceGap { asset_id }) = books.try_apply(&msg) {
+    ///                 ws.resubscribe(vec![asset_id]).await?;
+    ///             }
+    ///         }
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn resubscribe(&mut self, asset_ids: Vec<String>) -> Result<(), WebSocketError> {
+        if self.channel_type != ChannelType::Market {
+            return Err(WebSocketError::InvalidMessage(
+                "Only market channel subscriptions can be refreshed".to_string(),
+            ));
+        }
+
+        let subscription = MarketSubscription::subscribe(asset_ids);
+        let msg = serde_json::to_string(&subscription)?;
+        self.inner.send(Message::Text(msg.into())).await?;
+        Ok(())
+    }
+
     /// Close the WebSocket connection.
     pub async fn close(&mut self) -> Result<(), WebSocketError> {
         self.inner.close(None).await?;
@@ -201,6 +261,8 @@ pub struct WebSocketBuilder {
     market_url: String,
     user_url: String,
     ping_interval: Option<Duration>,
+    heartbeat_multiplier: u32,
+    max_assets_per_connection: usize,
 }
 
 impl Default for WebSocketBuilder {
@@ -216,6 +278,8 @@ impl WebSocketBuilder {
             market_url: WS_MARKET_URL.to_string(),
             user_url: WS_USER_URL.to_string(),
             ping_interval: None,
+            heartbeat_multiplier: DEFAULT_HEARTBEAT_MULTIPLIER,
+            max_assets_per_connection: MAX_ASSETS_PER_SUBSCRIPTION,
         }
     }
 
@@ -240,22 +304,53 @@ impl WebSocketBuilder {
         self
     }
 
+    /// Set how many ping intervals may pass without receiving any frame
+    /// (including `PONG`) before `run` fails with [`WebSocketError::Timeout`].
+    ///
+    /// Default: [`DEFAULT_HEARTBEAT_MULTIPLIER`]. Set to `0` to disable.
+    pub fn heartbeat_multiplier(mut self, multiplier: u32) -> Self {
+        self.heartbeat_multiplier = multiplier;
+        self
+    }
+
+    /// Set the maximum number of assets subscribed per market connection.
+    ///
+    /// Default: [`MAX_ASSETS_PER_SUBSCRIPTION`], the server cap
+    pub fn max_assets_per_connection(mut self, max: usize) -> Self {
+        self.max_assets_per_connection = max;
+        self
+    }
+
     /// Connect to the market channel.
+    ///
+    /// When `asset_ids` exceeds the per-connection cap (see
+    /// [`Self::max_assets_per_connection`]), one connection is opened per
+    /// chunk of assets and their messages are merged into a single stream.
     pub async fn connect_market(
         self,
         asset_ids: Vec<String>,
     ) -> Result<WebSocketWithPing, WebSocketError> {
-        let (mut ws, _) = connect_async(&self.market_url).await?;
-
-        let subscription = MarketSubscription::new(asset_ids);
-        let msg = serde_json::to_string(&subscription)?;
-        ws.send(Message::Text(msg.into())).await?;
-
-        Ok(WebSocketWithPing {
-            inner: ws,
-            channel_type: ChannelType::Market,
-            ping_interval: self.ping_interval.unwrap_or(Duration::from_secs(10)),
-        })
+        let subscriptions = MarketSubscription::chunked(asset_ids, self.max_assets_per_connection);
+
+        let connections = try_join_all(subscriptions.iter().map(|subscription| async {
+            let (mut ws, _) = connect_async(&self.market_url).await?;
+            let msg = serde_json::to_string(subscription)?;
+            ws.send(Message::Text(msg.into())).await?;
+            Ok::<_, WebSocketError>(ws)
+        }))
+        .await?;
+        let assets = subscriptions
+            .into_iter()
+            .map(|subscription| subscription.assets_ids)
+            .collect();
+
+        Ok(WebSocketWithPing::new(
+            connections,
+            assets,
+            ChannelType::Market,
+            self.ping_interval,
+            self.heartbeat_multiplier,
+        ))
     }
 
     /// Connect to the user channel.
@@ -270,31 +365,69 @@ impl WebSocketBuilder {
         let msg = serde_json::to_string(&subscription)?;
         ws.send(Message::Text(msg.into())).await?;
 
-        Ok(WebSocketWithPing {
-            inner: ws,
-            channel_type: ChannelType::User,
-            ping_interval: self.ping_interval.unwrap_or(Duration::from_secs(10)),
-        })
+        Ok(WebSocketWithPing::new(
+            vec![ws],
+            vec![Vec::new()],
+            ChannelType::User,
+            self.ping_interval,
+            self.heartbeat_multiplier,
+        ))
     }
 }
 
 /// WebSocket client with automatic ping handling.
 ///
 /// Use this when you need automatic keep-alive pings. Call `run` to process
-/// messages with automatic ping handling.
+/// messages with automatic ping handling. Messages from every underlying
+/// connection are merged into a single stream.
 pub struct WebSocketWithPing {
-    inner: WebSocketStream<MaybeTlsStream<TcpStream>>,
+    sinks: Vec<SplitSink<WsStream, Message>>,
+    streams: SelectAll<TaggedStream>,
+    /// Assets subscribed on each connection (market channel only)
+    assets: Vec<Vec<String>>,
     channel_type: ChannelType,
     ping_interval: Duration,
+    heartbeat_timeout: Option<Duration>,
 }
 
 impl WebSocketWithPing {
+    fn new(
+        connections: Vec<WsStream>,
+        assets: Vec<Vec<String>>,
+        channel_type: ChannelType,
+        ping_interval: Option<Duration>,
+        heartbeat_multiplier: u32,
+    ) -> Self {
+        let (sinks, streams): (Vec<_>, Vec<_>) =
+            connections.into_iter().map(StreamExt::split).unzip();
+        let streams = streams
+            .into_iter()
+            .enumerate()
+            .map(|(index, stream)| repeat(index).zip(stream));
+        let ping_interval = ping_interval.unwrap_or(Duration::from_secs(10));
+
+        Self {
+            sinks,
+            streams: select_all(streams),
+            assets,
+            channel_type,
+            ping_interval,
+            heartbeat_timeout: (heartbeat_multiplier > 0)
+                .then(|| ping_interval.saturating_mul(heartbeat_multiplier)),
+        }
+    }
+
     /// Run the WebSocket message loop with automatic ping handling.
     ///
     /// This method will:
     /// - Send ping messages at the configured interval
     /// - Call the provided handler for each received message
     /// - Return when the connection is closed or an error occurs
+    /// - Fail with [`WebSocketError::Timeout`] when any single connection
+    ///   receives no frame for `ping_interval * heartbeat_multiplier`
+    ///
+    /// The connections stay open when the handler fails, so `run` can be
+    /// called again, e.g. after [`WebSocketWithPing::resubscribe`].
     ///
     /// # Arguments
     ///
@@ -308,7 +441,7 @@ impl WebSocketWithPing {
     ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    ///     let ws = WebSocketBuilder::new()
+    ///     let mut ws = WebSocketBuilder::new()
     ///         .ping_interval(Duration::from_secs(10))
     ///         .connect_market(vec!["asset_id".to_string()])
     ///         .await?;
@@ -321,19 +454,44 @@ impl WebSocketWithPing {
     ///     Ok(())
     /// }
     /// ```
-    pub async fn run<F, Fut>(mut self, mut handler: F) -> Result<(), WebSocketError>
+    pub async fn run<F, Fut>(&mut self, mut handler: F) -> Result<(), WebSocketError>
     where
         F: FnMut(Channel) -> Fut,
         Fut: std::future::Future<Output = Result<(), WebSocketError>>,
     {
         let mut ping_interval = interval(self.ping_interval);
+        let heartbeat_timeout = self.heartbeat_timeout;
+        let mut last_received = vec![Instant::now(); self.sinks.len()];
 
         loop {
+            // The stalest connection decides when the heartbeat expires
+            let oldest = last_received.iter().min().copied();
+            let heartbeat = async move {
+                match (heartbeat_timeout, oldest) {
+                    (Some(timeout), Some(oldest)) => sleep_until(oldest + timeout).await,
+                    _ => std::future::pending().await,
+                }
+            };
+
             tokio::select! {
+                _ = heartbeat => {
+                    return Err(WebSocketError::Timeout(heartbeat_timeout.unwrap_or_default()));
+                }
                 _ = ping_interval.tick() => {
-                    self.inner.send(Message::Text("PING".into())).await?;
+                    for sink in &mut self.sinks {
+                        sink.send(Message::Text("PING".into())).await?;
+                    }
                 }
-                msg = self.inner.next() => {
+                msg = self.streams.next() => {
+                    let msg = match msg {
+                        Some((index, msg)) => {
+                            if msg.is_ok() {
+                                last_received[index] = Instant::now();
+                            }
+                            Some(msg)
+                        }
+                        None => None,
+                    };
                     match msg {
                         Some(Ok(Message::Text(text))) => {
                             if text.as_str() == "PONG" {
@@ -365,11 +523,91 @@ impl WebSocketWithPing {
         }
     }
 
+    /// Subscribe the market channel to `asset_ids` again to receive a fresh
+    /// `book` snapshot for each, e.g. after an
+    /// [`OrderBookState`](super::OrderBookState) reports a sequence gap.
+    ///
+    /// Each asset is resubscribed on the connection that subscribed to it
+    /// when connecting.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use polyte_clob::ws::{Channel, OrderBookState, WebSocketBuilder, WebSocketError};
+    /// use std::sync::{Arc, Mutex};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let mut ws = WebSocketBuilder::new()
+    ///         .connect_market(vec!["asset_id".to_string()])
+    ///         .await?;
+    ///     let books = Arc::new(Mutex::new(OrderBookState::new()));
+    ///
+    ///     loop {
+    ///         let result = ws
+    ///             .run(|msg| {
+    ///                 let books = books.clone();
+    ///                 async move {
+    ///                     if let Channel::Market(msg) = msg {
+    ///                         books.lock().unwrap().try_apply(&msg)?;
+    ///                     }
+    ///                     Ok(())
+    ///                 }
+    ///             })
+    ///             .await;
+    ///
+    ///         match result {
+    ///             Err(WebSocketError::SequenceGap { asset_id }) => {
+    ///                 ws.resubscribe(vec![asset_id]).await?
+    ///             }
+    ///             result => return Ok(result?),
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub async fn resubscribe(&mut self, asset_ids: Vec<String>) -> Result<(), WebSocketError> {
+        if self.channel_type != ChannelType::Market {
+            return Err(WebSocketError::InvalidMessage(
+                "Only market channel subscriptions can be refreshed".to_string(),
+            ));
+        }
+
+        let mut by_connection = vec![Vec::new(); self.sinks.len()];
+        for asset_id in asset_ids {
+            let index = self
+                .assets
+                .iter()
+                .position(|assets| assets.contains(&asset_id))
+                .ok_or_else(|| {
+                    WebSocketError::InvalidMessage(format!(
+                        "Asset {} is not subscribed on any connection",
+                        asset_id
+                    ))
+                })?;
+            by_connection[index].push(asset_id);
+        }
+
+        for (sink, asset_ids) in self.sinks.iter_mut().zip(by_connection) {
+            if asset_ids.is_empty() {
+                continue;
+            }
+            let subscription = MarketSubscription::subscribe(asset_ids);
+            let msg = serde_json::to_string(&subscription)?;
+            sink.send(Message::Text(msg.into())).await?;
+        }
+        Ok(())
+    }
+
     /// Get the channel type this WebSocket is connected to.
     pub fn channel_type(&self) -> ChannelType {
         self.channel_type
     }
 
+    /// Get the number of underlying connections.
+    pub fn connection_count(&self) -> usize {
+        self.sinks.len()
+    }
+
     /// Parse a text message based on the channel type.
     fn parse_message(&self, text: &str) -> Result<Option<Channel>, WebSocketError> {
         // Skip PONG responses and empty messages
@@ -395,3 +633,187 @@ impl WebSocketWithPing {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use tokio::{net::TcpListener, sync::mpsc};
+
+    use super::*;
+
+    /// Accept market connections and report every subscription each one
+    /// receives, along with the assets of the connection's first one
+    async fn serve_market(
+        listener: TcpListener,
+        subscribed: mpsc::UnboundedSender<(Vec<String>, MarketSubscription)>,
+    ) {
+        while let Ok((stream, _)) = listener.accept().await {
+            let subscribed = subscribed.clone();
+            tokio::spawn(async move {
+                let mut ws = tokio_tungstenite::accept_async(stream).await.unwrap();
+                let mut initial = None;
+                // Keep the connection open until the client goes away
+                while let Some(Ok(msg)) = ws.next().await {
+                    let Ok(subscription) =
+                        serde_json::from_str::<MarketSubscription>(msg.to_text().unwrap_or(""))
+                    else {
+                        continue;
+                    };
+                    let initial = initial.get_or_insert_with(|| subscription.assets_ids.clone());
+                    let _ = subscribed.send((initial.clone(), subscription));
+                }
+            });
+        }
+    }
+
+    #[tokio::test]
+    async fn test_connect_market_chunks_assets() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        tokio::spawn(serve_market(listener, tx));
+
+        let assets: Vec<String> = (0..5).map(|i| format!("asset_{}", i)).collect();
+        let ws = WebSocketBuilder::new()
+            .market_url(url)
+            .max_assets_per_connection(2)
+            .connect_market(assets.clone())
+            .await
+            .unwrap();
+
+        assert_eq!(ws.connection_count(), 3);
+
+        let mut covered = Vec::new();
+        for _ in 0..3 {
+            let (_, subscription) = rx.recv().await.unwrap();
+            assert!(subscription.assets_ids.len() <= 2);
+            covered.extend(subscription.assets_ids);
+        }
+        covered.sort();
+        assert_eq!(covered, assets);
+    }
+
+    #[tokio::test]
+    async fn test_resubscribe_on_each_chunk_connection() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        tokio::spawn(serve_market(listener, tx));
+
+        let assets: Vec<String> = (0..5).map(|i| format!("asset_{}", i)).collect();
+        let mut ws = WebSocketBuilder::new()
+            .market_url(url)
+            .max_assets_per_connection(2)
+            .connect_market(assets)
+            .await
+            .unwrap();
+        for _ in 0..3 {
+            rx.recv().await.unwrap();
+        }
+
+        let resubscribe = ["asset_4", "asset_0", "asset_1"].map(String::from).to_vec();
+        ws.resubscribe(resubscribe).await.unwrap();
+
+        let mut received = Vec::new();
+        for _ in 0..2 {
+            let (initial, subscription) = rx.recv().await.unwrap();
+            assert_eq!(subscription.operation.as_deref(), Some("subscribe"));
+            received.push((initial, subscription.assets_ids));
+        }
+        received.sort();
+        assert_eq!(
+            received,
+            [
+                (
+                    vec!["asset_0".to_string(), "asset_1".to_string()],
+                    vec!["asset_0".to_string(), "asset_1".to_string()]
+                ),
+                (vec!["asset_4".to_string()], vec!["asset_4".to_string()]),
+            ]
+        );
+
+        let err = ws
+            .resubscribe(vec!["unknown".to_string()])
+            .await
+            .unwrap_err();
+        assert!(matches!(err, WebSocketError::InvalidMessage(_)));
+    }
+
+    /// Accept one connection per entry of `silent_after` and answer every
+    /// `PING` with `PONG` until that many pings, then stop replying while
+    /// keeping the connection open
+    async fn serve_heartbeat(listener: TcpListener, silent_after: Vec<usize>) {
+        for silent_after in silent_after {
+            let (stream, _) = listener.accept().await.unwrap();
+            tokio::spawn(async move {
+                let mut ws = tokio_tungstenite::accept_async(stream).await.unwrap();
+                let mut pings = 0;
+                while let Some(Ok(msg)) = ws.next().await {
+                    if msg.to_text().is_ok_and(|text| text == "PING") {
+                        pings += 1;
+                        if pings <= silent_after {
+                            let _ = ws.send(Message::Text("PONG".into())).await;
+                        }
+                    }
+                }
+            });
+        }
+    }
+
+    async fn connect_heartbeat(silent_after: Vec<usize>, multiplier: u32) -> WebSocketWithPing {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        let assets = (0..silent_after.len())
+            .map(|i| format!("asset_{}", i))
+            .collect();
+        tokio::spawn(serve_heartbeat(listener, silent_after));
+
+        WebSocketBuilder::new()
+            .market_url(url)
+            .max_assets_per_connection(1)
+            .ping_interval(Duration::from_millis(50))
+            .heartbeat_multiplier(multiplier)
+            .connect_market(assets)
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_run_times_out_on_silent_server() {
+        let mut ws = connect_heartbeat(vec![2], 3).await;
+
+        let result = tokio::time::timeout(Duration::from_secs(5), ws.run(|_| async { Ok(()) }))
+            .await
+            .expect("run should detect the silent server");
+
+        assert!(matches!(
+            result,
+            Err(WebSocketError::Timeout(timeout)) if timeout == Duration::from_millis(150)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_run_heartbeat_disabled() {
+        let mut ws = connect_heartbeat(vec![0], 0).await;
+
+        let result =
+            tokio::time::timeout(Duration::from_millis(500), ws.run(|_| async { Ok(()) })).await;
+
+        assert!(
+            result.is_err(),
+            "run should keep waiting without a heartbeat timeout"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_run_times_out_when_one_connection_goes_silent() {
+        // One connection keeps answering, which must not mask the silent one
+        let mut ws = connect_heartbeat(vec![usize::MAX, 2], 3).await;
+        assert_eq!(ws.connection_count(), 2);
+
+        let result = tokio::time::timeout(Duration::from_secs(5), ws.run(|_| async { Ok(()) }))
+            .await
+            .expect("run should detect the silent connection");
+
+        assert!(matches!(result, Err(WebSocketError::Timeout(_))));
+    }
+}
@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use thiserror::Error;
 
 /// WebSocket-specific errors.
@@ -23,6 +25,28 @@ pub enum WebSocketError {
     #[error("Invalid message: {0}")]
     InvalidMessage(String),
 
+    /// Numeric field of a message could not be parsed
+    #[error("Invalid {field}: {value:?} is not a number")]
+    InvalidNumber {
+        /// Name of the field
+        field: &'static str,
+        /// Raw value received
+        value: String,
+    },
+
+    /// Updates of an asset were missed or arrived out of order; the local
+    /// order book needs a fresh snapshot
+    #[error("Sequence gap on asset {asset_id}")]
+    SequenceGap {
+        /// Asset ID (token ID)
+        asset_id: String,
+    },
+
+    /// No frame was received within the heartbeat timeout; the connection is
+    /// presumed dead
+    #[error("No message received within {0:?}")]
+    Timeout(Duration),
+
     /// URL parse error
     #[error("URL parse error: {0}")]
     Url(#[from] url::ParseError),
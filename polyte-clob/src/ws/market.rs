@@ -2,8 +2,13 @@
 //!
 //! The market channel provides real-time order book and price updates.
 
+use std::str::FromStr;
+
 use serde::{Deserialize, Serialize};
 
+use super::{error::WebSocketError, side::Side};
+use crate::types::OrderSide;
+
 /// Order summary in the order book
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OrderSummary {
@@ -13,6 +18,18 @@ pub struct OrderSummary {
     pub size: String,
 }
 
+impl OrderSummary {
+    /// Price level as a number
+    pub fn price_f64(&self) -> Result<f64, WebSocketError> {
+        parse_number("price", &self.price)
+    }
+
+    /// Size at this price level as a number
+    pub fn size_f64(&self) -> Result<f64, WebSocketError> {
+        parse_number("size", &self.size)
+    }
+}
+
 /// Book message - full order book snapshot
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BookMessage {
@@ -34,6 +51,13 @@ pub struct BookMessage {
     pub last_trade_price: Option<String>,
 }
 
+impl BookMessage {
+    /// Timestamp in milliseconds
+    pub fn timestamp_ms(&self) -> Result<i64, WebSocketError> {
+        parse_number("timestamp", &self.timestamp)
+    }
+}
+
 /// Price change entry
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PriceChange {
@@ -44,7 +68,7 @@ pub struct PriceChange {
     /// Size at this price level
     pub size: String,
     /// Order side (BUY or SELL)
-    pub side: String,
+    pub side: Side,
     /// Order book hash
     pub hash: String,
     /// Best bid price
@@ -53,6 +77,39 @@ pub struct PriceChange {
     pub best_ask: Option<String>,
 }
 
+impl PriceChange {
+    /// Order side parsed as [`OrderSide`], or `None` if unrecognized
+    pub fn side(&self) -> Option<OrderSide> {
+        self.side.order_side()
+    }
+
+    /// Price level as a number
+    pub fn price_f64(&self) -> Result<f64, WebSocketError> {
+        parse_number("price", &self.price)
+    }
+
+    /// Size at this price level as a number
+    pub fn size_f64(&self) -> Result<f64, WebSocketError> {
+        parse_number("size", &self.size)
+    }
+
+    /// Best bid price as a number, if reported
+    pub fn best_bid_f64(&self) -> Result<Option<f64>, WebSocketError> {
+        self.best_bid
+            .as_deref()
+            .map(|value| parse_number("best_bid", value))
+            .transpose()
+    }
+
+    /// Best ask price as a number, if reported
+    pub fn best_ask_f64(&self) -> Result<Option<f64>, WebSocketError> {
+        self.best_ask
+            .as_deref()
+            .map(|value| parse_number("best_ask", value))
+            .transpose()
+    }
+}
+
 /// Price change message - incremental order book update
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PriceChangeMessage {
@@ -66,6 +123,13 @@ pub struct PriceChangeMessage {
     pub timestamp: String,
 }
 
+impl PriceChangeMessage {
+    /// Timestamp in milliseconds
+    pub fn timestamp_ms(&self) -> Result<i64, WebSocketError> {
+        parse_number("timestamp", &self.timestamp)
+    }
+}
+
 /// Tick size change message
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TickSizeChangeMessage {
@@ -80,11 +144,23 @@ pub struct TickSizeChangeMessage {
     /// New tick size
     pub new_tick_size: String,
     /// Side (BUY or SELL)
-    pub side: String,
+    pub side: Side,
     /// Timestamp in milliseconds (as string)
     pub timestamp: String,
 }
 
+impl TickSizeChangeMessage {
+    /// Order side parsed as [`OrderSide`], or `None` if unrecognized
+    pub fn side(&self) -> Option<OrderSide> {
+        self.side.order_side()
+    }
+
+    /// Timestamp in milliseconds
+    pub fn timestamp_ms(&self) -> Result<i64, WebSocketError> {
+        parse_number("timestamp", &self.timestamp)
+    }
+}
+
 /// Last trade price message
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LastTradePriceMessage {
@@ -97,7 +173,7 @@ pub struct LastTradePriceMessage {
     /// Trade price
     pub price: String,
     /// Trade side (BUY or SELL)
-    pub side: String,
+    pub side: Side,
     /// Trade size
     pub size: String,
     /// Fee rate
@@ -106,6 +182,39 @@ pub struct LastTradePriceMessage {
     pub timestamp: String,
 }
 
+impl LastTradePriceMessage {
+    /// Trade side parsed as [`OrderSide`], or `None` if unrecognized
+    pub fn side(&self) -> Option<OrderSide> {
+        self.side.order_side()
+    }
+
+    /// Trade price as a number
+    pub fn price_f64(&self) -> Result<f64, WebSocketError> {
+        parse_number("price", &self.price)
+    }
+
+    /// Trade size as a number
+    pub fn size_f64(&self) -> Result<f64, WebSocketError> {
+        parse_number("size", &self.size)
+    }
+
+    /// Timestamp in milliseconds
+    pub fn timestamp_ms(&self) -> Result<i64, WebSocketError> {
+        parse_number("timestamp", &self.timestamp)
+    }
+}
+
+/// Parse a numeric string field, naming the field on failure
+fn parse_number<T: FromStr>(field: &'static str, value: &str) -> Result<T, WebSocketError> {
+    value
+        .trim()
+        .parse()
+        .map_err(|_| WebSocketError::InvalidNumber {
+            field,
+            value: value.to_string(),
+        })
+}
+
 /// Market channel message types
 #[derive(Debug, Clone, Serialize)]
 #[serde(untagged)]
@@ -150,3 +259,121 @@ impl MarketMessage {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_price_change_side() {
+        let change: PriceChange = serde_json::from_str(
+            r#"{"asset_id": "1", "price": "0.5", "size": "10", "side": "BUY", "hash": "0x"}"#,
+        )
+        .unwrap();
+        assert_eq!(change.side(), Some(OrderSide::Buy));
+    }
+
+    #[test]
+    fn test_last_trade_price_side() {
+        let msg = MarketMessage::from_json(
+            r#"{"event_type": "last_trade_price", "asset_id": "1", "market": "0x", "price": "0.5", "side": "sell", "size": "10", "timestamp": "0"}"#,
+        )
+        .unwrap();
+        let MarketMessage::LastTradePrice(trade) = msg else {
+            panic!("expected last trade price message");
+        };
+        assert_eq!(trade.side(), Some(OrderSide::Sell));
+    }
+
+    #[test]
+    fn test_tick_size_change_side() {
+        let msg = MarketMessage::from_json(
+            r#"{"event_type": "tick_size_change", "asset_id": "1", "market": "0x", "old_tick_size": "0.01", "new_tick_size": "0.001", "side": " Buy ", "timestamp": "0"}"#,
+        )
+        .unwrap();
+        let MarketMessage::TickSizeChange(change) = msg else {
+            panic!("expected tick size change message");
+        };
+        assert_eq!(change.side(), Some(OrderSide::Buy));
+    }
+
+    #[test]
+    fn test_unknown_side() {
+        let change: PriceChange = serde_json::from_str(
+            r#"{"asset_id": "1", "price": "0.5", "size": "10", "side": "HOLD", "hash": "0x"}"#,
+        )
+        .unwrap();
+        assert_eq!(change.side(), None);
+        assert_eq!(change.side, Side::Unknown("HOLD".to_string()));
+    }
+
+    #[test]
+    fn test_price_change_round_trip() {
+        let json =
+            r#"{"asset_id": "1", "price": "0.5", "size": "10", "side": "sell", "hash": "0x"}"#;
+        let change: PriceChange = serde_json::from_str(json).unwrap();
+        assert_eq!(change.side, Side::Sell);
+
+        let value = serde_json::to_value(&change).unwrap();
+        assert_eq!(value["side"], "SELL");
+        let change: PriceChange = serde_json::from_value(value).unwrap();
+        assert_eq!(change.side, Side::Sell);
+    }
+
+    #[test]
+    fn test_typed_accessors() {
+        let book = MarketMessage::from_json(
+            r#"[{"event_type": "book", "asset_id": "1", "market": "0x", "timestamp": "1700000000123", "hash": "0x", "bids": [{"price": "0.48", "size": "30.5"}], "asks": []}]"#,
+        )
+        .unwrap();
+        let MarketMessage::Book(book) = book else {
+            panic!("expected book message");
+        };
+        assert_eq!(book.timestamp_ms().unwrap(), 1_700_000_000_123);
+        assert_eq!(book.bids[0].price_f64().unwrap(), 0.48);
+        assert_eq!(book.bids[0].size_f64().unwrap(), 30.5);
+
+        let change: PriceChange = serde_json::from_str(
+            r#"{"asset_id": "1", "price": "0.5", "size": "10", "side": "BUY", "hash": "0x", "best_bid": "0.5"}"#,
+        )
+        .unwrap();
+        assert_eq!(change.price_f64().unwrap(), 0.5);
+        assert_eq!(change.size_f64().unwrap(), 10.0);
+        assert_eq!(change.best_bid_f64().unwrap(), Some(0.5));
+        assert_eq!(change.best_ask_f64().unwrap(), None);
+    }
+
+    #[test]
+    fn test_malformed_number() {
+        let level = OrderSummary {
+            price: "0.5x".to_string(),
+            size: "".to_string(),
+        };
+
+        let err = level.price_f64().unwrap_err();
+        assert!(matches!(
+            err,
+            WebSocketError::InvalidNumber { field: "price", ref value } if value == "0.5x"
+        ));
+        assert_eq!(err.to_string(), r#"Invalid price: "0.5x" is not a number"#);
+        assert!(matches!(
+            level.size_f64(),
+            Err(WebSocketError::InvalidNumber { field: "size", .. })
+        ));
+
+        let trade = MarketMessage::from_json(
+            r#"{"event_type": "last_trade_price", "asset_id": "1", "market": "0x", "price": "0.5", "side": "BUY", "size": "10", "timestamp": "soon"}"#,
+        )
+        .unwrap();
+        let MarketMessage::LastTradePrice(trade) = trade else {
+            panic!("expected last trade price message");
+        };
+        assert!(matches!(
+            trade.timestamp_ms(),
+            Err(WebSocketError::InvalidNumber {
+                field: "timestamp",
+                ..
+            })
+        ));
+    }
+}
@@ -89,7 +89,7 @@
 //!
 //! #[tokio::main]
 //! async fn main() -> Result<(), Box<dyn std::error::Error>> {
-//!     let ws = WebSocketBuilder::new()
+//!     let mut ws = WebSocketBuilder::new()
 //!         .ping_interval(Duration::from_secs(10))
 //!         .connect_market(vec!["asset_id".to_string()])
 //!         .await?;
@@ -104,20 +104,26 @@
 //! ```
 
 mod auth;
+mod book;
 mod client;
 mod error;
 mod market;
+mod sequence;
+mod side;
 mod subscription;
 mod user;
 
 pub use auth::ApiCredentials;
-pub use client::{WebSocket, WebSocketBuilder, WebSocketWithPing};
+pub use book::{BookDepth, LocalOrderBook, OrderBookState};
+pub use client::{WebSocket, WebSocketBuilder, WebSocketWithPing, DEFAULT_HEARTBEAT_MULTIPLIER};
 pub use error::WebSocketError;
 pub use market::{
     BookMessage, LastTradePriceMessage, MarketMessage, OrderSummary, PriceChange,
     PriceChangeMessage, TickSizeChangeMessage,
 };
-pub use subscription::ChannelType;
+pub use sequence::{Gap, SequenceEvent, SequenceTracker};
+pub use side::Side;
+pub use subscription::{ChannelType, MAX_ASSETS_PER_SUBSCRIPTION};
 pub use user::{MakerOrder, OrderEventType, OrderMessage, TradeMessage, TradeStatus, UserMessage};
 
 /// All possible WebSocket channel messages
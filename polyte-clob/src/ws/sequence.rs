@@ -0,0 +1,230 @@
+//! Gap detection on market channel timestamps.
+
+use std::{collections::HashMap, time::Duration};
+
+use futures_util::{stream, Stream, StreamExt};
+
+use super::{error::WebSocketError, market::MarketMessage, Channel};
+
+/// Suspiciously long silence between two updates of an asset
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Gap {
+    /// Asset ID (token ID)
+    pub asset_id: String,
+    /// Timestamp of the previous update, in milliseconds
+    pub last_ts: i64,
+    /// Timestamp of the update that ended the silence, in milliseconds
+    pub now_ts: i64,
+}
+
+/// Item of a stream watched by [`SequenceTracker::watch`]
+#[derive(Debug, Clone)]
+pub enum SequenceEvent {
+    /// Message received from the channel
+    Message(Box<Channel>),
+    /// Possible dropped messages, reported before the message revealing it
+    Gap(Gap),
+}
+
+/// Tracks market message timestamps per asset to spot dropped messages.
+///
+/// The market channel has no sequence numbers, so a gap between two
+/// consecutive updates of an asset larger than `max_gap` is treated as a
+/// possible drop; a REST snapshot is the usual way to resync.
+/// Out-of-order timestamps are ignored.
+#[derive(Debug, Clone)]
+pub struct SequenceTracker {
+    max_gap_ms: i64,
+    last: HashMap<String, i64>,
+}
+
+impl SequenceTracker {
+    /// Create a tracker flagging gaps longer than `max_gap`
+    pub fn new(max_gap: Duration) -> Self {
+        Self {
+            max_gap_ms: i64::try_from(max_gap.as_millis()).unwrap_or(i64::MAX),
+            last: HashMap::new(),
+        }
+    }
+
+    /// Record an update of `asset_id` at `timestamp_ms`
+    pub fn observe(&mut self, asset_id: &str, timestamp_ms: i64) -> Option<Gap> {
+        let Some(last) = self.last.get_mut(asset_id) else {
+            self.last.insert(asset_id.to_string(), timestamp_ms);
+            return None;
+        };
+        if timestamp_ms <= *last {
+            return None;
+        }
+
+        let last_ts = std::mem::replace(last, timestamp_ms);
+        (timestamp_ms - last_ts > self.max_gap_ms).then(|| Gap {
+            asset_id: asset_id.to_string(),
+            last_ts,
+            now_ts: timestamp_ms,
+        })
+    }
+
+    /// Record every asset updated by a market message.
+    ///
+    /// Messages with a malformed timestamp are skipped.
+    pub fn observe_message(&mut self, message: &MarketMessage) -> Vec<Gap> {
+        let (timestamp, mut assets) = match message {
+            MarketMessage::Book(book) => (book.timestamp_ms(), vec![book.asset_id.as_str()]),
+            MarketMessage::PriceChange(change) => (
+                change.timestamp_ms(),
+                change
+                    .price_changes
+                    .iter()
+                    .map(|c| c.asset_id.as_str())
+                    .collect(),
+            ),
+            MarketMessage::TickSizeChange(change) => {
+                (change.timestamp_ms(), vec![change.asset_id.as_str()])
+            }
+            MarketMessage::LastTradePrice(trade) => {
+                (trade.timestamp_ms(), vec![trade.asset_id.as_str()])
+            }
+        };
+        let Ok(timestamp) = timestamp else {
+            return Vec::new();
+        };
+
+        assets.sort_unstable();
+        assets.dedup();
+        assets
+            .into_iter()
+            .filter_map(|asset_id| self.observe(asset_id, timestamp))
+            .collect()
+    }
+
+    /// Timestamp of the last update seen for `asset_id`
+    pub fn last_timestamp(&self, asset_id: &str) -> Option<i64> {
+        self.last.get(asset_id).copied()
+    }
+
+    /// Watch a channel stream, interleaving [`SequenceEvent::Gap`] events
+    /// with its messages.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    ///
+    /// use futures_util::StreamExt;
+    /// use polyte_clob::ws::{SequenceEvent, SequenceTracker, WebSocket};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let ws = WebSocket::connect_market(vec!["asset_id".to_string()]).await?;
+    ///     let mut events = SequenceTracker::new(Duration::from_secs(30)).watch(ws);
+    ///
+    ///     while let Some(event) = events.next().await {
+    ///         if let SequenceEvent::Gap(gap) = event? {
+    ///             println!("Possible drop on {}, resyncing", gap.asset_id);
+    ///         }
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn watch<S>(
+        mut self,
+        messages: S,
+    ) -> impl Stream<Item = Result<SequenceEvent, WebSocketError>>
+    where
+        S: Stream<Item = Result<Channel, WebSocketError>>,
+    {
+        messages
+            .map(move |item| {
+                let mut events: Vec<_> = match &item {
+                    Ok(Channel::Market(message)) => self
+                        .observe_message(message)
+                        .into_iter()
+                        .map(|gap| Ok(SequenceEvent::Gap(gap)))
+                        .collect(),
+                    _ => Vec::new(),
+                };
+                events.push(item.map(|message| SequenceEvent::Message(Box::new(message))));
+                stream::iter(events)
+            })
+            .flatten()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ws::{market::LastTradePriceMessage, side::Side};
+
+    fn trade(asset_id: &str, timestamp: i64) -> Result<Channel, WebSocketError> {
+        Ok(Channel::Market(MarketMessage::LastTradePrice(
+            LastTradePriceMessage {
+                event_type: "last_trade_price".to_string(),
+                asset_id: asset_id.to_string(),
+                market: "0xabc".to_string(),
+                price: "0.5".to_string(),
+                side: Side::Buy,
+                size: "10".to_string(),
+                fee_rate_bps: None,
+                timestamp: timestamp.to_string(),
+            },
+        )))
+    }
+
+    #[test]
+    fn test_observe() {
+        let mut tracker = SequenceTracker::new(Duration::from_secs(5));
+        assert_eq!(tracker.observe("1", 1_000), None);
+        assert_eq!(tracker.observe("1", 4_000), None);
+        // Out of order updates are ignored
+        assert_eq!(tracker.observe("1", 2_000), None);
+        assert_eq!(
+            tracker.observe("1", 10_000),
+            Some(Gap {
+                asset_id: "1".to_string(),
+                last_ts: 4_000,
+                now_ts: 10_000,
+            })
+        );
+        assert_eq!(tracker.last_timestamp("1"), Some(10_000));
+    }
+
+    #[tokio::test]
+    async fn test_watch_interleaves_gaps() {
+        let messages = stream::iter(vec![
+            trade("1", 1_000),
+            trade("2", 1_200),
+            trade("1", 1_500),
+            trade("2", 2_000),
+            trade("1", 9_000),
+        ]);
+
+        let events: Vec<_> = SequenceTracker::new(Duration::from_secs(5))
+            .watch(messages)
+            .collect()
+            .await;
+
+        assert_eq!(events.len(), 6);
+        let gaps: Vec<(usize, &Gap)> = events
+            .iter()
+            .enumerate()
+            .filter_map(|(i, event)| match event {
+                Ok(SequenceEvent::Gap(gap)) => Some((i, gap)),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            gaps,
+            vec![(
+                4,
+                &Gap {
+                    asset_id: "1".to_string(),
+                    last_ts: 1_500,
+                    now_ts: 9_000,
+                }
+            )]
+        );
+        // The message that revealed the gap follows it
+        assert!(matches!(events[5], Ok(SequenceEvent::Message(_))));
+    }
+}
@@ -0,0 +1,109 @@
+//! Order side as sent on WebSocket channels.
+
+use std::fmt;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::types::OrderSide;
+
+/// Order side of a WebSocket message.
+///
+/// Deserializes "BUY" and "SELL" ignoring case; any other value is kept as
+/// [`Side::Unknown`] instead of failing, so a new server value does not
+/// break the stream. Serializes back to the uppercase form, or to the
+/// original string for unknown values.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Side {
+    /// Buy side
+    Buy,
+    /// Sell side
+    Sell,
+    /// Value not recognized by this client, as received
+    Unknown(String),
+}
+
+impl Side {
+    /// Side as an [`OrderSide`], or `None` if unknown
+    pub fn order_side(&self) -> Option<OrderSide> {
+        match self {
+            Self::Buy => Some(OrderSide::Buy),
+            Self::Sell => Some(OrderSide::Sell),
+            Self::Unknown(_) => None,
+        }
+    }
+
+    /// Wire representation of the side
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Buy => "BUY",
+            Self::Sell => "SELL",
+            Self::Unknown(side) => side,
+        }
+    }
+}
+
+impl From<OrderSide> for Side {
+    fn from(side: OrderSide) -> Self {
+        match side {
+            OrderSide::Buy => Self::Buy,
+            OrderSide::Sell => Self::Sell,
+        }
+    }
+}
+
+impl From<&str> for Side {
+    fn from(side: &str) -> Self {
+        match side.parse::<OrderSide>() {
+            Ok(side) => Self::from(side),
+            Err(_) => Self::Unknown(side.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for Side {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for Side {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for Side {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let side = String::deserialize(deserializer)?;
+        Ok(Self::from(side.as_str()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize() {
+        let sides: Vec<Side> = serde_json::from_str(r#"["BUY", "SELL", "buy", " Sell "]"#).unwrap();
+        assert_eq!(sides, vec![Side::Buy, Side::Sell, Side::Buy, Side::Sell]);
+    }
+
+    #[test]
+    fn test_unknown_preserved() {
+        let side: Side = serde_json::from_str(r#""HOLD""#).unwrap();
+        assert_eq!(side, Side::Unknown("HOLD".to_string()));
+        assert_eq!(side.order_side(), None);
+        assert_eq!(serde_json::to_string(&side).unwrap(), r#""HOLD""#);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for side in [Side::Buy, Side::Sell, Side::Unknown("CROSS".to_string())] {
+            let json = serde_json::to_string(&side).unwrap();
+            assert_eq!(serde_json::from_str::<Side>(&json).unwrap(), side);
+        }
+        assert_eq!(serde_json::to_string(&Side::Buy).unwrap(), r#""BUY""#);
+        assert_eq!(serde_json::to_string(&Side::Sell).unwrap(), r#""SELL""#);
+    }
+}
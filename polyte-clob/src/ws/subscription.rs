@@ -10,6 +10,12 @@ pub const WS_MARKET_URL: &str = "wss://ws-subscriptions-clob.polymarket.com/ws/m
 /// WebSocket endpoint URL for user channel
 pub const WS_USER_URL: &str = "wss://ws-subscriptions-clob.polymarket.com/ws/user";
 
+/// Maximum number of asset IDs per market channel subscription.
+///
+/// The server silently drops assets past this cap, so larger subscriptions
+/// are split across several connections.
+pub const MAX_ASSETS_PER_SUBSCRIPTION: usize = 500;
+
 /// Channel type for WebSocket subscription
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -28,6 +34,10 @@ pub struct MarketSubscription {
     /// Channel type (always "market")
     #[serde(rename = "type")]
     pub channel_type: ChannelType,
+    /// Operation on an open connection ("subscribe"), absent for the
+    /// initial subscription
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub operation: Option<String>,
 }
 
 impl MarketSubscription {
@@ -36,8 +46,30 @@ impl MarketSubscription {
         Self {
             assets_ids,
             channel_type: ChannelType::Market,
+            operation: None,
+        }
+    }
+
+    /// Subscribe an open connection to `assets_ids`, which makes the server
+    /// send a fresh `book` snapshot for each
+    pub fn subscribe(assets_ids: Vec<String>) -> Self {
+        Self {
+            operation: Some("subscribe".to_string()),
+            ..Self::new(assets_ids)
         }
     }
+
+    /// Split asset IDs into subscriptions of at most `max_assets` assets each
+    pub fn chunked(assets_ids: Vec<String>, max_assets: usize) -> Vec<Self> {
+        if assets_ids.is_empty() {
+            return vec![Self::new(assets_ids)];
+        }
+
+        assets_ids
+            .chunks(max_assets.max(1))
+            .map(|chunk| Self::new(chunk.to_vec()))
+            .collect()
+    }
 }
 
 /// Subscription message for user channel
@@ -62,3 +94,44 @@ impl UserSubscription {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assets(count: usize) -> Vec<String> {
+        (0..count).map(|i| i.to_string()).collect()
+    }
+
+    #[test]
+    fn test_chunked_within_cap() {
+        let subscriptions = MarketSubscription::chunked(assets(3), 5);
+        assert_eq!(subscriptions.len(), 1);
+        assert_eq!(subscriptions[0].assets_ids, assets(3));
+    }
+
+    #[test]
+    fn test_chunked_over_cap() {
+        let subscriptions = MarketSubscription::chunked(assets(7), 3);
+        assert_eq!(subscriptions.len(), 3);
+        assert!(subscriptions.iter().all(|s| s.assets_ids.len() <= 3));
+
+        let covered: Vec<String> = subscriptions
+            .into_iter()
+            .flat_map(|s| s.assets_ids)
+            .collect();
+        assert_eq!(covered, assets(7));
+    }
+
+    #[test]
+    fn test_subscribe_operation() {
+        assert_eq!(
+            serde_json::to_value(MarketSubscription::new(assets(1))).unwrap(),
+            serde_json::json!({"assets_ids": ["0"], "type": "market"})
+        );
+        assert_eq!(
+            serde_json::to_value(MarketSubscription::subscribe(assets(1))).unwrap(),
+            serde_json::json!({"assets_ids": ["0"], "type": "market", "operation": "subscribe"})
+        );
+    }
+}
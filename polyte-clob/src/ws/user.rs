@@ -4,6 +4,9 @@
 
 use serde::{Deserialize, Serialize};
 
+use super::side::Side;
+use crate::types::OrderSide;
+
 /// Maker order in a trade
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MakerOrder {
@@ -55,7 +58,7 @@ pub struct TradeMessage {
     /// Trade size
     pub size: String,
     /// Trade side (BUY or SELL)
-    pub side: String,
+    pub side: Side,
     /// Trade status
     pub status: TradeStatus,
     /// Taker order ID
@@ -70,6 +73,13 @@ pub struct TradeMessage {
     pub timestamp: String,
 }
 
+impl TradeMessage {
+    /// Trade side parsed as [`OrderSide`], or `None` if unrecognized
+    pub fn side(&self) -> Option<OrderSide> {
+        self.side.order_side()
+    }
+}
+
 /// Order event type
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "UPPERCASE")]
@@ -98,7 +108,7 @@ pub struct OrderMessage {
     /// Order price
     pub price: String,
     /// Order side (BUY or SELL)
-    pub side: String,
+    pub side: Side,
     /// Original order size
     pub original_size: String,
     /// Size matched so far
@@ -112,6 +122,13 @@ pub struct OrderMessage {
     pub timestamp: String,
 }
 
+impl OrderMessage {
+    /// Order side parsed as [`OrderSide`], or `None` if unrecognized
+    pub fn side(&self) -> Option<OrderSide> {
+        self.side.order_side()
+    }
+}
+
 /// User channel message types
 #[derive(Debug, Clone, Serialize)]
 #[serde(untagged)]
@@ -141,3 +158,47 @@ impl UserMessage {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trade_side() {
+        let trade: TradeMessage = serde_json::from_value(serde_json::json!({
+            "event_type": "trade",
+            "id": "1",
+            "asset_id": "1",
+            "market": "0x",
+            "outcome": "YES",
+            "price": "0.5",
+            "size": "10",
+            "side": "Sell",
+            "status": "MATCHED",
+            "taker_order_id": "0x",
+            "maker_orders": [],
+            "timestamp": "0"
+        }))
+        .unwrap();
+        assert_eq!(trade.side(), Some(OrderSide::Sell));
+    }
+
+    #[test]
+    fn test_order_side() {
+        let order: OrderMessage = serde_json::from_value(serde_json::json!({
+            "event_type": "order",
+            "id": "1",
+            "asset_id": "1",
+            "market": "0x",
+            "outcome": "YES",
+            "price": "0.5",
+            "side": "buy",
+            "original_size": "10",
+            "size_matched": "0",
+            "type": "PLACEMENT",
+            "timestamp": "0"
+        }))
+        .unwrap();
+        assert_eq!(order.side(), Some(OrderSide::Buy));
+    }
+}
@@ -0,0 +1,134 @@
+//! Deserialization helpers shared by API types.
+
+use serde::{de::Error, Deserialize, Deserializer};
+
+/// Deserialize an optional count or ID, mapping sentinel values to `None`.
+///
+/// Some endpoints report missing counts and IDs as `-1` or `u64::MAX`
+/// (`18446744073709551615`) instead of omitting them. Those values, and any
+/// value that doesn't fit in `T`, become `None`.
+///
+/// Use with `#[serde(default, deserialize_with = "...")]` so missing fields
+/// also deserialize to `None`.
+pub fn sentinel_as_none<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: TryFrom<i64> + TryFrom<u64>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Number {
+        Unsigned(u64),
+        Signed(i64),
+    }
+
+    let value = match Option::<Number>::deserialize(deserializer)? {
+        None | Some(Number::Unsigned(u64::MAX)) | Some(Number::Signed(-1)) => None,
+        Some(Number::Unsigned(value)) => T::try_from(value).ok(),
+        Some(Number::Signed(value)) => T::try_from(value).ok(),
+    };
+
+    Ok(value)
+}
+
+/// Deserialize an optional number sent either bare (`123.4`) or quoted
+/// (`"123.4"`).
+///
+/// Null and empty strings become `None`; other strings that don't parse as a
+/// number are an error. Use with `#[serde(default, deserialize_with = "...")]`
+/// so missing fields also deserialize to `None`.
+pub fn string_or_number<'de, D>(deserializer: D) -> Result<Option<f64>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Number {
+        Number(f64),
+        String(String),
+    }
+
+    match Option::<Number>::deserialize(deserializer)? {
+        None => Ok(None),
+        Some(Number::Number(value)) => Ok(Some(value)),
+        Some(Number::String(value)) if value.trim().is_empty() => Ok(None),
+        Some(Number::String(value)) => value
+            .trim()
+            .parse()
+            .map(Some)
+            .map_err(|_| D::Error::custom(format!("invalid number: {:?}", value))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+
+    #[derive(Debug, Deserialize)]
+    struct Counts {
+        #[serde(default, deserialize_with = "super::sentinel_as_none")]
+        signed: Option<i64>,
+        #[serde(default, deserialize_with = "super::sentinel_as_none")]
+        unsigned: Option<u64>,
+    }
+
+    fn parse(json: &str) -> Counts {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn test_regular_values() {
+        let counts = parse(r#"{"signed": 42, "unsigned": 7}"#);
+        assert_eq!(counts.signed, Some(42));
+        assert_eq!(counts.unsigned, Some(7));
+    }
+
+    #[test]
+    fn test_negative_one_is_none() {
+        let counts = parse(r#"{"signed": -1, "unsigned": -1}"#);
+        assert_eq!(counts.signed, None);
+        assert_eq!(counts.unsigned, None);
+    }
+
+    #[test]
+    fn test_u64_max_is_none() {
+        let counts = parse(r#"{"signed": 18446744073709551615, "unsigned": 18446744073709551615}"#);
+        assert_eq!(counts.signed, None);
+        assert_eq!(counts.unsigned, None);
+    }
+
+    #[test]
+    fn test_null_and_missing_are_none() {
+        let counts = parse(r#"{"signed": null}"#);
+        assert_eq!(counts.signed, None);
+        assert_eq!(counts.unsigned, None);
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct Amounts {
+        #[serde(default, deserialize_with = "super::string_or_number")]
+        volume: Option<f64>,
+    }
+
+    fn amounts(json: &str) -> Result<Amounts, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    #[test]
+    fn test_string_or_number() {
+        assert_eq!(amounts(r#"{"volume": 123.4}"#).unwrap().volume, Some(123.4));
+        assert_eq!(
+            amounts(r#"{"volume": "123.4"}"#).unwrap().volume,
+            Some(123.4)
+        );
+        assert_eq!(amounts(r#"{"volume": 7}"#).unwrap().volume, Some(7.0));
+    }
+
+    #[test]
+    fn test_string_or_number_missing() {
+        assert_eq!(amounts(r#"{}"#).unwrap().volume, None);
+        assert_eq!(amounts(r#"{"volume": null}"#).unwrap().volume, None);
+        assert_eq!(amounts(r#"{"volume": ""}"#).unwrap().volume, None);
+        assert!(amounts(r#"{"volume": "lots"}"#).is_err());
+    }
+}
@@ -3,19 +3,26 @@ use thiserror::Error;
 /// Core API error types shared across Polyte clients
 #[derive(Error, Debug)]
 pub enum ApiError {
-    /// HTTP request failed
+    /// Server answered with a non-success status
     #[error("API error: {status} - {message}")]
-    Api { status: u16, message: String },
+    Api {
+        status: u16,
+        /// Error code from the response body, if any
+        code: Option<String>,
+        message: String,
+        /// Raw response body
+        raw: String,
+    },
 
-    /// Authentication failed (401/403)
+    /// Authentication failed before the request was sent
     #[error("Authentication failed: {0}")]
     Authentication(String),
 
-    /// Request validation failed (400)
+    /// Request validation failed before it was sent
     #[error("Validation error: {0}")]
     Validation(String),
 
-    /// Rate limit exceeded (429)
+    /// Rate limit exceeded
     #[error("Rate limit exceeded")]
     RateLimit,
 
@@ -34,31 +41,137 @@ pub enum ApiError {
     /// URL parsing error
     #[error("URL error: {0}")]
     Url(#[from] url::ParseError),
+
+    /// Response body exceeded the configured size limit
+    #[error("Response body exceeds limit of {limit} bytes")]
+    ResponseTooLarge { limit: usize },
 }
 
 impl ApiError {
-    /// Create error from HTTP response
+    /// Create error from a non-success HTTP response.
+    ///
+    /// Every status maps to [`ApiError::Api`] so the status, error code and
+    /// raw body stay available to callers, including for 4xx rejections.
     pub async fn from_response(response: reqwest::Response) -> Self {
         let status = response.status().as_u16();
+        let raw = response.text().await.unwrap_or_default();
+        let (code, message) = parse_error_body(&raw);
+
+        Self::Api {
+            status,
+            code,
+            message,
+            raw,
+        }
+    }
+
+    /// HTTP status of the failed response, when known
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            Self::Api { status, .. } => Some(*status),
+            Self::RateLimit => Some(429),
+            Self::Timeout => Some(408),
+            Self::Network(err) => err.status().map(|status| status.as_u16()),
+            _ => None,
+        }
+    }
+
+    /// Error code from the response body, if any
+    pub fn code(&self) -> Option<&str> {
+        match self {
+            Self::Api { code, .. } => code.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Whether the server rejected the request for exceeding its rate limit
+    pub fn is_rate_limited(&self) -> bool {
+        self.status() == Some(429)
+    }
+}
+
+/// Extract the error code and message from an error response body.
+///
+/// Polymarket answers with JSON like `{"error": "...", "code": "..."}`; other
+/// bodies are used verbatim as the message.
+fn parse_error_body(raw: &str) -> (Option<String>, String) {
+    let Ok(serde_json::Value::Object(body)) = serde_json::from_str(raw) else {
+        let message = match raw.trim() {
+            "" => "Unknown error",
+            text => text,
+        };
+        return (None, message.to_string());
+    };
+
+    let code = body.get("code").and_then(|code| match code {
+        serde_json::Value::String(code) => Some(code.clone()),
+        serde_json::Value::Number(code) => Some(code.to_string()),
+        _ => None,
+    });
+    let message = ["error", "message", "errorMsg"]
+        .iter()
+        .find_map(|key| body.get(*key)?.as_str())
+        .unwrap_or("Unknown error")
+        .to_string();
+
+    (code, message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error_body() {
+        assert_eq!(
+            parse_error_body(r#"{"error": "order not found", "code": "NOT_FOUND"}"#),
+            (Some("NOT_FOUND".to_string()), "order not found".to_string())
+        );
+        assert_eq!(
+            parse_error_body(r#"{"message": "bad gateway", "code": 502}"#),
+            (Some("502".to_string()), "bad gateway".to_string())
+        );
+        assert_eq!(
+            parse_error_body(r#"{"detail": "?"}"#),
+            (None, "Unknown error".to_string())
+        );
+        assert_eq!(
+            parse_error_body("upstream timed out\n"),
+            (None, "upstream timed out".to_string())
+        );
+        assert_eq!(parse_error_body(""), (None, "Unknown error".to_string()));
+    }
+
+    #[test]
+    fn test_status_helpers() {
+        let err = ApiError::Api {
+            status: 503,
+            code: Some("UNAVAILABLE".to_string()),
+            message: "try later".to_string(),
+            raw: String::new(),
+        };
+        assert_eq!(err.status(), Some(503));
+        assert_eq!(err.code(), Some("UNAVAILABLE"));
+        assert!(!err.is_rate_limited());
+
+        assert!(ApiError::RateLimit.is_rate_limited());
+        assert_eq!(ApiError::Validation("bad".to_string()).status(), None);
+    }
+
+    #[tokio::test]
+    async fn test_from_response_keeps_rejection_details() {
+        for status in [400, 401, 403, 429] {
+            let raw = r#"{"error": "rejected", "code": "REJECTED"}"#;
+            let response = http::Response::builder().status(status).body(raw).unwrap();
 
-        let message = response
-            .json::<serde_json::Value>()
-            .await
-            .ok()
-            .and_then(|v| {
-                v.get("error")
-                    .or(v.get("message"))
-                    .and_then(|m| m.as_str())
-                    .map(String::from)
-            })
-            .unwrap_or_else(|| "Unknown error".to_string());
-
-        match status {
-            401 | 403 => Self::Authentication(message),
-            400 => Self::Validation(message),
-            429 => Self::RateLimit,
-            408 => Self::Timeout,
-            _ => Self::Api { status, message },
+            let err = ApiError::from_response(response.into()).await;
+            assert_eq!(err.status(), Some(status));
+            assert_eq!(err.code(), Some("REJECTED"));
+            assert!(matches!(
+                err,
+                ApiError::Api { ref message, raw: ref body, .. }
+                    if message == "rejected" && body == raw
+            ));
         }
     }
 }
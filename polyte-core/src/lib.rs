@@ -5,7 +5,12 @@
 //! This crate provides common functionality used across `polyte-clob`, `polyte-gamma`, and `polyte-data`:
 //! - Shared error types and error handling
 //! - HTTP client configuration
-//! - Request builder utilities
+//! - Request builder utilities and a shared send path with retries
+//! - Offset pagination of list endpoints as a stream
+//! - Rate limit header tracking
+//! - A pluggable [`HttpTransport`] for testing without a network, with an
+//!   in-memory mock under the `test-util` feature
+//! - Deserialization helpers for sentinel values and numbers sent as strings
 //!
 //! ## HTTP Client
 //!
@@ -25,9 +30,26 @@
 //! Use the [`impl_api_error_conversions`] macro to reduce boilerplate in error types.
 
 pub mod client;
+pub mod de;
 pub mod error;
+pub mod pagination;
+pub mod rate_limit;
 pub mod request;
+pub mod retry;
+#[cfg(any(test, feature = "test-util"))]
+pub mod testing;
+pub mod transport;
 
-pub use client::{HttpClient, HttpClientBuilder, DEFAULT_POOL_SIZE, DEFAULT_TIMEOUT_MS};
+pub use client::{
+    HttpClient, HttpClientBuilder, DEFAULT_POOL_SIZE, DEFAULT_TIMEOUT_MS, DEFAULT_USER_AGENT,
+};
 pub use error::ApiError;
-pub use request::{QueryBuilder, Request, RequestError};
+pub use pagination::paginate;
+pub use rate_limit::{RateLimit, RateLimitTracker};
+pub use request::{
+    body_snippet, read_body, QueryBuilder, Request, RequestError, ResponseMeta, DECODE_BODY_LIMIT,
+};
+pub use retry::{RetryPolicy, DEFAULT_MAX_RETRIES};
+pub use transport::{
+    FailoverTransport, HttpTransport, ReqwestTransport, TransportFuture, FAILOVER_COOLDOWN,
+};
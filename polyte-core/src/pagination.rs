@@ -0,0 +1,142 @@
+use futures_util::{stream, Stream, TryStreamExt};
+use serde::de::DeserializeOwned;
+
+use crate::request::{Request, RequestError};
+
+/// Walk an offset-paginated list endpoint page by page.
+///
+/// The request's `limit` is used as the page size (`default_page_size` if
+/// unset or zero) and its `offset` as the starting point, keeping all other
+/// query parameters. Each page advances the offset by the page size. The
+/// stream ends after the first page with fewer results than the page size,
+/// after the first error, or once the next offset would pass `max_offset`.
+pub fn paginate<T, E>(
+    request: Request<Vec<T>, E>,
+    default_page_size: u32,
+    max_offset: u32,
+) -> impl Stream<Item = Result<T, E>> + Send + Unpin
+where
+    T: DeserializeOwned + Send + 'static,
+    E: RequestError + Send + 'static,
+{
+    let page_size = request
+        .query_value::<u32>("limit")
+        .filter(|limit| *limit > 0)
+        .unwrap_or(default_page_size);
+    let start = request.query_value::<u32>("offset").unwrap_or(0);
+
+    let pages = stream::try_unfold(Some(start), move |offset| {
+        let request = request.clone();
+        async move {
+            let Some(offset) = offset else {
+                return Ok::<_, E>(None);
+            };
+
+            let page = request
+                .set_query("limit", page_size)
+                .set_query("offset", offset)
+                .send()
+                .await?;
+            let next = offset
+                .checked_add(page_size)
+                .filter(|next| page.len() as u32 >= page_size && *next <= max_offset);
+
+            Ok(Some((stream::iter(page.into_iter().map(Ok)), next)))
+        }
+    });
+
+    Box::pin(pages.try_flatten())
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::Client;
+    use url::Url;
+
+    use super::*;
+    use crate::{
+        testing::{MockResponse, MockTransport, TestError},
+        QueryBuilder,
+    };
+
+    /// Serve `total` items numbered from zero, honouring limit and offset
+    fn numbered(total: u32) -> std::sync::Arc<MockTransport> {
+        MockTransport::new(move |request| {
+            let limit: u32 = request.query("limit").unwrap().parse().unwrap();
+            let offset: u32 = request.query("offset").unwrap().parse().unwrap();
+            let items: Vec<u32> = (offset..total.min(offset + limit)).collect();
+            MockResponse::json(&items)
+        })
+    }
+
+    fn request(transport: std::sync::Arc<MockTransport>) -> Request<Vec<u32>, TestError> {
+        let base_url = Url::parse("https://api.example.com").unwrap();
+        Request::new(Client::new(), base_url, "/items")
+            .query("active", true)
+            .transport(transport)
+    }
+
+    #[tokio::test]
+    async fn test_paginate_stops_on_short_page() {
+        let transport = numbered(5);
+
+        let items: Vec<u32> = paginate(request(transport.clone()).query("limit", 2), 100, 1000)
+            .try_collect()
+            .await
+            .unwrap();
+
+        assert_eq!(items, [0, 1, 2, 3, 4]);
+        let offsets: Vec<String> = transport
+            .requests()
+            .iter()
+            .map(|request| request.query("offset").unwrap())
+            .collect();
+        assert_eq!(offsets, ["0", "2", "4"]);
+        // Other parameters are kept on every page
+        assert!(transport
+            .requests()
+            .iter()
+            .all(|request| request.query("active").as_deref() == Some("true")));
+    }
+
+    #[tokio::test]
+    async fn test_paginate_uses_default_page_size_and_offset() {
+        let transport = numbered(10);
+
+        let items: Vec<u32> = paginate(request(transport.clone()).query("offset", 4), 3, 1000)
+            .try_collect()
+            .await
+            .unwrap();
+
+        assert_eq!(items, [4, 5, 6, 7, 8, 9]);
+        assert_eq!(transport.requests()[0].query("limit").as_deref(), Some("3"));
+    }
+
+    #[tokio::test]
+    async fn test_paginate_respects_max_offset() {
+        let transport = numbered(100);
+
+        let items: Vec<u32> = paginate(request(transport.clone()), 10, 20)
+            .try_collect()
+            .await
+            .unwrap();
+
+        assert_eq!(items, (0..30).collect::<Vec<_>>());
+        assert_eq!(transport.request_count(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_paginate_stops_on_error() {
+        let transport = MockTransport::sequence([
+            MockResponse::json(&[1, 2]),
+            MockResponse::status(400, r#"{"error": "bad offset"}"#),
+        ]);
+
+        let mut pages = paginate(request(transport.clone()), 2, 1000);
+        assert_eq!(pages.try_next().await.unwrap(), Some(1));
+        assert_eq!(pages.try_next().await.unwrap(), Some(2));
+        assert!(pages.try_next().await.is_err());
+        assert_eq!(pages.try_next().await.unwrap(), None);
+        assert_eq!(transport.request_count(), 2);
+    }
+}
@@ -0,0 +1,149 @@
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use reqwest::header::HeaderMap;
+
+/// Header carrying the request budget for the current window
+pub const RATE_LIMIT_LIMIT_HEADER: &str = "x-ratelimit-limit";
+/// Header carrying the requests remaining in the current window
+pub const RATE_LIMIT_REMAINING_HEADER: &str = "x-ratelimit-remaining";
+/// Header carrying the time until the current window resets
+pub const RATE_LIMIT_RESET_HEADER: &str = "x-ratelimit-reset";
+
+/// Rate limit budget reported by the API via `x-ratelimit-*` headers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimit {
+    /// Maximum number of requests in the current window
+    pub limit: Option<u64>,
+    /// Requests remaining in the current window
+    pub remaining: Option<u64>,
+    /// Time until the window resets, as reported by the server
+    pub reset: Option<u64>,
+}
+
+impl RateLimit {
+    /// Parse rate limit headers, returning `None` if none are present
+    pub fn from_headers(headers: &HeaderMap) -> Option<Self> {
+        let parse = |name: &str| {
+            headers
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.trim().parse::<u64>().ok())
+        };
+
+        let rate_limit = Self {
+            limit: parse(RATE_LIMIT_LIMIT_HEADER),
+            remaining: parse(RATE_LIMIT_REMAINING_HEADER),
+            reset: parse(RATE_LIMIT_RESET_HEADER),
+        };
+
+        if rate_limit.limit.is_none()
+            && rate_limit.remaining.is_none()
+            && rate_limit.reset.is_none()
+        {
+            return None;
+        }
+
+        Some(rate_limit)
+    }
+
+    /// How long to wait before the next request once the budget is spent.
+    ///
+    /// Returns `None` while requests remain. When the server does not report
+    /// a reset time, one second is assumed.
+    pub fn wait_time(&self) -> Option<Duration> {
+        (self.remaining == Some(0)).then(|| Duration::from_secs(self.reset.unwrap_or(1)))
+    }
+}
+
+/// Shared record of the most recent rate limit headers seen by a client.
+///
+/// Cloning the tracker shares the underlying state, so every namespace of a
+/// client reports into the same slot.
+#[derive(Debug, Clone, Default)]
+pub struct RateLimitTracker {
+    last: Arc<Mutex<Option<RateLimit>>>,
+}
+
+impl RateLimitTracker {
+    /// Create an empty tracker
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record the rate limit headers of a response, if present
+    pub fn record(&self, headers: &HeaderMap) {
+        if let Some(rate_limit) = RateLimit::from_headers(headers) {
+            if let Ok(mut last) = self.last.lock() {
+                *last = Some(rate_limit);
+            }
+        }
+    }
+
+    /// Rate limit reported by the most recent response that carried one
+    pub fn last(&self) -> Option<RateLimit> {
+        self.last.lock().ok().and_then(|last| *last)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::header::HeaderValue;
+
+    use super::*;
+
+    #[test]
+    fn test_from_headers() {
+        let mut headers = HeaderMap::new();
+        headers.insert(RATE_LIMIT_LIMIT_HEADER, HeaderValue::from_static("100"));
+        headers.insert(RATE_LIMIT_REMAINING_HEADER, HeaderValue::from_static("42"));
+        headers.insert(RATE_LIMIT_RESET_HEADER, HeaderValue::from_static("10"));
+
+        assert_eq!(
+            RateLimit::from_headers(&headers),
+            Some(RateLimit {
+                limit: Some(100),
+                remaining: Some(42),
+                reset: Some(10),
+            })
+        );
+    }
+
+    #[test]
+    fn test_from_headers_missing() {
+        assert_eq!(RateLimit::from_headers(&HeaderMap::new()), None);
+    }
+
+    #[test]
+    fn test_wait_time() {
+        let mut rate_limit = RateLimit {
+            limit: Some(100),
+            remaining: Some(3),
+            reset: Some(10),
+        };
+        assert_eq!(rate_limit.wait_time(), None);
+
+        rate_limit.remaining = Some(0);
+        assert_eq!(rate_limit.wait_time(), Some(Duration::from_secs(10)));
+
+        rate_limit.reset = None;
+        assert_eq!(rate_limit.wait_time(), Some(Duration::from_secs(1)));
+    }
+
+    #[test]
+    fn test_tracker_keeps_last_seen() {
+        let tracker = RateLimitTracker::new();
+        let shared = tracker.clone();
+
+        let mut headers = HeaderMap::new();
+        headers.insert(RATE_LIMIT_REMAINING_HEADER, HeaderValue::from_static("5"));
+        tracker.record(&headers);
+
+        // Responses without rate limit headers keep the previous value
+        tracker.record(&HeaderMap::new());
+
+        assert_eq!(shared.last().and_then(|r| r.remaining), Some(5));
+    }
+}
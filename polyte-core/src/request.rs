@@ -1,10 +1,16 @@
-use std::marker::PhantomData;
+use std::{marker::PhantomData, sync::Arc};
 
-use reqwest::{Client, Response};
-use serde::de::DeserializeOwned;
+use reqwest::{header::HeaderMap, Client, Method, Response, StatusCode};
+use serde::{de::DeserializeOwned, Deserialize};
 use url::Url;
 
-use crate::ApiError;
+use crate::{
+    client::HttpClient,
+    rate_limit::{RateLimit, RateLimitTracker},
+    retry::RetryPolicy,
+    transport::{HttpTransport, ReqwestTransport},
+    ApiError,
+};
 
 /// Query parameter builder
 pub trait QueryBuilder: Sized {
@@ -53,10 +59,61 @@ pub trait QueryBuilder: Sized {
     }
 }
 
+/// Maximum number of body bytes kept in decode errors
+pub const DECODE_BODY_LIMIT: usize = 1024;
+
 /// Trait for error types that can be created from API responses
 pub trait RequestError: From<ApiError> + std::fmt::Debug {
     /// Create error from HTTP response
     fn from_response(response: Response) -> impl std::future::Future<Output = Self> + Send;
+
+    /// Create error from a successful response whose body failed to
+    /// deserialize. See [`body_snippet`] to keep part of the body.
+    fn from_decode(source: serde_json::Error, body: &[u8]) -> Self {
+        let _ = body;
+        Self::from(ApiError::Serialization(source))
+    }
+}
+
+/// Response body as lossy UTF-8, truncated to [`DECODE_BODY_LIMIT`] bytes
+pub fn body_snippet(body: &[u8]) -> String {
+    let text = String::from_utf8_lossy(body);
+    if text.len() <= DECODE_BODY_LIMIT {
+        return text.into_owned();
+    }
+
+    let mut end = DECODE_BODY_LIMIT;
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    format!("{}...", &text[..end])
+}
+
+/// Status, headers and pagination cursor of a response, returned by
+/// [`Request::send_with_meta`]
+#[derive(Debug, Clone)]
+pub struct ResponseMeta {
+    /// HTTP status of the response
+    pub status: StatusCode,
+    /// Response headers
+    pub headers: HeaderMap,
+    /// Cursor of the next page, from a `next_cursor` or `nextCursor` field of
+    /// an object body
+    pub next_cursor: Option<String>,
+}
+
+impl ResponseMeta {
+    /// Rate limit reported by the response headers, if any
+    pub fn rate_limit(&self) -> Option<RateLimit> {
+        RateLimit::from_headers(&self.headers)
+    }
+}
+
+/// Pagination cursor of a page body, whatever the rest of it holds
+#[derive(Deserialize)]
+struct CursorField {
+    #[serde(alias = "nextCursor")]
+    next_cursor: Option<String>,
 }
 
 /// Generic request builder for simple GET-only APIs (Gamma, Data)
@@ -65,6 +122,10 @@ pub struct Request<T, E> {
     pub(crate) base_url: Url,
     pub(crate) path: String,
     pub(crate) query: Vec<(String, String)>,
+    pub(crate) max_response_bytes: Option<usize>,
+    pub(crate) rate_limit: Option<RateLimitTracker>,
+    pub(crate) retry: RetryPolicy,
+    pub(crate) transport: Arc<dyn HttpTransport>,
     pub(crate) _marker: PhantomData<(T, E)>,
 }
 
@@ -72,10 +133,94 @@ impl<T, E> Request<T, E> {
     /// Create a new request
     pub fn new(client: Client, base_url: Url, path: impl Into<String>) -> Self {
         Self {
+            transport: Arc::new(ReqwestTransport::new(client.clone())),
             client,
             base_url,
             path: path.into(),
             query: Vec::new(),
+            max_response_bytes: None,
+            rate_limit: None,
+            retry: RetryPolicy::default(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Set the maximum response body size in bytes (unlimited if `None`)
+    pub fn max_response_bytes(mut self, max: Option<usize>) -> Self {
+        self.max_response_bytes = max;
+        self
+    }
+
+    /// Query parameters added so far, in insertion order
+    pub fn query_params(&self) -> &[(String, String)] {
+        &self.query
+    }
+
+    /// Last value added for a query parameter, parsed
+    pub fn query_value<V: std::str::FromStr>(&self, key: &str) -> Option<V> {
+        self.query
+            .iter()
+            .rev()
+            .find(|(k, _)| k == key)
+            .and_then(|(_, value)| value.parse().ok())
+    }
+
+    /// Set a query parameter, replacing any values already added for `key`
+    pub fn set_query(mut self, key: impl Into<String>, value: impl ToString) -> Self {
+        let key = key.into();
+        self.query.retain(|(k, _)| *k != key);
+        self.query.push((key, value.to_string()));
+        self
+    }
+
+    /// Record rate limit headers of the response into `tracker`
+    pub fn rate_limit(mut self, tracker: RateLimitTracker) -> Self {
+        self.rate_limit = Some(tracker);
+        self
+    }
+
+    /// Set how many times the request is retried on transient failures
+    pub fn max_retries(mut self, retries: u32) -> Self {
+        self.retry = self.retry.max_retries(retries);
+        self
+    }
+
+    /// Set the retry policy for transient failures
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry = policy;
+        self
+    }
+
+    /// Send the request through `transport`
+    pub fn transport(mut self, transport: Arc<dyn HttpTransport>) -> Self {
+        self.transport = transport;
+        self
+    }
+
+    /// Shared client carrying this request's settings
+    fn http(&self) -> HttpClient {
+        HttpClient {
+            client: self.client.clone(),
+            base_url: self.base_url.clone(),
+            max_response_bytes: self.max_response_bytes,
+            rate_limit: self.rate_limit.clone().unwrap_or_default(),
+            retry: self.retry.clone(),
+            transport: self.transport.clone(),
+        }
+    }
+}
+
+impl<T, E> Clone for Request<T, E> {
+    fn clone(&self) -> Self {
+        Self {
+            client: self.client.clone(),
+            base_url: self.base_url.clone(),
+            path: self.path.clone(),
+            query: self.query.clone(),
+            max_response_bytes: self.max_response_bytes,
+            rate_limit: self.rate_limit.clone(),
+            retry: self.retry.clone(),
+            transport: self.transport.clone(),
             _marker: PhantomData,
         }
     }
@@ -90,55 +235,80 @@ impl<T, E> QueryBuilder for Request<T, E> {
 impl<T: DeserializeOwned, E: RequestError> Request<T, E> {
     /// Execute the request and deserialize response
     pub async fn send(self) -> Result<T, E> {
-        let response = self.send_raw().await?;
-
-        // Get text for debugging
-        let text = response
-            .text()
+        self.http()
+            .send_json(Method::GET, &self.path, &self.query, None)
             .await
-            .map_err(|e| E::from(ApiError::from(e)))?;
-
-        tracing::debug!("Response body: {}", text);
-
-        // Deserialize and provide better error context
-        serde_json::from_str(&text).map_err(|e| {
-            tracing::error!("Deserialization failed: {}", e);
-            tracing::error!("Failed to deserialize: {}", text);
-            E::from(ApiError::from(e))
-        })
     }
 
     /// Execute the request and return raw response
     pub async fn send_raw(self) -> Result<Response, E> {
-        let url = self
-            .base_url
-            .join(&self.path)
-            .map_err(|e| E::from(ApiError::from(e)))?;
-
-        let mut request = self.client.get(url);
+        self.http()
+            .send_raw(Method::GET, &self.path, &self.query, None)
+            .await
+    }
 
-        if !self.query.is_empty() {
-            request = request.query(&self.query);
-        }
+    /// Execute the request and return the response body as untyped JSON
+    pub async fn send_raw_json(self) -> Result<serde_json::Value, E> {
+        self.http()
+            .send_json(Method::GET, &self.path, &self.query, None)
+            .await
+    }
 
-        tracing::debug!("Sending request to: {:?}", request);
+    /// Execute the request and deserialize the response, along with its
+    /// status, headers and pagination cursor
+    pub async fn send_with_meta(self) -> Result<(T, ResponseMeta), E> {
+        let max_response_bytes = self.max_response_bytes;
+        let response = self.send_raw().await?;
+        let status = response.status();
+        let headers = response.headers().clone();
 
-        let response = request
-            .send()
+        let body = read_body(response, max_response_bytes)
             .await
-            .map_err(|e| E::from(ApiError::from(e)))?;
-        let status = response.status();
+            .map_err(E::from)?;
+        let value = serde_json::from_slice(&body).map_err(|e| E::from_decode(e, &body))?;
+        let next_cursor = serde_json::from_slice::<CursorField>(&body)
+            .ok()
+            .and_then(|page| page.next_cursor);
 
-        tracing::debug!("Response status: {}", status);
+        Ok((
+            value,
+            ResponseMeta {
+                status,
+                headers,
+                next_cursor,
+            },
+        ))
+    }
+}
 
-        if !status.is_success() {
-            let error = E::from_response(response).await;
-            tracing::error!("Request failed: {:?}", error);
-            return Err(error);
-        }
+/// Read a response body, failing once it grows past `max_bytes`.
+///
+/// The body is read chunk by chunk so oversized responses are rejected
+/// without being buffered in full.
+pub async fn read_body(
+    mut response: Response,
+    max_bytes: Option<usize>,
+) -> Result<Vec<u8>, ApiError> {
+    let Some(limit) = max_bytes else {
+        return Ok(response.bytes().await?.to_vec());
+    };
+
+    if response
+        .content_length()
+        .is_some_and(|len| len > limit as u64)
+    {
+        return Err(ApiError::ResponseTooLarge { limit });
+    }
 
-        Ok(response)
+    let mut body = Vec::new();
+    while let Some(chunk) = response.chunk().await? {
+        if body.len() + chunk.len() > limit {
+            return Err(ApiError::ResponseTooLarge { limit });
+        }
+        body.extend_from_slice(&chunk);
     }
+
+    Ok(body)
 }
 
 /// Type marker for deserializable responses
@@ -159,3 +329,230 @@ impl<T> Default for TypedRequest<T> {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{MockResponse, MockTransport, TestError};
+
+    /// Request to the root of a mock API answered by `transport`
+    fn request<T>(transport: &Arc<MockTransport>) -> Request<T, TestError> {
+        let base_url = Url::parse("https://api.example.com").unwrap();
+        Request::new(Client::new(), base_url, "/").transport(transport.clone())
+    }
+
+    #[test]
+    fn test_body_snippet_truncates() {
+        assert_eq!(body_snippet(b"{\"a\": 1}"), "{\"a\": 1}");
+
+        let long = "é".repeat(DECODE_BODY_LIMIT);
+        let snippet = body_snippet(long.as_bytes());
+        assert!(snippet.ends_with("..."));
+        assert!(snippet.len() <= DECODE_BODY_LIMIT + 3);
+    }
+
+    #[tokio::test]
+    async fn test_response_within_limit() {
+        let transport = MockTransport::always(MockResponse::ok(format!("\"{}\"", "a".repeat(100))));
+
+        let value: String = request(&transport)
+            .max_response_bytes(Some(1024))
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(value.len(), 100);
+    }
+
+    #[tokio::test]
+    async fn test_response_exceeds_limit() {
+        let transport =
+            MockTransport::always(MockResponse::ok(format!("\"{}\"", "a".repeat(4096))));
+
+        let err = request::<String>(&transport)
+            .max_response_bytes(Some(1024))
+            .send()
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err.0, ApiError::ResponseTooLarge { limit: 1024 }));
+    }
+
+    #[tokio::test]
+    async fn test_records_rate_limit_headers() {
+        let transport = MockTransport::always(
+            MockResponse::ok("\"ok\"")
+                .header("X-RateLimit-Limit", "100")
+                .header("X-RateLimit-Remaining", "99")
+                .header("X-RateLimit-Reset", "10"),
+        );
+        let tracker = RateLimitTracker::new();
+
+        let _: String = request(&transport)
+            .rate_limit(tracker.clone())
+            .send()
+            .await
+            .unwrap();
+
+        let rate_limit = tracker.last().unwrap();
+        assert_eq!(rate_limit.limit, Some(100));
+        assert_eq!(rate_limit.remaining, Some(99));
+        assert_eq!(rate_limit.reset, Some(10));
+    }
+
+    #[tokio::test]
+    async fn test_retries_transient_failure() {
+        let transport = MockTransport::after_unavailable("\"ok\"");
+
+        let value: String = request(&transport).send().await.unwrap();
+
+        assert_eq!(value, "ok");
+        assert_eq!(transport.request_count(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_no_retry_when_disabled() {
+        let transport = MockTransport::always(MockResponse::status(503, ""));
+
+        let err = request::<String>(&transport)
+            .max_retries(0)
+            .send()
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err.0, ApiError::Api { status: 503, .. }));
+        assert_eq!(transport.request_count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_client_error_not_retried() {
+        let transport = MockTransport::sequence([
+            MockResponse::status(400, r#"{"error": "bad"}"#),
+            MockResponse::ok("\"ok\""),
+        ]);
+
+        let err = request::<String>(&transport).send().await.unwrap_err();
+
+        assert!(matches!(
+            err.0,
+            ApiError::Api { status: 400, ref message, .. } if message == "bad"
+        ));
+        assert_eq!(transport.request_count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_retries_rate_limit_after_retry_after() {
+        let transport = MockTransport::sequence([
+            MockResponse::status(429, "").header("Retry-After", "0"),
+            MockResponse::ok("\"ok\""),
+        ]);
+
+        let value: String = request(&transport).send().await.unwrap();
+
+        assert_eq!(value, "ok");
+    }
+
+    #[tokio::test]
+    async fn test_post_not_retried_by_default() {
+        let transport = MockTransport::after_unavailable("\"ok\"");
+        let http = crate::HttpClientBuilder::new("https://api.example.com")
+            .transport(transport.clone())
+            .build()
+            .unwrap();
+
+        let err = http
+            .send_json::<String, TestError>(Method::POST, "/", &[], None)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err.0, ApiError::Api { status: 503, .. }));
+        assert_eq!(transport.request_count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_http_client_send_json() {
+        let transport = MockTransport::always(MockResponse::ok(r#"{"value": 1}"#));
+        let http = crate::HttpClientBuilder::new("https://api.example.com")
+            .transport(transport)
+            .build()
+            .unwrap();
+
+        let value: serde_json::Value = http
+            .send_json::<_, TestError>(Method::GET, "/", &[], None)
+            .await
+            .unwrap();
+
+        assert_eq!(value["value"], 1);
+    }
+
+    #[tokio::test]
+    async fn test_sends_through_custom_transport() {
+        let transport = MockTransport::after_unavailable("\"ok\"");
+        let base_url = Url::parse("https://api.example.com").unwrap();
+
+        let value: String = Request::<String, TestError>::new(Client::new(), base_url, "/markets")
+            .query("limit", 2)
+            .transport(transport.clone())
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(value, "ok");
+        // The retry goes through the transport as well
+        let urls: Vec<String> = transport
+            .requests()
+            .iter()
+            .map(|request| request.url.to_string())
+            .collect();
+        assert_eq!(urls, ["https://api.example.com/markets?limit=2"; 2]);
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct Page {
+        data: Vec<u32>,
+    }
+
+    #[tokio::test]
+    async fn test_send_with_meta_extracts_cursor() {
+        let transport = MockTransport::always(
+            MockResponse::ok(r#"{"data": [1, 2], "next_cursor": "MTA="}"#)
+                .header("X-RateLimit-Remaining", "42"),
+        );
+
+        let (page, meta) = request::<Page>(&transport).send_with_meta().await.unwrap();
+
+        assert_eq!(page.data, [1, 2]);
+        assert_eq!(meta.status, StatusCode::OK);
+        assert_eq!(meta.next_cursor.as_deref(), Some("MTA="));
+        assert_eq!(meta.headers["content-type"], "application/json");
+        assert_eq!(meta.rate_limit().unwrap().remaining, Some(42));
+    }
+
+    #[tokio::test]
+    async fn test_send_with_meta_without_cursor() {
+        let transport =
+            MockTransport::always(MockResponse::ok(r#"{"data": [], "nextCursor": null}"#));
+        let (_, meta) = request::<Page>(&transport).send_with_meta().await.unwrap();
+        assert_eq!(meta.next_cursor, None);
+
+        // Array bodies carry no cursor
+        let transport = MockTransport::always(MockResponse::ok("[1, 2]"));
+        let (values, meta) = request::<Vec<u32>>(&transport)
+            .send_with_meta()
+            .await
+            .unwrap();
+        assert_eq!(values, [1, 2]);
+        assert_eq!(meta.next_cursor, None);
+    }
+
+    #[tokio::test]
+    async fn test_send_raw_json() {
+        let transport =
+            MockTransport::always(MockResponse::ok(r#"{"nextCursor": "LTE=", "extra": true}"#));
+
+        let value = request::<Page>(&transport).send_raw_json().await.unwrap();
+
+        assert_eq!(value["nextCursor"], "LTE=");
+        assert_eq!(value["extra"], true);
+    }
+}
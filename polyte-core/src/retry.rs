@@ -0,0 +1,260 @@
+//! Retry policy for transient request failures.
+
+use std::{
+    collections::hash_map::RandomState,
+    hash::{BuildHasher, Hasher},
+    time::Duration,
+};
+
+use reqwest::{header::HeaderMap, Method, StatusCode};
+
+/// Default number of retries for idempotent requests
+pub const DEFAULT_MAX_RETRIES: u32 = 2;
+/// Default delay before the first retry
+pub const DEFAULT_BASE_DELAY: Duration = Duration::from_millis(200);
+/// Default upper bound of a single backoff delay
+pub const DEFAULT_MAX_DELAY: Duration = Duration::from_secs(10);
+/// Statuses retried by default
+pub const DEFAULT_RETRY_STATUSES: [u16; 5] = [429, 500, 502, 503, 504];
+
+/// When and how long to wait before retrying a failed request.
+///
+/// Requests are retried on the configured statuses, on timeouts and on
+/// connection errors, with exponential backoff and jitter. A `Retry-After`
+/// header on the response takes precedence over the computed backoff.
+///
+/// Only idempotent methods (GET, HEAD, PUT, DELETE, OPTIONS) are retried
+/// unless [`RetryPolicy::retry_non_idempotent`] is enabled, so order
+/// placement is never sent twice by default.
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use polyte_core::RetryPolicy;
+///
+/// let policy = RetryPolicy::default()
+///     .max_retries(5)
+///     .base_delay(Duration::from_millis(500))
+///     .retry_statuses([429, 503]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryPolicy {
+    max_retries: u32,
+    base_delay: Duration,
+    max_delay: Duration,
+    retry_statuses: Vec<u16>,
+    retry_non_idempotent: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: DEFAULT_MAX_RETRIES,
+            base_delay: DEFAULT_BASE_DELAY,
+            max_delay: DEFAULT_MAX_DELAY,
+            retry_statuses: DEFAULT_RETRY_STATUSES.to_vec(),
+            retry_non_idempotent: false,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Policy that never retries
+    pub fn none() -> Self {
+        Self::default().max_retries(0)
+    }
+
+    /// Set the maximum number of retries after the first attempt
+    pub fn max_retries(mut self, retries: u32) -> Self {
+        self.max_retries = retries;
+        self
+    }
+
+    /// Set the delay before the first retry, doubled on each subsequent one
+    pub fn base_delay(mut self, delay: Duration) -> Self {
+        self.base_delay = delay;
+        self
+    }
+
+    /// Set the upper bound of a single backoff delay
+    pub fn max_delay(mut self, delay: Duration) -> Self {
+        self.max_delay = delay;
+        self
+    }
+
+    /// Set the response statuses that are retried
+    pub fn retry_statuses(mut self, statuses: impl IntoIterator<Item = u16>) -> Self {
+        self.retry_statuses = statuses.into_iter().collect();
+        self
+    }
+
+    /// Also retry non-idempotent methods such as POST.
+    ///
+    /// Retrying order placement can create duplicate orders; only enable this
+    /// for endpoints known to be safe to repeat.
+    pub fn retry_non_idempotent(mut self, enabled: bool) -> Self {
+        self.retry_non_idempotent = enabled;
+        self
+    }
+
+    /// Number of retries allowed for a request with `method`
+    pub fn retries_for(&self, method: &Method) -> u32 {
+        if self.is_repeatable(method) {
+            self.max_retries
+        } else {
+            0
+        }
+    }
+
+    /// Whether a request with `method` may be sent again when its outcome
+    /// is unknown
+    pub fn is_repeatable(&self, method: &Method) -> bool {
+        self.retry_non_idempotent || is_idempotent(method)
+    }
+
+    /// Whether a request that got `status` may be sent to a fallback host
+    /// once its retries are used up
+    pub fn should_fail_over_status(&self, method: &Method, status: StatusCode) -> bool {
+        status.is_server_error() && self.is_repeatable(method)
+    }
+
+    /// Whether a request that failed with `err` may be sent to a fallback
+    /// host once its retries are used up.
+    ///
+    /// Requests that can't be repeated only fail over when the connection
+    /// failed, as they then provably never reached the host.
+    pub fn should_fail_over_error(&self, method: &Method, err: &reqwest::Error) -> bool {
+        err.is_connect() || (self.is_repeatable(method) && self.should_retry_error(err))
+    }
+
+    /// Whether a response status is worth retrying
+    pub fn should_retry_status(&self, status: StatusCode) -> bool {
+        self.retry_statuses.contains(&status.as_u16())
+    }
+
+    /// Whether a transport error is worth retrying
+    pub fn should_retry_error(&self, err: &reqwest::Error) -> bool {
+        err.is_timeout() || err.is_connect() || err.is_request()
+    }
+
+    /// Delay before retry number `attempt` (zero-based).
+    ///
+    /// Uses `retry_after` when the server sent one, otherwise exponential
+    /// backoff capped at `max_delay`, with half of it randomized.
+    pub fn delay(&self, attempt: u32, retry_after: Option<Duration>) -> Duration {
+        if let Some(retry_after) = retry_after {
+            return retry_after;
+        }
+
+        let backoff = self
+            .base_delay
+            .saturating_mul(2u32.saturating_pow(attempt))
+            .min(self.max_delay);
+        backoff / 2 + backoff.mul_f64(jitter() / 2.0)
+    }
+}
+
+/// Parse a `Retry-After` header given in seconds
+pub fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    headers
+        .get(reqwest::header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse()
+        .ok()
+        .map(Duration::from_secs)
+}
+
+fn is_idempotent(method: &Method) -> bool {
+    matches!(
+        *method,
+        Method::GET | Method::HEAD | Method::PUT | Method::DELETE | Method::OPTIONS
+    )
+}
+
+/// Random fraction in `[0, 1)`
+fn jitter() -> f64 {
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u32(std::process::id());
+    (hasher.finish() % 1_000) as f64 / 1_000.0
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::header::HeaderValue;
+
+    use super::*;
+
+    #[test]
+    fn test_post_not_retried_by_default() {
+        let policy = RetryPolicy::default();
+        assert_eq!(policy.retries_for(&Method::GET), DEFAULT_MAX_RETRIES);
+        assert_eq!(policy.retries_for(&Method::DELETE), DEFAULT_MAX_RETRIES);
+        assert_eq!(policy.retries_for(&Method::POST), 0);
+        assert_eq!(policy.retries_for(&Method::PATCH), 0);
+
+        let policy = policy.retry_non_idempotent(true);
+        assert_eq!(policy.retries_for(&Method::POST), DEFAULT_MAX_RETRIES);
+    }
+
+    #[test]
+    fn test_post_fails_over_only_when_unsent() {
+        let policy = RetryPolicy::default();
+        assert!(policy.should_fail_over_status(&Method::GET, StatusCode::BAD_GATEWAY));
+        assert!(policy.should_fail_over_status(&Method::DELETE, StatusCode::SERVICE_UNAVAILABLE));
+        assert!(!policy.should_fail_over_status(&Method::POST, StatusCode::GATEWAY_TIMEOUT));
+        assert!(!policy.should_fail_over_status(&Method::GET, StatusCode::TOO_MANY_REQUESTS));
+
+        let policy = policy.retry_non_idempotent(true);
+        assert!(policy.should_fail_over_status(&Method::POST, StatusCode::BAD_GATEWAY));
+    }
+
+    #[test]
+    fn test_retry_statuses() {
+        let policy = RetryPolicy::default();
+        for status in [429, 500, 502, 503, 504] {
+            assert!(policy.should_retry_status(StatusCode::from_u16(status).unwrap()));
+        }
+        assert!(!policy.should_retry_status(StatusCode::BAD_REQUEST));
+        assert!(!policy.should_retry_status(StatusCode::NOT_FOUND));
+
+        let policy = policy.retry_statuses([503]);
+        assert!(!policy.should_retry_status(StatusCode::TOO_MANY_REQUESTS));
+        assert!(policy.should_retry_status(StatusCode::SERVICE_UNAVAILABLE));
+    }
+
+    #[test]
+    fn test_exponential_backoff_with_jitter() {
+        let policy = RetryPolicy::default()
+            .base_delay(Duration::from_millis(100))
+            .max_delay(Duration::from_millis(300));
+
+        for (attempt, full) in [(0, 100), (1, 200), (2, 300), (5, 300)] {
+            let delay = policy.delay(attempt, None);
+            assert!(delay >= Duration::from_millis(full / 2), "{:?}", delay);
+            assert!(delay <= Duration::from_millis(full), "{:?}", delay);
+        }
+    }
+
+    #[test]
+    fn test_retry_after_takes_precedence() {
+        let mut headers = HeaderMap::new();
+        headers.insert(reqwest::header::RETRY_AFTER, HeaderValue::from_static("3"));
+
+        let retry_after = retry_after(&headers);
+        assert_eq!(retry_after, Some(Duration::from_secs(3)));
+        assert_eq!(
+            RetryPolicy::default().delay(0, retry_after),
+            Duration::from_secs(3)
+        );
+        assert_eq!(super::retry_after(&HeaderMap::new()), None);
+    }
+
+    #[test]
+    fn test_none_never_retries() {
+        assert_eq!(RetryPolicy::none().retries_for(&Method::GET), 0);
+    }
+}
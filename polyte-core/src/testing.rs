@@ -0,0 +1,240 @@
+//! In-memory [`HttpTransport`] for tests, enabled by the `test-util` feature.
+//!
+//! ```
+//! use polyte_core::testing::{MockResponse, MockTransport};
+//! use polyte_core::HttpClientBuilder;
+//!
+//! let transport = MockTransport::new(|request| match request.path() {
+//!     "/markets" => MockResponse::ok("[]"),
+//!     _ => MockResponse::status(404, r#"{"error": "not found"}"#),
+//! });
+//! let client = HttpClientBuilder::new("https://api.example.com")
+//!     .transport(transport.clone())
+//!     .build()
+//!     .unwrap();
+//! ```
+
+use std::{
+    collections::VecDeque,
+    fmt,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use reqwest::{header::HeaderMap, Method};
+use serde::Serialize;
+use url::Url;
+
+use crate::transport::{HttpTransport, TransportFuture};
+#[cfg(test)]
+use crate::{error::ApiError, request::RequestError};
+
+/// Canned response returned by a [`MockTransport`]
+#[derive(Debug, Clone)]
+pub struct MockResponse {
+    status: u16,
+    body: String,
+    headers: Vec<(String, String)>,
+    delay: Duration,
+}
+
+impl MockResponse {
+    /// `200 OK` with `body`
+    pub fn ok(body: impl Into<String>) -> Self {
+        Self::status(200, body)
+    }
+
+    /// `200 OK` with `value` serialized as the JSON body
+    pub fn json(value: &impl Serialize) -> Self {
+        Self::ok(serde_json::to_string(value).expect("mock body serializes to JSON"))
+    }
+
+    /// Response with the given status code and body
+    pub fn status(status: u16, body: impl Into<String>) -> Self {
+        Self {
+            status,
+            body: body.into(),
+            headers: vec![("content-type".to_string(), "application/json".to_string())],
+            delay: Duration::ZERO,
+        }
+    }
+
+    /// Add a response header
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Wait `delay` before answering
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+}
+
+/// Request received by a [`MockTransport`]
+#[derive(Debug, Clone)]
+pub struct RecordedRequest {
+    pub method: Method,
+    pub url: Url,
+    pub headers: HeaderMap,
+    pub body: Vec<u8>,
+}
+
+impl RecordedRequest {
+    /// Path of the request URL
+    pub fn path(&self) -> &str {
+        self.url.path()
+    }
+
+    /// Last value of a query parameter
+    pub fn query(&self, key: &str) -> Option<String> {
+        self.url
+            .query_pairs()
+            .filter(|(k, _)| k == key)
+            .last()
+            .map(|(_, value)| value.into_owned())
+    }
+
+    /// Value of a header, if present and valid text
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(name)?.to_str().ok()
+    }
+
+    /// Body as text, empty if it is not UTF-8
+    pub fn body_text(&self) -> &str {
+        std::str::from_utf8(&self.body).unwrap_or_default()
+    }
+
+    /// Body parsed as JSON
+    pub fn body_json(&self) -> serde_json::Value {
+        serde_json::from_slice(&self.body).expect("request body is JSON")
+    }
+}
+
+type Handler = dyn Fn(&RecordedRequest) -> MockResponse + Send + Sync;
+
+/// [`HttpTransport`] answering from memory and recording every request.
+///
+/// Responses are built with [`MockResponse`], either by a handler that sees
+/// each request or from a fixed sequence.
+pub struct MockTransport {
+    handler: Box<Handler>,
+    requests: Mutex<Vec<RecordedRequest>>,
+}
+
+impl MockTransport {
+    /// Answer every request with the response built by `handler`
+    pub fn new(
+        handler: impl Fn(&RecordedRequest) -> MockResponse + Send + Sync + 'static,
+    ) -> Arc<Self> {
+        Arc::new(Self {
+            handler: Box::new(handler),
+            requests: Mutex::default(),
+        })
+    }
+
+    /// Answer requests with `responses` in order, repeating the last one
+    pub fn sequence(responses: impl IntoIterator<Item = MockResponse>) -> Arc<Self> {
+        let responses: Mutex<VecDeque<MockResponse>> = Mutex::new(responses.into_iter().collect());
+        Self::new(move |_| {
+            let mut responses = responses.lock().unwrap();
+            match responses.len() {
+                0 => panic!("MockTransport::sequence needs at least one response"),
+                1 => responses[0].clone(),
+                _ => responses.pop_front().unwrap(),
+            }
+        })
+    }
+
+    /// Answer every request with `response`
+    pub fn always(response: MockResponse) -> Arc<Self> {
+        Self::new(move |_| response.clone())
+    }
+
+    /// Answer a `503 Service Unavailable`, then `200 OK` with `body` to every
+    /// later request, to exercise retries
+    pub fn after_unavailable(body: impl Into<String>) -> Arc<Self> {
+        Self::sequence([MockResponse::status(503, ""), MockResponse::ok(body)])
+    }
+
+    /// Answer every request with `200 OK`, `body` and a full set of rate limit
+    /// headers: a limit of 100, 42 remaining and a reset in 10 seconds
+    pub fn rate_limited(body: impl Into<String>) -> Arc<Self> {
+        Self::always(
+            MockResponse::ok(body)
+                .header("x-ratelimit-limit", "100")
+                .header("x-ratelimit-remaining", "42")
+                .header("x-ratelimit-reset", "10"),
+        )
+    }
+
+    /// Requests received so far, in order
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    /// Number of requests received so far
+    pub fn request_count(&self) -> usize {
+        self.requests.lock().unwrap().len()
+    }
+}
+
+impl fmt::Debug for MockTransport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MockTransport")
+            .field("requests", &self.request_count())
+            .finish_non_exhaustive()
+    }
+}
+
+impl HttpTransport for MockTransport {
+    fn execute(&self, request: reqwest::Request) -> TransportFuture<'_> {
+        let request = RecordedRequest {
+            method: request.method().clone(),
+            url: request.url().clone(),
+            headers: request.headers().clone(),
+            body: request
+                .body()
+                .and_then(|body| body.as_bytes())
+                .map(<[u8]>::to_vec)
+                .unwrap_or_default(),
+        };
+        let response = (self.handler)(&request);
+        self.requests.lock().unwrap().push(request);
+
+        Box::pin(async move {
+            if !response.delay.is_zero() {
+                tokio::time::sleep(response.delay).await;
+            }
+
+            let mut builder = http::Response::builder().status(response.status);
+            for (name, value) in &response.headers {
+                builder = builder.header(name, value);
+            }
+            Ok(builder
+                .body(response.body)
+                .expect("valid mock response")
+                .into())
+        })
+    }
+}
+
+/// Error type for requests made by this crate's own tests
+#[cfg(test)]
+#[derive(Debug)]
+pub(crate) struct TestError(pub(crate) ApiError);
+
+#[cfg(test)]
+impl From<ApiError> for TestError {
+    fn from(err: ApiError) -> Self {
+        Self(err)
+    }
+}
+
+#[cfg(test)]
+impl RequestError for TestError {
+    async fn from_response(response: reqwest::Response) -> Self {
+        Self(ApiError::from_response(response).await)
+    }
+}
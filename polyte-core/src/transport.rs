@@ -0,0 +1,315 @@
+use std::{
+    fmt,
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use reqwest::{Client, Request, Response};
+use url::Url;
+
+use crate::error::ApiError;
+
+/// Future returned by [`HttpTransport::execute`]
+pub type TransportFuture<'a> =
+    Pin<Box<dyn Future<Output = Result<Response, ApiError>> + Send + 'a>>;
+
+/// Executes built HTTP requests on behalf of the API clients.
+///
+/// Every request goes through a transport, which is [`ReqwestTransport`]
+/// unless another one is set with [`HttpClientBuilder::transport`]. A custom
+/// transport can answer from memory to test request building without a
+/// network. Connection failures should be returned as [`ApiError::Network`]
+/// so they are retried like reqwest's.
+///
+/// Requests reach the transport before the client's default headers and
+/// timeout are applied, so it only sees headers set per request.
+///
+/// [`HttpClientBuilder::transport`]: crate::HttpClientBuilder::transport
+///
+/// # Example
+///
+/// ```
+/// use std::sync::Arc;
+///
+/// use polyte_core::{ApiError, HttpClientBuilder, HttpTransport, TransportFuture};
+///
+/// /// Answer every request with an empty JSON array
+/// #[derive(Debug)]
+/// struct EmptyList;
+///
+/// impl HttpTransport for EmptyList {
+///     fn execute(&self, _request: reqwest::Request) -> TransportFuture<'_> {
+///         Box::pin(async {
+///             let response = http::Response::builder().status(200).body("[]").unwrap();
+///             Ok::<_, ApiError>(response.into())
+///         })
+///     }
+/// }
+///
+/// let client = HttpClientBuilder::new("https://api.example.com")
+///     .transport(Arc::new(EmptyList))
+///     .build()
+///     .unwrap();
+/// ```
+pub trait HttpTransport: fmt::Debug + Send + Sync {
+    /// Send `request` and return its response
+    fn execute(&self, request: Request) -> TransportFuture<'_>;
+
+    /// URL to send a request for `url` to after its host failed.
+    ///
+    /// Called once a request's retries are used up and the
+    /// [`RetryPolicy`](crate::RetryPolicy) allows another attempt elsewhere.
+    /// Returning a URL sends the request once more to it. Transports without
+    /// a fallback host return `None`, the default.
+    fn fail_over(&self, url: &Url) -> Option<Url> {
+        let _ = url;
+        None
+    }
+}
+
+/// Transport sending requests over the network with a reqwest client
+#[derive(Debug, Clone)]
+pub struct ReqwestTransport {
+    client: Client,
+}
+
+impl ReqwestTransport {
+    /// Create a transport backed by `client`
+    pub fn new(client: Client) -> Self {
+        Self { client }
+    }
+}
+
+impl HttpTransport for ReqwestTransport {
+    fn execute(&self, request: Request) -> TransportFuture<'_> {
+        Box::pin(async move { Ok(self.client.execute(request).await?) })
+    }
+}
+
+/// How long requests skip the primary host of a [`FailoverTransport`] after
+/// it failed
+pub const FAILOVER_COOLDOWN: Duration = Duration::from_secs(30);
+
+/// Transport sending requests to a fallback host when the primary one fails.
+///
+/// Once a request to the primary base URL has used up its retries, the
+/// client asks [`HttpTransport::fail_over`] for the same path on the
+/// fallback base URL and sends it there once, if its
+/// [`RetryPolicy`](crate::RetryPolicy) allows it. Non-idempotent requests
+/// such as order placement only fail over when they could not connect, so
+/// they are never sent twice. The primary is then skipped for
+/// [`FAILOVER_COOLDOWN`], so requests don't pay for its failure every time,
+/// and tried again once the cooldown expires. Requests to other hosts are
+/// sent as is.
+#[derive(Debug)]
+pub struct FailoverTransport {
+    inner: Arc<dyn HttpTransport>,
+    primary: Url,
+    fallback: Url,
+    cooldown: Duration,
+    primary_failed_at: Mutex<Option<Instant>>,
+}
+
+impl FailoverTransport {
+    /// Send requests through `inner`, failing over from `primary` to
+    /// `fallback`
+    pub fn new(inner: Arc<dyn HttpTransport>, primary: Url, fallback: Url) -> Self {
+        Self {
+            inner,
+            primary,
+            fallback,
+            cooldown: FAILOVER_COOLDOWN,
+            primary_failed_at: Mutex::new(None),
+        }
+    }
+
+    /// Set how long the primary host is skipped after a failure
+    pub fn cooldown(mut self, cooldown: Duration) -> Self {
+        self.cooldown = cooldown;
+        self
+    }
+
+    /// Whether requests currently go to the primary host
+    pub fn primary_healthy(&self) -> bool {
+        !self
+            .primary_failed_at
+            .lock()
+            .unwrap()
+            .is_some_and(|failed_at| failed_at.elapsed() < self.cooldown)
+    }
+
+    fn set_primary_failed(&self, failed: bool) {
+        *self.primary_failed_at.lock().unwrap() = failed.then(Instant::now);
+    }
+
+    /// `url` moved from the primary to the fallback host, if it targets the
+    /// primary
+    fn to_fallback(&self, url: &Url) -> Option<Url> {
+        let path = url.as_str().strip_prefix(self.primary.as_str())?;
+        Url::parse(&format!("{}{}", self.fallback, path)).ok()
+    }
+}
+
+/// Whether a response or error warrants trying another host
+fn host_failed(result: &Result<Response, ApiError>) -> bool {
+    match result {
+        Ok(response) => response.status().is_server_error(),
+        Err(ApiError::Network(_)) => true,
+        Err(_) => false,
+    }
+}
+
+impl HttpTransport for FailoverTransport {
+    fn execute(&self, mut request: Request) -> TransportFuture<'_> {
+        Box::pin(async move {
+            let Some(fallback_url) = self.to_fallback(request.url()) else {
+                return self.inner.execute(request).await;
+            };
+
+            if !self.primary_healthy() {
+                *request.url_mut() = fallback_url;
+                return self.inner.execute(request).await;
+            }
+
+            let result = self.inner.execute(request).await;
+            if !host_failed(&result) {
+                self.set_primary_failed(false);
+            }
+            result
+        })
+    }
+
+    fn fail_over(&self, url: &Url) -> Option<Url> {
+        let fallback_url = self.to_fallback(url)?;
+        tracing::warn!(
+            "Primary host {} failed, trying {}",
+            self.primary,
+            self.fallback
+        );
+        self.set_primary_failed(true);
+        Some(fallback_url)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Answer with the status configured for each host, recording the
+    /// requested URLs
+    #[derive(Debug)]
+    struct Hosts {
+        statuses: Vec<(&'static str, u16)>,
+        requested: Mutex<Vec<String>>,
+    }
+
+    impl HttpTransport for Hosts {
+        fn execute(&self, request: Request) -> TransportFuture<'_> {
+            let url = request.url().to_string();
+            self.requested.lock().unwrap().push(url.clone());
+            let status = self
+                .statuses
+                .iter()
+                .find(|(host, _)| url.starts_with(host))
+                .map_or(404, |(_, status)| *status);
+
+            Box::pin(async move {
+                let response = http::Response::builder().status(status).body("{}").unwrap();
+                Ok::<_, ApiError>(response.into())
+            })
+        }
+    }
+
+    fn failover(primary_status: u16) -> (Arc<Hosts>, FailoverTransport) {
+        let hosts = Arc::new(Hosts {
+            statuses: vec![
+                ("https://primary", primary_status),
+                ("https://fallback", 200),
+            ],
+            requested: Mutex::new(Vec::new()),
+        });
+        let transport = FailoverTransport::new(
+            hosts.clone(),
+            Url::parse("https://primary.example.com").unwrap(),
+            Url::parse("https://fallback.example.com").unwrap(),
+        );
+        (hosts, transport)
+    }
+
+    fn get(url: &str) -> Request {
+        Client::new().get(url).build().unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_healthy_primary() {
+        let (hosts, transport) = failover(200);
+
+        let response = transport
+            .execute(get("https://primary.example.com/markets?limit=1"))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), 200);
+        assert!(transport.primary_healthy());
+        assert_eq!(
+            *hosts.requested.lock().unwrap(),
+            ["https://primary.example.com/markets?limit=1"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fails_over_and_skips_primary() {
+        let (hosts, transport) = failover(503);
+        let url = Url::parse("https://primary.example.com/markets?limit=1").unwrap();
+
+        // A failed request is returned as is, the client decides to fail over
+        let response = transport.execute(get(url.as_str())).await.unwrap();
+        assert_eq!(response.status(), 503);
+        assert!(transport.primary_healthy());
+
+        let fallback = transport.fail_over(&url).unwrap();
+        assert_eq!(
+            fallback.as_str(),
+            "https://fallback.example.com/markets?limit=1"
+        );
+        assert!(!transport.primary_healthy());
+
+        let response = transport.execute(get(url.as_str())).await.unwrap();
+        assert_eq!(response.status(), 200);
+        assert_eq!(
+            *hosts.requested.lock().unwrap(),
+            [
+                "https://primary.example.com/markets?limit=1",
+                "https://fallback.example.com/markets?limit=1",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_primary_retried_after_cooldown() {
+        let (hosts, transport) = failover(503);
+        let transport = transport.cooldown(Duration::ZERO);
+        let url = Url::parse("https://primary.example.com/time").unwrap();
+
+        transport.fail_over(&url).unwrap();
+        transport.execute(get(url.as_str())).await.unwrap();
+
+        let requested = hosts.requested.lock().unwrap();
+        assert_eq!(*requested, ["https://primary.example.com/time"]);
+    }
+
+    #[tokio::test]
+    async fn test_other_hosts_untouched() {
+        let (hosts, transport) = failover(503);
+        let url = Url::parse("https://other.example.com/time").unwrap();
+
+        let response = transport.execute(get(url.as_str())).await.unwrap();
+
+        assert_eq!(response.status(), 404);
+        assert_eq!(transport.fail_over(&url), None);
+        assert!(transport.primary_healthy());
+        assert_eq!(hosts.requested.lock().unwrap().len(), 1);
+    }
+}
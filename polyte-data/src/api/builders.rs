@@ -1,36 +1,27 @@
-use polyte_core::{QueryBuilder, Request};
-use reqwest::Client;
+use std::sync::Arc;
+
+use polyte_core::{HttpClient, QueryBuilder, Request};
 use serde::{Deserialize, Serialize};
-use url::Url;
 
 use crate::error::DataApiError;
 
 /// Builders namespace for builder-related operations
 #[derive(Clone)]
 pub struct BuildersApi {
-    pub(crate) client: Client,
-    pub(crate) base_url: Url,
+    pub(crate) http: Arc<HttpClient>,
 }
 
 impl BuildersApi {
     /// Get the aggregated builder leaderboard
     pub fn leaderboard(&self) -> GetBuilderLeaderboard {
-        let request = Request::new(
-            self.client.clone(),
-            self.base_url.clone(),
-            "/v1/builders/leaderboard",
-        );
+        let request = self.http.request("/v1/builders/leaderboard");
 
         GetBuilderLeaderboard { request }
     }
 
     /// Get daily builder volume time series
     pub fn volume(&self) -> GetBuilderVolume {
-        let request = Request::new(
-            self.client.clone(),
-            self.base_url.clone(),
-            "/v1/builders/volume",
-        );
+        let request = self.http.request("/v1/builders/volume");
 
         GetBuilderVolume { request }
     }
@@ -126,6 +117,17 @@ impl GetBuilderVolume {
     pub async fn send(self) -> Result<Vec<BuilderVolume>, DataApiError> {
         self.request.send().await
     }
+
+    /// Execute the request and return the trend for a single builder
+    ///
+    /// Returns `None` if the builder does not appear in the series.
+    pub async fn rank_of(
+        self,
+        builder: impl AsRef<str>,
+    ) -> Result<Option<BuilderTrend>, DataApiError> {
+        let series = self.send().await?;
+        Ok(BuilderTrend::from_series(&series, builder.as_ref()))
+    }
 }
 
 /// Builder volume entry in the time series
@@ -147,3 +149,126 @@ pub struct BuilderVolume {
     /// Builder's ranking position
     pub rank: String,
 }
+
+/// A builder's latest standing and change versus the previous period
+#[derive(Debug, Clone)]
+pub struct BuilderTrend {
+    /// Most recent entry for the builder
+    pub latest: BuilderVolume,
+    /// Entry for the period before `latest`, if any
+    pub previous: Option<BuilderVolume>,
+}
+
+impl BuilderTrend {
+    /// Extract a builder's trend from a volume time series
+    pub fn from_series(series: &[BuilderVolume], builder: &str) -> Option<Self> {
+        let mut entries: Vec<&BuilderVolume> =
+            series.iter().filter(|v| v.builder == builder).collect();
+        entries.sort_by(|a, b| b.dt.cmp(&a.dt));
+
+        let mut entries = entries.into_iter();
+        let latest = entries.next()?.clone();
+        let previous = entries.next().cloned();
+
+        Some(Self { latest, previous })
+    }
+
+    /// Current ranking position
+    pub fn rank(&self) -> &str {
+        &self.latest.rank
+    }
+
+    /// Current trading volume
+    pub fn volume(&self) -> f64 {
+        self.latest.volume
+    }
+
+    /// Volume change versus the previous period
+    pub fn volume_delta(&self) -> Option<f64> {
+        self.previous
+            .as_ref()
+            .map(|previous| self.latest.volume - previous.volume)
+    }
+
+    /// Active users change versus the previous period
+    pub fn active_users_delta(&self) -> Option<i64> {
+        self.previous
+            .as_ref()
+            .map(|previous| self.latest.active_users as i64 - previous.active_users as i64)
+    }
+
+    /// Positions gained in the ranking versus the previous period
+    ///
+    /// Positive values mean the builder moved up. Returns `None` if there is no
+    /// previous period or a rank is not numeric.
+    pub fn rank_delta(&self) -> Option<i64> {
+        let previous = self.previous.as_ref()?;
+        let latest: i64 = self.latest.rank.parse().ok()?;
+        let previous: i64 = previous.rank.parse().ok()?;
+        Some(previous - latest)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(dt: &str, builder: &str, volume: f64, active_users: u64, rank: &str) -> BuilderVolume {
+        BuilderVolume {
+            dt: dt.to_string(),
+            builder: builder.to_string(),
+            builder_logo: None,
+            verified: true,
+            volume,
+            active_users,
+            rank: rank.to_string(),
+        }
+    }
+
+    fn series() -> Vec<BuilderVolume> {
+        vec![
+            entry("2025-01-01T00:00:00Z", "alpha", 1_000.0, 10, "2"),
+            entry("2025-01-01T00:00:00Z", "beta", 2_000.0, 20, "1"),
+            entry("2025-01-02T00:00:00Z", "alpha", 3_000.0, 15, "1"),
+            entry("2025-01-02T00:00:00Z", "beta", 2_500.0, 18, "2"),
+            entry("2025-01-03T00:00:00Z", "beta", 2_000.0, 25, "1"),
+        ]
+    }
+
+    #[test]
+    fn test_latest_and_previous() {
+        let trend = BuilderTrend::from_series(&series(), "alpha").unwrap();
+        assert_eq!(trend.latest.dt, "2025-01-02T00:00:00Z");
+        assert_eq!(trend.previous.unwrap().dt, "2025-01-01T00:00:00Z");
+    }
+
+    #[test]
+    fn test_deltas() {
+        let trend = BuilderTrend::from_series(&series(), "alpha").unwrap();
+        assert_eq!(trend.rank(), "1");
+        assert_eq!(trend.volume(), 3_000.0);
+        assert_eq!(trend.volume_delta(), Some(2_000.0));
+        assert_eq!(trend.active_users_delta(), Some(5));
+        assert_eq!(trend.rank_delta(), Some(1));
+
+        let trend = BuilderTrend::from_series(&series(), "beta").unwrap();
+        assert_eq!(trend.latest.dt, "2025-01-03T00:00:00Z");
+        assert_eq!(trend.volume_delta(), Some(-500.0));
+        assert_eq!(trend.active_users_delta(), Some(7));
+        assert_eq!(trend.rank_delta(), Some(1));
+    }
+
+    #[test]
+    fn test_single_period() {
+        let series = vec![entry("2025-01-01T00:00:00Z", "alpha", 1_000.0, 10, "3")];
+        let trend = BuilderTrend::from_series(&series, "alpha").unwrap();
+        assert!(trend.previous.is_none());
+        assert_eq!(trend.volume_delta(), None);
+        assert_eq!(trend.rank_delta(), None);
+    }
+
+    #[test]
+    fn test_unknown_builder() {
+        assert!(BuilderTrend::from_series(&series(), "gamma").is_none());
+    }
+}
@@ -1,30 +1,44 @@
-use polyte_core::{QueryBuilder, Request};
-use reqwest::Client;
+use std::sync::Arc;
+
+use polyte_core::{HttpClient, QueryBuilder, Request};
 use serde::{Deserialize, Serialize};
-use url::Url;
 
 use crate::error::DataApiError;
 
 /// Holders namespace for holder-related operations
 #[derive(Clone)]
 pub struct Holders {
-    pub(crate) client: Client,
-    pub(crate) base_url: Url,
+    pub(crate) http: Arc<HttpClient>,
 }
 
 impl Holders {
     /// Get top holders for markets
     pub fn list(&self, markets: impl IntoIterator<Item = impl ToString>) -> ListHolders {
         let market_ids: Vec<String> = markets.into_iter().map(|s| s.to_string()).collect();
-        let mut request = Request::new(self.client.clone(), self.base_url.clone(), "/holders");
+        let mut request = self.http.request("/holders");
         if !market_ids.is_empty() {
             request = request.query("market", market_ids.join(","));
         }
 
         ListHolders { request }
     }
+
+    /// Get top holders of a single market, across all of its outcome tokens
+    pub fn top(&self, condition_id: impl Into<String>) -> TopHolders {
+        TopHolders {
+            request: self
+                .http
+                .request("/holders")
+                .query("market", condition_id.into()),
+            limit: None,
+            outcome_index: None,
+        }
+    }
 }
 
+/// Maximum number of holders the API returns per token
+const MAX_HOLDERS_LIMIT: u32 = 500;
+
 /// Request builder for getting top holders
 pub struct ListHolders {
     request: Request<Vec<MarketHolders>, DataApiError>,
@@ -49,6 +63,53 @@ impl ListHolders {
     }
 }
 
+/// Request builder for getting the top holders of a single market
+pub struct TopHolders {
+    request: Request<Vec<MarketHolders>, DataApiError>,
+    limit: Option<u32>,
+    outcome_index: Option<u32>,
+}
+
+impl TopHolders {
+    /// Set maximum number of holders per outcome token (0-500, default: 100)
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Only return holders of the given outcome (0 or 1 for binary markets)
+    pub fn outcome_index(mut self, outcome_index: u32) -> Self {
+        self.outcome_index = Some(outcome_index);
+        self
+    }
+
+    /// Execute the request
+    pub async fn send(self) -> Result<Vec<Holder>, DataApiError> {
+        let mut request = self.request;
+        if let Some(limit) = self.limit {
+            if limit > MAX_HOLDERS_LIMIT {
+                return Err(DataApiError::validation(format!(
+                    "Holders limit must be at most {}, got {}",
+                    MAX_HOLDERS_LIMIT, limit
+                )));
+            }
+            request = request.query("limit", limit);
+        }
+
+        let markets = request.send().await?;
+        Ok(flatten_holders(markets, self.outcome_index))
+    }
+}
+
+/// Flatten per-token holder lists, keeping only the requested outcome if any
+fn flatten_holders(markets: Vec<MarketHolders>, outcome_index: Option<u32>) -> Vec<Holder> {
+    markets
+        .into_iter()
+        .flat_map(|market| market.holders)
+        .filter(|holder| outcome_index.map_or(true, |index| holder.outcome_index == index))
+        .collect()
+}
+
 /// Market holders response containing token and its holders
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all(deserialize = "camelCase"))]
@@ -84,3 +145,96 @@ pub struct Holder {
     /// Optimized profile image URL
     pub profile_image_optimized: Option<String>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DataApi;
+
+    const SAMPLE: &str = r#"[
+        {
+            "token": "111",
+            "holders": [
+                {
+                    "proxyWallet": "0x1111111111111111111111111111111111111111",
+                    "bio": "",
+                    "asset": "111",
+                    "pseudonym": "Quiet-Otter",
+                    "amount": 15230.5,
+                    "displayUsernamePublic": true,
+                    "outcomeIndex": 0,
+                    "name": "alice",
+                    "profileImage": "https://example.com/alice.png",
+                    "profileImageOptimized": ""
+                }
+            ]
+        },
+        {
+            "token": "222",
+            "holders": [
+                {
+                    "proxyWallet": "0x2222222222222222222222222222222222222222",
+                    "asset": "222",
+                    "amount": 980,
+                    "outcomeIndex": 1,
+                    "name": null,
+                    "profileImage": null
+                }
+            ]
+        }
+    ]"#;
+
+    #[test]
+    fn test_deserialize_holders() {
+        let markets: Vec<MarketHolders> = serde_json::from_str(SAMPLE).unwrap();
+        assert_eq!(markets.len(), 2);
+
+        let holder = &markets[0].holders[0];
+        assert_eq!(
+            holder.proxy_wallet,
+            "0x1111111111111111111111111111111111111111"
+        );
+        assert_eq!(holder.amount, 15230.5);
+        assert_eq!(holder.outcome_index, 0);
+        assert_eq!(holder.name.as_deref(), Some("alice"));
+        assert_eq!(
+            holder.profile_image.as_deref(),
+            Some("https://example.com/alice.png")
+        );
+
+        let holder = &markets[1].holders[0];
+        assert_eq!(holder.amount, 980.0);
+        assert_eq!(holder.outcome_index, 1);
+        assert!(holder.name.is_none());
+        assert!(holder.pseudonym.is_none());
+    }
+
+    #[test]
+    fn test_flatten_holders_by_outcome() {
+        let markets: Vec<MarketHolders> = serde_json::from_str(SAMPLE).unwrap();
+        assert_eq!(flatten_holders(markets.clone(), None).len(), 2);
+
+        let no = flatten_holders(markets, Some(1));
+        assert_eq!(no.len(), 1);
+        assert_eq!(
+            no[0].proxy_wallet,
+            "0x2222222222222222222222222222222222222222"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_top_rejects_limit_out_of_bounds() {
+        let data = DataApi::new().unwrap();
+        let err = data
+            .holders()
+            .top("0xabc")
+            .limit(501)
+            .send()
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            DataApiError::Api(polyte_core::ApiError::Validation(_))
+        ));
+    }
+}
@@ -1,35 +1,24 @@
-use polyte_core::RequestError;
-use reqwest::Client;
+use std::sync::Arc;
+
+use polyte_core::{HttpClient, QueryBuilder};
 use serde::{Deserialize, Serialize};
-use url::Url;
 
 use crate::error::DataApiError;
 
 /// LiveVolume namespace for live volume operations
 #[derive(Clone)]
 pub struct LiveVolumeApi {
-    pub(crate) client: Client,
-    pub(crate) base_url: Url,
+    pub(crate) http: Arc<HttpClient>,
 }
 
 impl LiveVolumeApi {
     /// Get live volume for an event
     pub async fn get(&self, event_id: u64) -> Result<Vec<LiveVolume>, DataApiError> {
-        let url = self.base_url.join("/live-volume")?;
-        let response = self
-            .client
-            .get(url)
-            .query(&[("id", event_id)])
+        self.http
+            .request("/live-volume")
+            .query("id", event_id)
             .send()
-            .await?;
-        let status = response.status();
-
-        if !status.is_success() {
-            return Err(DataApiError::from_response(response).await);
-        }
-
-        let volume: Vec<LiveVolume> = response.json().await?;
-        Ok(volume)
+            .await
     }
 }
 
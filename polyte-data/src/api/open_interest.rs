@@ -1,32 +1,27 @@
-use polyte_core::RequestError;
-use reqwest::Client;
-use url::Url;
+use std::sync::Arc;
+
+use polyte_core::{HttpClient, QueryBuilder, Request};
 
 use crate::{error::DataApiError, types::OpenInterest};
 
 /// OpenInterest namespace for open interest operations
 #[derive(Clone)]
 pub struct OpenInterestApi {
-    pub(crate) client: Client,
-    pub(crate) base_url: Url,
+    pub(crate) http: Arc<HttpClient>,
 }
 
 impl OpenInterestApi {
     /// Get open interest for markets
     pub fn get(&self) -> GetOpenInterest {
         GetOpenInterest {
-            client: self.client.clone(),
-            base_url: self.base_url.clone(),
-            markets: None,
+            request: self.http.request("/oi"),
         }
     }
 }
 
 /// Request builder for getting open interest
 pub struct GetOpenInterest {
-    client: Client,
-    base_url: Url,
-    markets: Option<Vec<String>>,
+    request: Request<Vec<OpenInterest>, DataApiError>,
 }
 
 impl GetOpenInterest {
@@ -34,28 +29,13 @@ impl GetOpenInterest {
     pub fn market(mut self, condition_ids: impl IntoIterator<Item = impl ToString>) -> Self {
         let ids: Vec<String> = condition_ids.into_iter().map(|s| s.to_string()).collect();
         if !ids.is_empty() {
-            self.markets = Some(ids);
+            self.request = self.request.query("market", ids.join(","));
         }
         self
     }
 
     /// Execute the request
     pub async fn send(self) -> Result<Vec<OpenInterest>, DataApiError> {
-        let url = self.base_url.join("/oi")?;
-        let mut request = self.client.get(url);
-
-        if let Some(markets) = self.markets {
-            request = request.query(&[("market", markets.join(","))]);
-        }
-
-        let response = request.send().await?;
-        let status = response.status();
-
-        if !status.is_success() {
-            return Err(DataApiError::from_response(response).await);
-        }
-
-        let oi: Vec<OpenInterest> = response.json().await?;
-        Ok(oi)
+        self.request.send().await
     }
 }
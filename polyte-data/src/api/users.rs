@@ -1,7 +1,9 @@
-use polyte_core::{QueryBuilder, Request, RequestError};
-use reqwest::Client;
-use serde::{Deserialize, Serialize};
-use url::Url;
+use std::{collections::HashSet, future::Future, sync::Arc, time::Duration};
+
+use futures_util::{stream, Stream, TryStreamExt};
+
+use polyte_core::{ApiError, HttpClient, QueryBuilder, Request};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 
 use crate::{
     error::DataApiError,
@@ -11,18 +13,22 @@ use crate::{
     },
 };
 
+/// Page size used by the `stream` methods when no limit is set
+pub const DEFAULT_PAGE_SIZE: u32 = 100;
+/// Largest `offset` accepted by the paginated user endpoints
+pub const MAX_OFFSET: u32 = 10_000;
+
 /// User namespace for user-related operations
 #[derive(Clone)]
 pub struct UserApi {
-    pub(crate) client: Client,
-    pub(crate) base_url: Url,
+    pub(crate) http: Arc<HttpClient>,
     pub(crate) user_address: String,
 }
 
 impl UserApi {
     /// List positions for this user
     pub fn list_positions(&self) -> ListPositions {
-        let mut request = Request::new(self.client.clone(), self.base_url.clone(), "/positions");
+        let mut request = self.http.request("/positions");
         request = request.query("user", &self.user_address);
 
         ListPositions { request }
@@ -30,7 +36,7 @@ impl UserApi {
 
     /// Get total value of this user's positions
     pub fn positions_value(&self) -> GetPositionValue {
-        let mut request = Request::new(self.client.clone(), self.base_url.clone(), "/value");
+        let mut request = self.http.request("/value");
         request = request.query("user", &self.user_address);
 
         GetPositionValue { request }
@@ -38,11 +44,7 @@ impl UserApi {
 
     /// List closed positions for this user
     pub fn closed_positions(&self) -> ListClosedPositions {
-        let mut request = Request::new(
-            self.client.clone(),
-            self.base_url.clone(),
-            "/closed-positions",
-        );
+        let mut request = self.http.request("/closed-positions");
         request = request.query("user", &self.user_address);
 
         ListClosedPositions { request }
@@ -50,7 +52,7 @@ impl UserApi {
 
     /// List trades for this user
     pub fn trades(&self) -> ListUserTrades {
-        let mut request = Request::new(self.client.clone(), self.base_url.clone(), "/trades");
+        let mut request = self.http.request("/trades");
         request = request.query("user", &self.user_address);
 
         ListUserTrades { request }
@@ -58,7 +60,7 @@ impl UserApi {
 
     /// List activity for this user
     pub fn activity(&self) -> ListActivity {
-        let mut request = Request::new(self.client.clone(), self.base_url.clone(), "/activity");
+        let mut request = self.http.request("/activity");
         request = request.query("user", &self.user_address);
 
         ListActivity { request }
@@ -66,21 +68,110 @@ impl UserApi {
 
     /// Get total markets traded by this user
     pub async fn traded(&self) -> Result<UserTraded, DataApiError> {
-        let url = self.base_url.join("/traded")?;
-        let response = self
-            .client
-            .get(url)
-            .query(&[("user", &self.user_address)])
+        self.http
+            .request("/traded")
+            .query("user", &self.user_address)
             .send()
-            .await?;
-        let status = response.status();
+            .await
+    }
+
+    /// Fetch positions, positions value, trades and activity concurrently.
+    ///
+    /// Each call gets its own `timeout_per_call`, so a slow or failing
+    /// endpoint only fails its own section of the snapshot. Timed out calls
+    /// report [`ApiError::Timeout`].
+    pub async fn snapshot(&self, timeout_per_call: Duration) -> UserSnapshot {
+        let (positions, value, trades, activity) = tokio::join!(
+            with_timeout(timeout_per_call, self.list_positions().send()),
+            with_timeout(timeout_per_call, self.positions_value().send()),
+            with_timeout(timeout_per_call, self.trades().send()),
+            with_timeout(timeout_per_call, self.activity().send()),
+        );
 
-        if !status.is_success() {
-            return Err(DataApiError::from_response(response).await);
+        UserSnapshot {
+            positions,
+            value,
+            trades,
+            activity,
         }
+    }
+
+    /// Fetch every open and closed position, the positions value and the
+    /// traded count concurrently, and aggregate them into a summary.
+    ///
+    /// Open and closed positions are paginated to completion. Fails with the
+    /// first error returned by any of the calls.
+    pub async fn portfolio_summary(&self) -> Result<PortfolioSummary, DataApiError> {
+        let (positions, value, closed, traded) = tokio::try_join!(
+            paginate(self.list_positions().request).try_collect::<Vec<_>>(),
+            self.positions_value().send(),
+            paginate(self.closed_positions().request).try_collect::<Vec<_>>(),
+            self.traded(),
+        )?;
 
-        let traded: UserTraded = response.json().await?;
-        Ok(traded)
+        Ok(PortfolioSummary::new(&positions, &value, &closed, &traded))
+    }
+}
+
+/// Run a call, failing with [`ApiError::Timeout`] if it takes longer than `timeout`
+async fn with_timeout<T>(
+    timeout: Duration,
+    call: impl Future<Output = Result<T, DataApiError>>,
+) -> Result<T, DataApiError> {
+    tokio::time::timeout(timeout, call)
+        .await
+        .unwrap_or_else(|_| Err(ApiError::Timeout.into()))
+}
+
+/// Point-in-time view of a user's account, with one result per section
+#[derive(Debug)]
+pub struct UserSnapshot {
+    /// Current positions
+    pub positions: Result<Vec<Position>, DataApiError>,
+    /// Total value of positions
+    pub value: Result<Vec<UserValue>, DataApiError>,
+    /// Recent trades
+    pub trades: Result<Vec<Trade>, DataApiError>,
+    /// Recent activity
+    pub activity: Result<Vec<Activity>, DataApiError>,
+}
+
+impl UserSnapshot {
+    /// Whether every section was fetched successfully
+    pub fn is_complete(&self) -> bool {
+        self.positions.is_ok() && self.value.is_ok() && self.trades.is_ok() && self.activity.is_ok()
+    }
+}
+
+/// Aggregate view of a user's portfolio
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PortfolioSummary {
+    /// Total value of open positions
+    pub total_value: f64,
+    /// Number of open positions
+    pub open_positions: usize,
+    /// Realized profit and loss across closed positions
+    pub realized_pnl: f64,
+    /// Unrealized profit and loss across open positions
+    pub unrealized_pnl: f64,
+    /// Total count of distinct markets traded
+    pub markets_traded: u64,
+}
+
+impl PortfolioSummary {
+    fn new(
+        positions: &[Position],
+        value: &[UserValue],
+        closed: &[ClosedPosition],
+        traded: &UserTraded,
+    ) -> Self {
+        Self {
+            total_value: value.iter().map(|v| v.value).sum(),
+            open_positions: positions.len(),
+            realized_pnl: closed.iter().map(|p| p.realized_pnl).sum(),
+            unrealized_pnl: positions.iter().map(|p| p.cash_pnl).sum(),
+            markets_traded: traded.traded,
+        }
     }
 }
 
@@ -169,6 +260,32 @@ impl ListPositions {
     pub async fn send(self) -> Result<Vec<Position>, DataApiError> {
         self.request.send().await
     }
+
+    /// Stream every matching position, fetching pages on demand.
+    ///
+    /// The configured `limit` is used as the page size ([`DEFAULT_PAGE_SIZE`]
+    /// if unset) and `offset` as the starting point, keeping all other
+    /// filters. The stream ends after the first page with fewer results than
+    /// the page size, after the first error, or once the next offset would
+    /// pass the API's [`MAX_OFFSET`] ceiling.
+    ///
+    /// ```no_run
+    /// use futures_util::StreamExt;
+    /// use polyte_data::DataApi;
+    ///
+    /// # async fn run() -> Result<(), polyte_data::DataApiError> {
+    /// let data = DataApi::new()?;
+    /// let mut positions = data.user("0x...").list_positions().limit(500).stream();
+    ///
+    /// while let Some(position) = positions.next().await {
+    ///     println!("{}", position?.title);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn stream(self) -> impl Stream<Item = Result<Position, DataApiError>> + Send + Unpin {
+        paginate(self.request)
+    }
 }
 
 /// Request builder for getting total position value
@@ -302,6 +419,18 @@ impl ListUserTrades {
         self
     }
 
+    /// Only return trades at or after this Unix timestamp (seconds)
+    pub fn start(mut self, timestamp: i64) -> Self {
+        self.request = self.request.query("start", timestamp);
+        self
+    }
+
+    /// Only return trades at or before this Unix timestamp (seconds)
+    pub fn end(mut self, timestamp: i64) -> Self {
+        self.request = self.request.query("end", timestamp);
+        self
+    }
+
     /// Set maximum number of results (0-10000, default: 100)
     pub fn limit(mut self, limit: u32) -> Self {
         self.request = self.request.query("limit", limit);
@@ -318,6 +447,21 @@ impl ListUserTrades {
     pub async fn send(self) -> Result<Vec<Trade>, DataApiError> {
         self.request.send().await
     }
+
+    /// Stream every matching trade, fetching pages on demand.
+    ///
+    /// Pages the same way as [`ListPositions::stream`].
+    pub fn stream(self) -> impl Stream<Item = Result<Trade, DataApiError>> + Send + Unpin {
+        paginate(self.request)
+    }
+
+    /// Stream every matching trade newest first, paging with a timestamp
+    /// cursor.
+    ///
+    /// Pages the same way as [`ListActivity::cursor_stream`].
+    pub fn cursor_stream(self) -> impl Stream<Item = Result<Trade, DataApiError>> + Send + Unpin {
+        paginate_by_timestamp(self.request)
+    }
 }
 
 /// Request builder for listing user activity
@@ -399,4 +543,419 @@ impl ListActivity {
     pub async fn send(self) -> Result<Vec<Activity>, DataApiError> {
         self.request.send().await
     }
+
+    /// Stream every matching activity, fetching pages on demand.
+    ///
+    /// Pages the same way as [`ListPositions::stream`].
+    pub fn stream(self) -> impl Stream<Item = Result<Activity, DataApiError>> + Send + Unpin {
+        paginate(self.request)
+    }
+
+    /// Stream every matching activity newest first, paging with a timestamp
+    /// cursor.
+    ///
+    /// Offset pages drift when records arrive mid-walk: everything shifts
+    /// down and the next page repeats what was already seen. Here each page
+    /// instead asks for records at or before the oldest timestamp seen so
+    /// far (`end`), skipping the ones already returned at that timestamp,
+    /// so new records neither duplicate nor hide older ones. A configured
+    /// `end` is the starting cursor and `limit` the page size
+    /// ([`DEFAULT_PAGE_SIZE`] if unset); any `offset`, sort field or
+    /// direction is replaced. The stream ends after the first page with
+    /// fewer results than the page size or after the first error.
+    ///
+    /// ```no_run
+    /// use futures_util::StreamExt;
+    /// use polyte_data::DataApi;
+    ///
+    /// # async fn run() -> Result<(), polyte_data::DataApiError> {
+    /// let data = DataApi::new()?;
+    /// let mut activity = data.user("0x...").activity().cursor_stream();
+    ///
+    /// while let Some(activity) = activity.next().await {
+    ///     println!("{}", activity?.timestamp);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn cursor_stream(
+        self,
+    ) -> impl Stream<Item = Result<Activity, DataApiError>> + Send + Unpin {
+        paginate_by_timestamp(
+            self.sort_by(ActivitySortBy::Timestamp)
+                .sort_direction(SortDirection::Desc)
+                .request,
+        )
+    }
+}
+
+/// Walk a list endpoint page by page, advancing `offset` by the page size
+fn paginate<T>(
+    request: Request<Vec<T>, DataApiError>,
+) -> impl Stream<Item = Result<T, DataApiError>> + Send + Unpin
+where
+    T: DeserializeOwned + Send + 'static,
+{
+    polyte_core::paginate(request, DEFAULT_PAGE_SIZE, MAX_OFFSET)
+}
+
+/// Record carrying the timestamp used as cursor by [`paginate_by_timestamp`]
+trait Timestamped {
+    fn timestamp(&self) -> i64;
+}
+
+impl Timestamped for Trade {
+    fn timestamp(&self) -> i64 {
+        self.timestamp
+    }
+}
+
+impl Timestamped for Activity {
+    fn timestamp(&self) -> i64 {
+        self.timestamp
+    }
+}
+
+/// Position of a timestamp-cursor walk
+#[derive(Default)]
+struct TimestampCursor {
+    /// Oldest timestamp seen so far, sent as `end`
+    end: Option<i64>,
+    /// Records already returned at `end`
+    seen: HashSet<String>,
+    /// Offset within `end`, only used when a whole page shares it
+    offset: u32,
+}
+
+/// Walk a newest-first list endpoint by moving its `end` timestamp down to
+/// the oldest record of each page
+fn paginate_by_timestamp<T>(
+    request: Request<Vec<T>, DataApiError>,
+) -> impl Stream<Item = Result<T, DataApiError>> + Send + Unpin
+where
+    T: Timestamped + Serialize + DeserializeOwned + Send + 'static,
+{
+    let page_size = request
+        .query_value::<u32>("limit")
+        .filter(|limit| *limit > 0)
+        .unwrap_or(DEFAULT_PAGE_SIZE);
+    let cursor = TimestampCursor {
+        end: request.query_value::<i64>("end"),
+        ..TimestampCursor::default()
+    };
+
+    let pages = stream::try_unfold(Some(cursor), move |cursor| {
+        let request = request.clone();
+        async move {
+            let Some(mut cursor) = cursor else {
+                return Ok::<_, DataApiError>(None);
+            };
+
+            let mut request = request
+                .set_query("limit", page_size)
+                .set_query("offset", cursor.offset);
+            if let Some(end) = cursor.end {
+                request = request.set_query("end", end);
+            }
+            let page = request.send().await?;
+            let full = page.len() as u32 >= page_size;
+
+            let mut fresh = Vec::with_capacity(page.len());
+            for record in page {
+                let timestamp = record.timestamp();
+                if cursor.end.is_some_and(|end| timestamp > end) {
+                    continue;
+                }
+                if cursor.end != Some(timestamp) {
+                    cursor.end = Some(timestamp);
+                    cursor.seen.clear();
+                }
+                // Records have no ID, their content identifies them
+                let key = serde_json::to_string(&record).unwrap_or_default();
+                if cursor.seen.insert(key) {
+                    fresh.push(record);
+                }
+            }
+
+            // A full page of already returned records means they all share
+            // the cursor timestamp, so step over them with the offset
+            cursor.offset = if fresh.is_empty() {
+                cursor.offset.saturating_add(page_size)
+            } else {
+                0
+            };
+            let next = (full && cursor.offset <= MAX_OFFSET).then_some(cursor);
+
+            Ok(Some((stream::iter(fresh.into_iter().map(Ok)), next)))
+        }
+    });
+
+    Box::pin(pages.try_flatten())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use polyte_core::testing::{MockResponse, MockTransport};
+
+    use super::*;
+    use crate::DataApi;
+
+    fn data(transport: Arc<MockTransport>) -> DataApi {
+        DataApi::builder().transport(transport).build().unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_returns_partial_results() {
+        // Every endpoint answers `[]`, but `/trades` only after the deadline
+        let data = data(MockTransport::new(|request| {
+            let response = MockResponse::ok("[]");
+            match request.path() {
+                "/trades" => response.delay(Duration::from_secs(5)),
+                _ => response,
+            }
+        }));
+
+        let snapshot = data
+            .user("0x0000000000000000000000000000000000000001")
+            .snapshot(Duration::from_millis(500))
+            .await;
+
+        assert!(snapshot.positions.unwrap().is_empty());
+        assert!(snapshot.value.unwrap().is_empty());
+        assert!(snapshot.activity.unwrap().is_empty());
+        assert!(matches!(
+            snapshot.trades,
+            Err(DataApiError::Api(ApiError::Timeout))
+        ));
+    }
+
+    /// Serve `total` positions (unbounded if `None`), paginated by the
+    /// request's `limit` and `offset`, titled with their index and the `user`
+    fn positions(total: Option<usize>) -> Arc<MockTransport> {
+        MockTransport::new(move |request| {
+            let param = |key: &str| request.query(key).unwrap_or_default();
+            let limit: usize = param("limit").parse().unwrap_or(0);
+            let offset: usize = param("offset").parse().unwrap_or(0);
+            let end = total.map_or(offset + limit, |total| total.min(offset + limit));
+
+            let positions: Vec<_> = (offset..end)
+                .map(|i| {
+                    serde_json::json!({
+                        "proxyWallet": param("user"),
+                        "asset": i.to_string(),
+                        "conditionId": "0xabc",
+                        "size": 1.0,
+                        "avgPrice": 0.5,
+                        "initialValue": 0.5,
+                        "currentValue": 0.5,
+                        "cashPnl": 0.0,
+                        "percentPnl": 0.0,
+                        "totalBought": 0.5,
+                        "realizedPnl": 0.0,
+                        "percentRealizedPnl": 0.0,
+                        "curPrice": 0.5,
+                        "redeemable": false,
+                        "mergeable": false,
+                        "title": format!("Position {}", i),
+                        "slug": "",
+                        "outcome": "Yes",
+                        "outcomeIndex": 0,
+                        "oppositeOutcome": "No",
+                        "oppositeAsset": "",
+                        "negativeRisk": false,
+                    })
+                })
+                .collect();
+            MockResponse::json(&positions)
+        })
+    }
+
+    #[tokio::test]
+    async fn test_positions_stream_walks_all_pages() {
+        let data = data(positions(Some(5)));
+        let user = "0x0000000000000000000000000000000000000001";
+
+        let positions: Vec<Position> = data
+            .user(user)
+            .list_positions()
+            .limit(2)
+            .stream()
+            .try_collect()
+            .await
+            .unwrap();
+
+        let assets: Vec<&str> = positions.iter().map(|p| p.asset.as_str()).collect();
+        assert_eq!(assets, ["0", "1", "2", "3", "4"]);
+        assert!(positions.iter().all(|p| p.proxy_wallet == user));
+    }
+
+    #[tokio::test]
+    async fn test_positions_stream_stops_at_offset_ceiling() {
+        let data = data(positions(None));
+
+        let positions: Vec<Position> = data
+            .user("0x0000000000000000000000000000000000000001")
+            .list_positions()
+            .limit(2)
+            .offset(MAX_OFFSET - 4)
+            .stream()
+            .try_collect()
+            .await
+            .unwrap();
+
+        let last = positions.last().unwrap().asset.parse::<u32>().unwrap();
+        assert_eq!(positions.len(), 6);
+        assert_eq!(last, MAX_OFFSET + 1);
+    }
+
+    /// Serve activity newest first, honouring `end`, `limit` and `offset`,
+    /// and record a new activity after every request as a live feed would
+    fn growing_activity(timestamps: &[i64]) -> Arc<MockTransport> {
+        let records: Vec<(i64, String)> = timestamps
+            .iter()
+            .enumerate()
+            .map(|(i, ts)| (*ts, format!("0x{}", i)))
+            .collect();
+        let records = Mutex::new(records);
+
+        MockTransport::new(move |request| {
+            let param = |key: &str| request.query(key).and_then(|v| v.parse::<i64>().ok());
+            let end = param("end").unwrap_or(i64::MAX);
+            let limit = param("limit").unwrap_or(100) as usize;
+            let offset = param("offset").unwrap_or(0) as usize;
+
+            let mut records = records.lock().unwrap();
+            let page: Vec<_> = records
+                .iter()
+                .filter(|(ts, _)| *ts <= end)
+                .skip(offset)
+                .take(limit)
+                .map(|(ts, hash)| {
+                    serde_json::json!({
+                        "proxyWallet": "0x1",
+                        "timestamp": ts,
+                        "conditionId": "0xabc",
+                        "type": "TRADE",
+                        "size": 1.0,
+                        "usdcSize": 0.5,
+                        "transactionHash": hash,
+                    })
+                })
+                .collect();
+            let response = MockResponse::json(&page);
+
+            let newest = records[0].0 + 1;
+            records.insert(0, (newest, format!("0xnew{}", newest)));
+            response
+        })
+    }
+
+    #[tokio::test]
+    async fn test_activity_cursor_stream_survives_inserts() {
+        let timestamps = &[100, 90, 80, 80, 80, 70, 60, 50];
+        let data = data(growing_activity(timestamps));
+
+        let activity: Vec<Activity> = data
+            .user("0x1")
+            .activity()
+            .limit(3)
+            .cursor_stream()
+            .try_collect()
+            .await
+            .unwrap();
+
+        let hashes: Vec<&str> = activity
+            .iter()
+            .map(|a| a.transaction_hash.as_deref().unwrap())
+            .collect();
+        assert_eq!(
+            hashes,
+            ["0x0", "0x1", "0x2", "0x3", "0x4", "0x5", "0x6", "0x7"]
+        );
+    }
+
+    /// Serve a fixed portfolio, answering `/traded` with `traded_status`
+    fn portfolio(traded_status: u16) -> Arc<MockTransport> {
+        let position = |asset: &str, cash_pnl: f64| {
+            serde_json::json!({
+                "proxyWallet": "0x1",
+                "asset": asset,
+                "conditionId": "0xabc",
+                "size": 10.0,
+                "avgPrice": 0.5,
+                "initialValue": 5.0,
+                "currentValue": 5.0 + cash_pnl,
+                "cashPnl": cash_pnl,
+                "percentPnl": 0.0,
+                "totalBought": 5.0,
+                "realizedPnl": 0.0,
+                "percentRealizedPnl": 0.0,
+                "curPrice": 0.5,
+                "redeemable": false,
+                "mergeable": false,
+                "title": "Position",
+                "slug": "",
+                "outcome": "Yes",
+                "outcomeIndex": 0,
+                "oppositeOutcome": "No",
+                "oppositeAsset": "",
+                "negativeRisk": false,
+            })
+        };
+        let positions = serde_json::json!([position("1", 2.5), position("2", -1.0)]);
+
+        MockTransport::new(move |request| match request.path() {
+            "/positions" => MockResponse::json(&positions),
+            "/value" => MockResponse::json(&serde_json::json!([{ "user": "0x1", "value": 16.5 }])),
+            "/closed-positions" => MockResponse::json(&serde_json::json!([{
+                "proxyWallet": "0x1",
+                "asset": "3",
+                "conditionId": "0xdef",
+                "avgPrice": 0.4,
+                "totalBought": 20.0,
+                "realizedPnl": 7.25,
+                "curPrice": 1.0,
+                "timestamp": 1700000000,
+                "title": "Closed",
+                "slug": "",
+                "outcome": "Yes",
+                "outcomeIndex": 0,
+                "oppositeOutcome": "No",
+                "oppositeAsset": "",
+            }])),
+            "/traded" if traded_status == 200 => {
+                MockResponse::json(&serde_json::json!({ "user": "0x1", "traded": 12 }))
+            }
+            _ => MockResponse::status(traded_status, r#"{"error": "unavailable"}"#),
+        })
+    }
+
+    #[tokio::test]
+    async fn test_portfolio_summary() {
+        let data = data(portfolio(200));
+
+        let summary = data.user("0x1").portfolio_summary().await.unwrap();
+
+        assert_eq!(
+            summary,
+            PortfolioSummary {
+                total_value: 16.5,
+                open_positions: 2,
+                realized_pnl: 7.25,
+                unrealized_pnl: 1.5,
+                markets_traded: 12,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_portfolio_summary_surfaces_error() {
+        let data = data(portfolio(400));
+
+        let err = data.user("0x1").portfolio_summary().await.unwrap_err();
+
+        assert!(matches!(err, DataApiError::Response { status: 400, .. }));
+    }
 }
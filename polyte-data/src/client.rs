@@ -1,6 +1,7 @@
-use polyte_core::{HttpClient, HttpClientBuilder, DEFAULT_POOL_SIZE, DEFAULT_TIMEOUT_MS};
-use reqwest::Client;
-use url::Url;
+use std::sync::Arc;
+
+use futures_util::{stream, StreamExt};
+use polyte_core::{HttpClient, HttpClientBuilder, HttpTransport, RateLimit, RetryPolicy};
 
 use crate::{
     api::{
@@ -16,12 +17,13 @@ use crate::{
 };
 
 const DEFAULT_BASE_URL: &str = "https://data-api.polymarket.com";
+/// Maximum number of `/traded` requests in flight for [`DataApi::traded_many`]
+const TRADED_CONCURRENCY: usize = 8;
 
 /// Main Data API client
 #[derive(Clone)]
 pub struct DataApi {
-    pub(crate) client: Client,
-    pub(crate) base_url: Url,
+    pub(crate) http: Arc<HttpClient>,
 }
 
 impl DataApi {
@@ -35,19 +37,23 @@ impl DataApi {
         DataApiBuilder::new()
     }
 
+    /// Rate limit reported by the most recent response that carried
+    /// `x-ratelimit-*` headers, if any
+    pub fn last_rate_limit(&self) -> Option<RateLimit> {
+        self.http.rate_limit.last()
+    }
+
     /// Get health namespace
     pub fn health(&self) -> Health {
         Health {
-            client: self.client.clone(),
-            base_url: self.base_url.clone(),
+            http: self.http.clone(),
         }
     }
 
     /// Get user namespace for user-specific operations
     pub fn user(&self, user_address: impl Into<String>) -> UserApi {
         UserApi {
-            client: self.client.clone(),
-            base_url: self.base_url.clone(),
+            http: self.http.clone(),
             user_address: user_address.into(),
         }
     }
@@ -64,89 +70,149 @@ impl DataApi {
         }
     }
 
+    /// Get total markets traded for several users concurrently.
+    ///
+    /// Results are aligned with `users`; a failing user only fails its own
+    /// entry. At most 8 requests are in flight at once.
+    pub async fn traded_many(
+        &self,
+        users: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Vec<Result<UserTraded, DataApiError>> {
+        let users: Vec<UserApi> = users.into_iter().map(|user| self.user(user)).collect();
+
+        stream::iter(users)
+            .map(|user| async move { user.traded().await })
+            .buffered(TRADED_CONCURRENCY)
+            .collect()
+            .await
+    }
+
     /// Get trades namespace
     pub fn trades(&self) -> Trades {
         Trades {
-            client: self.client.clone(),
-            base_url: self.base_url.clone(),
+            http: self.http.clone(),
         }
     }
 
     /// Get holders namespace
     pub fn holders(&self) -> Holders {
         Holders {
-            client: self.client.clone(),
-            base_url: self.base_url.clone(),
+            http: self.http.clone(),
         }
     }
 
     /// Get open interest namespace
     pub fn open_interest(&self) -> OpenInterestApi {
         OpenInterestApi {
-            client: self.client.clone(),
-            base_url: self.base_url.clone(),
+            http: self.http.clone(),
         }
     }
 
     /// Get live volume namespace
     pub fn live_volume(&self) -> LiveVolumeApi {
         LiveVolumeApi {
-            client: self.client.clone(),
-            base_url: self.base_url.clone(),
+            http: self.http.clone(),
         }
     }
 
     /// Get builders namespace
     pub fn builders(&self) -> BuildersApi {
         BuildersApi {
-            client: self.client.clone(),
-            base_url: self.base_url.clone(),
+            http: self.http.clone(),
         }
     }
 }
 
 /// Builder for configuring Data API client
 pub struct DataApiBuilder {
-    base_url: String,
-    timeout_ms: u64,
-    pool_size: usize,
+    http: HttpClientBuilder,
 }
 
 impl DataApiBuilder {
     fn new() -> Self {
         Self {
-            base_url: DEFAULT_BASE_URL.to_string(),
-            timeout_ms: DEFAULT_TIMEOUT_MS,
-            pool_size: DEFAULT_POOL_SIZE,
+            http: HttpClientBuilder::new(DEFAULT_BASE_URL),
         }
     }
 
     /// Set base URL for the API
     pub fn base_url(mut self, url: impl Into<String>) -> Self {
-        self.base_url = url.into();
+        self.http = self.http.base_url(url);
         self
     }
 
     /// Set request timeout in milliseconds
     pub fn timeout_ms(mut self, timeout: u64) -> Self {
-        self.timeout_ms = timeout;
+        self.http = self.http.timeout_ms(timeout);
         self
     }
 
     /// Set connection pool size
     pub fn pool_size(mut self, size: usize) -> Self {
-        self.pool_size = size;
+        self.http = self.http.pool_size(size);
+        self
+    }
+
+    /// Set the maximum response body size in bytes, see
+    /// [`HttpClientBuilder::max_response_bytes`]
+    pub fn max_response_bytes(mut self, max: usize) -> Self {
+        self.http = self.http.max_response_bytes(max);
+        self
+    }
+
+    /// Set the retry policy for transient failures, see
+    /// [`HttpClientBuilder::retry_policy`]
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.http = self.http.retry_policy(policy);
+        self
+    }
+
+    /// Set the `User-Agent` header, see [`HttpClientBuilder::user_agent`]
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.http = self.http.user_agent(user_agent);
+        self
+    }
+
+    /// Add a header sent with every request, see
+    /// [`HttpClientBuilder::default_header`]
+    pub fn default_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.http = self.http.default_header(name, value);
+        self
+    }
+
+    /// Route every request through an `http://`, `https://`, `socks5://`
+    /// or `socks5h://` proxy, see [`HttpClientBuilder::proxy`]
+    pub fn proxy(mut self, url: impl Into<String>) -> Self {
+        self.http = self.http.proxy(url);
+        self
+    }
+
+    /// Ignore proxies configured through the environment, see
+    /// [`HttpClientBuilder::no_proxy`]
+    pub fn no_proxy(mut self) -> Self {
+        self.http = self.http.no_proxy();
+        self
+    }
+
+    /// Send requests through a custom transport, see
+    /// [`HttpClientBuilder::transport`]
+    pub fn transport(mut self, transport: Arc<dyn HttpTransport>) -> Self {
+        self.http = self.http.transport(transport);
+        self
+    }
+
+    /// Send requests to a fallback base URL when the primary host keeps
+    /// failing, see [`HttpClientBuilder::fallback_base_url`]
+    pub fn fallback_base_url(mut self, url: impl Into<String>) -> Self {
+        self.http = self.http.fallback_base_url(url);
         self
     }
 
     /// Build the Data API client
     pub fn build(self) -> Result<DataApi, DataApiError> {
-        let HttpClient { client, base_url } = HttpClientBuilder::new(&self.base_url)
-            .timeout_ms(self.timeout_ms)
-            .pool_size(self.pool_size)
-            .build()?;
-
-        Ok(DataApi { client, base_url })
+        Ok(DataApi {
+            http: Arc::new(self.http.build()?),
+        })
     }
 }
 
@@ -167,3 +233,96 @@ impl Traded {
         self.user_api.traded().await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use polyte_core::testing::{MockResponse, MockTransport};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_get_uses_shared_retrying_client() {
+        let data = DataApi::builder()
+            .transport(MockTransport::after_unavailable("[]"))
+            .build()
+            .unwrap();
+
+        let trades = data.trades().list().send().await.unwrap();
+        assert!(trades.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_malformed_body_is_decode_error() {
+        let data = DataApi::builder()
+            .transport(MockTransport::after_unavailable(r#"[{"proxyWallet": 42}]"#))
+            .build()
+            .unwrap();
+
+        let err = data.trades().list().send().await.unwrap_err();
+        assert!(
+            matches!(err, DataApiError::Decode { ref body, .. } if body == r#"[{"proxyWallet": 42}]"#)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_last_rate_limit_captured() {
+        let data = DataApi::builder()
+            .transport(MockTransport::rate_limited("[]"))
+            .build()
+            .unwrap();
+        assert_eq!(data.last_rate_limit(), None);
+
+        data.trades().list().send().await.unwrap();
+        assert_eq!(
+            data.last_rate_limit(),
+            Some(RateLimit {
+                limit: Some(100),
+                remaining: Some(42),
+                reset: Some(10),
+            })
+        );
+    }
+
+    /// Answer `/traded?user=<address>` with the address' last byte as count,
+    /// or a 400 for `bad_user`
+    fn traded(bad_user: &'static str) -> Arc<MockTransport> {
+        MockTransport::new(move |request| {
+            let user = request.query("user").unwrap_or_default();
+            if user == bad_user {
+                return MockResponse::status(400, r#"{"error": "invalid user"}"#);
+            }
+
+            let traded = u64::from_str_radix(&user[user.len() - 2..], 16).unwrap();
+            MockResponse::json(&serde_json::json!({ "user": user, "traded": traded }))
+        })
+    }
+
+    #[tokio::test]
+    async fn test_traded_many_partial_failure() {
+        let bad = "0x00000000000000000000000000000000000000ff";
+        let data = DataApi::builder().transport(traded(bad)).build().unwrap();
+
+        let users: Vec<String> = (1..=10)
+            .map(|i| format!("0x{:040x}", i))
+            .chain([bad.to_string()])
+            .collect();
+        let results = data.traded_many(users.clone()).await;
+
+        assert_eq!(results.len(), 11);
+        for (i, (user, result)) in users.iter().zip(&results).take(10).enumerate() {
+            let traded = result.as_ref().unwrap();
+            assert_eq!(&traded.user, user);
+            assert_eq!(traded.traded, i as u64 + 1);
+        }
+        assert!(matches!(
+            results[10],
+            Err(DataApiError::Response { status: 400, ref message }) if message == "invalid user"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_traded_many_empty() {
+        let data = DataApi::new().unwrap();
+        assert!(data.traded_many(Vec::<String>::new()).await.is_empty());
+    }
+}
@@ -1,4 +1,5 @@
-use polyte_core::{ApiError, RequestError};
+use polyte_core::{body_snippet, ApiError, RequestError};
+use serde::Deserialize;
 use thiserror::Error;
 
 /// Error types for Data API operations
@@ -7,11 +8,76 @@ pub enum DataApiError {
     /// Core API error
     #[error(transparent)]
     Api(#[from] ApiError),
+
+    /// Error response returned by the Data API
+    #[error("Data API error: {status} - {message}")]
+    Response { status: u16, message: String },
+
+    /// Response body did not match the expected schema
+    #[error("Failed to decode response: {source}")]
+    Decode {
+        #[source]
+        source: serde_json::Error,
+        /// Start of the raw response body
+        body: String,
+    },
+}
+
+impl DataApiError {
+    /// Create validation error
+    pub(crate) fn validation(msg: impl Into<String>) -> Self {
+        Self::Api(ApiError::Validation(msg.into()))
+    }
+
+    /// Create error from an HTTP status and raw response body
+    pub fn from_status_body(status: u16, body: &str) -> Self {
+        match status {
+            429 => return Self::Api(ApiError::RateLimit),
+            408 => return Self::Api(ApiError::Timeout),
+            _ => {}
+        }
+
+        let message = ApiErrorBody::parse(body).unwrap_or_else(|| {
+            if body.trim().is_empty() {
+                "Unknown error".to_string()
+            } else {
+                body.trim().to_string()
+            }
+        });
+
+        Self::Response { status, message }
+    }
+}
+
+/// Error envelope returned by the Data API (`{"error": ...}` or `{"message": ...}`)
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ApiErrorBody {
+    pub error: Option<String>,
+    pub message: Option<String>,
+}
+
+impl ApiErrorBody {
+    /// Extract the error message from a response body, if it matches the envelope
+    pub fn parse(body: &str) -> Option<String> {
+        let body: Self = serde_json::from_str(body).ok()?;
+        body.error.or(body.message)
+    }
 }
 
 impl RequestError for DataApiError {
     async fn from_response(response: reqwest::Response) -> Self {
-        Self::Api(ApiError::from_response(response).await)
+        let status = response.status().as_u16();
+        match response.text().await {
+            Ok(body) => Self::from_status_body(status, &body),
+            Err(err) => Self::Api(ApiError::Network(err)),
+        }
+    }
+
+    fn from_decode(source: serde_json::Error, body: &[u8]) -> Self {
+        Self::Decode {
+            source,
+            body: body_snippet(body),
+        }
     }
 }
 
@@ -26,3 +92,41 @@ impl From<url::ParseError> for DataApiError {
         Self::Api(ApiError::Url(err))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_envelope() {
+        let err = DataApiError::from_status_body(400, r#"{"error": "invalid user address"}"#);
+        assert!(matches!(
+            err,
+            DataApiError::Response { status: 400, ref message } if message == "invalid user address"
+        ));
+    }
+
+    #[test]
+    fn test_message_envelope() {
+        let err = DataApiError::from_status_body(404, r#"{"message": "market not found"}"#);
+        assert!(matches!(
+            err,
+            DataApiError::Response { status: 404, ref message } if message == "market not found"
+        ));
+    }
+
+    #[test]
+    fn test_non_json_body() {
+        let err = DataApiError::from_status_body(502, "Bad Gateway");
+        assert!(matches!(
+            err,
+            DataApiError::Response { status: 502, ref message } if message == "Bad Gateway"
+        ));
+    }
+
+    #[test]
+    fn test_rate_limit() {
+        let err = DataApiError::from_status_body(429, r#"{"error": "too many requests"}"#);
+        assert!(matches!(err, DataApiError::Api(ApiError::RateLimit)));
+    }
+}
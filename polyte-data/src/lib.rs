@@ -39,4 +39,5 @@ pub mod error;
 pub mod types;
 
 pub use client::{DataApi, DataApiBuilder};
-pub use error::DataApiError;
+pub use error::{ApiErrorBody, DataApiError};
+pub use polyte_core::RetryPolicy;
@@ -1,23 +1,27 @@
-use polyte_core::{QueryBuilder, Request};
-use reqwest::Client;
-use url::Url;
+use std::sync::Arc;
+
+use polyte_core::{HttpClient, QueryBuilder, Request};
 
 use crate::{error::GammaError, types::Comment};
 
 /// Comments namespace for comment-related operations
 #[derive(Clone)]
 pub struct Comments {
-    pub(crate) client: Client,
-    pub(crate) base_url: Url,
+    pub(crate) http: Arc<HttpClient>,
 }
 
 impl Comments {
     /// List comments with optional filtering
     pub fn list(&self) -> ListComments {
         ListComments {
-            request: Request::new(self.client.clone(), self.base_url.clone(), "/comments"),
+            request: self.http.request("/comments"),
         }
     }
+
+    /// List the direct replies to a comment
+    pub fn replies_to(&self, comment_id: impl Into<String>) -> ListComments {
+        self.list().parent_id(comment_id)
+    }
 }
 
 /// Request builder for listing comments
@@ -62,6 +66,27 @@ impl ListComments {
         self
     }
 
+    /// Only comments on a market
+    pub fn market_id(self, id: i64) -> Self {
+        self.parent_entity_type("market").parent_entity_id(id)
+    }
+
+    /// Only comments on an event
+    pub fn event_id(self, id: i64) -> Self {
+        self.parent_entity_type("Event").parent_entity_id(id)
+    }
+
+    /// Only comments on a series
+    pub fn series_id(self, id: i64) -> Self {
+        self.parent_entity_type("Series").parent_entity_id(id)
+    }
+
+    /// Only replies to the given comment
+    pub fn parent_id(mut self, comment_id: impl Into<String>) -> Self {
+        self.request = self.request.query("parent_id", comment_id.into());
+        self
+    }
+
     /// Include position data in response
     pub fn get_positions(mut self, include: bool) -> Self {
         self.request = self.request.query("get_positions", include);
@@ -1,49 +1,44 @@
-use polyte_core::{QueryBuilder, Request};
-use reqwest::Client;
-use url::Url;
+use std::sync::Arc;
 
-use crate::{error::GammaError, types::Event};
+use futures_util::Stream;
+use polyte_core::{paginate, HttpClient, QueryBuilder, Request};
+
+use crate::{api::markets::DEFAULT_PAGE_SIZE, error::GammaError, types::Event};
 
 /// Events namespace for event-related operations
 #[derive(Clone)]
 pub struct Events {
-    pub(crate) client: Client,
-    pub(crate) base_url: Url,
+    pub(crate) http: Arc<HttpClient>,
 }
 
 impl Events {
     /// List events with optional filtering
     pub fn list(&self) -> ListEvents {
         ListEvents {
-            request: Request::new(self.client.clone(), self.base_url.clone(), "/events"),
+            request: self.http.request("/events"),
         }
     }
 
     /// Get an event by ID
     pub fn get(&self, id: impl Into<String>) -> Request<Event, GammaError> {
-        Request::new(
-            self.client.clone(),
-            self.base_url.clone(),
-            format!("/events/{}", urlencoding::encode(&id.into())),
-        )
+        self.http
+            .request(format!("/events/{}", urlencoding::encode(&id.into())))
     }
 
     /// Get an event by slug
     pub fn get_by_slug(&self, slug: impl Into<String>) -> Request<Event, GammaError> {
-        Request::new(
-            self.client.clone(),
-            self.base_url.clone(),
-            format!("/events/slug/{}", urlencoding::encode(&slug.into())),
-        )
+        self.http.request(format!(
+            "/events/slug/{}",
+            urlencoding::encode(&slug.into())
+        ))
     }
 
     /// Get related events by slug
     pub fn get_related_by_slug(&self, slug: impl Into<String>) -> Request<Vec<Event>, GammaError> {
-        Request::new(
-            self.client.clone(),
-            self.base_url.clone(),
-            format!("/events/slug/{}/related", urlencoding::encode(&slug.into())),
-        )
+        self.http.request(format!(
+            "/events/slug/{}/related",
+            urlencoding::encode(&slug.into())
+        ))
     }
 }
 
@@ -89,6 +84,12 @@ impl ListEvents {
         self
     }
 
+    /// Filter by any of several tag identifiers
+    pub fn tag_ids(mut self, tag_ids: impl IntoIterator<Item = i64>) -> Self {
+        self.request = self.request.query_many("tag_id", tag_ids);
+        self
+    }
+
     /// Exclude events with specified tag IDs
     pub fn exclude_tag_id(mut self, tag_ids: impl IntoIterator<Item = i64>) -> Self {
         self.request = self.request.query_many("exclude_tag_id", tag_ids);
@@ -162,29 +163,57 @@ impl ListEvents {
     }
 
     /// Set minimum liquidity threshold
+    ///
+    /// Filters on the aggregate `liquidity` event field (`liquidity_min` query parameter).
     pub fn liquidity_min(mut self, min: f64) -> Self {
         self.request = self.request.query("liquidity_min", min);
         self
     }
 
     /// Set maximum liquidity threshold
+    ///
+    /// Filters on the aggregate `liquidity` event field (`liquidity_max` query parameter).
     pub fn liquidity_max(mut self, max: f64) -> Self {
         self.request = self.request.query("liquidity_max", max);
         self
     }
 
     /// Set minimum trading volume
+    ///
+    /// Filters on the aggregate `volume` event field (`volume_min` query parameter).
     pub fn volume_min(mut self, min: f64) -> Self {
         self.request = self.request.query("volume_min", min);
         self
     }
 
     /// Set maximum trading volume
+    ///
+    /// Filters on the aggregate `volume` event field (`volume_max` query parameter).
     pub fn volume_max(mut self, max: f64) -> Self {
         self.request = self.request.query("volume_max", max);
         self
     }
 
+    /// Set minimum liquidity threshold (alias for [`Self::liquidity_min`])
+    pub fn min_liquidity(self, min: f64) -> Self {
+        self.liquidity_min(min)
+    }
+
+    /// Set maximum liquidity threshold (alias for [`Self::liquidity_max`])
+    pub fn max_liquidity(self, max: f64) -> Self {
+        self.liquidity_max(max)
+    }
+
+    /// Set minimum trading volume (alias for [`Self::volume_min`])
+    pub fn min_volume(self, min: f64) -> Self {
+        self.volume_min(min)
+    }
+
+    /// Set maximum trading volume (alias for [`Self::volume_max`])
+    pub fn max_volume(self, max: f64) -> Self {
+        self.volume_max(max)
+    }
+
     /// Set earliest start date (ISO 8601 format)
     pub fn start_date_min(mut self, date: impl Into<String>) -> Self {
         self.request = self.request.query("start_date_min", date.into());
@@ -213,4 +242,135 @@ impl ListEvents {
     pub async fn send(self) -> Result<Vec<Event>, GammaError> {
         self.request.send().await
     }
+
+    /// Stream every matching event, fetching pages on demand.
+    ///
+    /// Paging works like [`ListMarkets::stream`](super::markets::ListMarkets::stream):
+    /// `limit` is the page size ([`DEFAULT_PAGE_SIZE`] if unset), `offset`
+    /// the starting point, and the stream ends after the first short page or
+    /// the first error.
+    ///
+    /// ```no_run
+    /// use futures_util::StreamExt;
+    /// use polyte_gamma::Gamma;
+    ///
+    /// # async fn run() -> Result<(), polyte_gamma::GammaError> {
+    /// let gamma = Gamma::new()?;
+    /// let mut events = gamma.events().list().active(true).stream();
+    ///
+    /// while let Some(event) = events.next().await {
+    ///     let event = event?;
+    ///     println!("{}: {}", event.id, event.total_market_volume());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn stream(self) -> impl Stream<Item = Result<Event, GammaError>> + Send + Unpin {
+        paginate(self.request, DEFAULT_PAGE_SIZE, u32::MAX)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use futures_util::TryStreamExt;
+    use polyte_core::testing::{MockResponse, MockTransport};
+
+    use super::*;
+    use crate::Gamma;
+
+    /// Serve `total` events paginated by `limit`/`offset`
+    fn events(total: usize) -> Arc<MockTransport> {
+        MockTransport::new(move |request| {
+            let param = |key: &str| {
+                request
+                    .query(key)
+                    .and_then(|v| v.parse::<usize>().ok())
+                    .unwrap_or(0)
+            };
+            let (limit, offset) = (param("limit"), param("offset"));
+
+            let events: Vec<_> = (offset..total.min(offset + limit))
+                .map(|i| serde_json::json!({ "id": i.to_string() }))
+                .collect();
+            MockResponse::json(&events)
+        })
+    }
+
+    #[tokio::test]
+    async fn test_stream_stops_after_short_page() {
+        let transport = events(4);
+        let gamma = Gamma::builder()
+            .transport(transport.clone())
+            .build()
+            .unwrap();
+
+        let events: Vec<Event> = gamma
+            .events()
+            .list()
+            .tag_ids([1, 2])
+            .limit(2)
+            .stream()
+            .try_collect()
+            .await
+            .unwrap();
+
+        let ids: Vec<&str> = events.iter().map(|e| e.id.as_str()).collect();
+        assert_eq!(ids, ["0", "1", "2", "3"]);
+
+        // Two full pages, then an empty one that ends the stream
+        let paths: Vec<String> = transport
+            .requests()
+            .iter()
+            .map(|request| format!("{}?{}", request.path(), request.url.query().unwrap()))
+            .collect();
+        assert_eq!(
+            paths,
+            [
+                "/events?tag_id=1&tag_id=2&limit=2&offset=0",
+                "/events?tag_id=1&tag_id=2&limit=2&offset=2",
+                "/events?tag_id=1&tag_id=2&limit=2&offset=4",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_tag_ids_repeat_the_key() {
+        let list = Gamma::new().unwrap().events().list().tag_ids([1, 2, 3]);
+
+        assert_eq!(
+            list.request.query_params(),
+            [
+                ("tag_id".to_string(), "1".to_string()),
+                ("tag_id".to_string(), "2".to_string()),
+                ("tag_id".to_string(), "3".to_string()),
+            ]
+        );
+    }
+
+    fn keys(list: &ListEvents) -> Vec<&str> {
+        list.request
+            .query_params()
+            .iter()
+            .map(|(key, _)| key.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_liquidity_and_volume_filters() {
+        let list = Gamma::new()
+            .unwrap()
+            .events()
+            .list()
+            .min_liquidity(1_000.0)
+            .max_liquidity(5_000.0)
+            .min_volume(10_000.0)
+            .max_volume(50_000.0);
+
+        assert_eq!(
+            keys(&list),
+            ["liquidity_min", "liquidity_max", "volume_min", "volume_max"]
+        );
+    }
 }
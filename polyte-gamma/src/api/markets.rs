@@ -1,41 +1,89 @@
-use polyte_core::{QueryBuilder, Request};
-use reqwest::Client;
-use url::Url;
+use std::{collections::HashMap, sync::Arc};
+
+use futures_util::{future::try_join_all, Stream};
+use polyte_core::{paginate, HttpClient, QueryBuilder, Request};
 
 use crate::{error::GammaError, types::Market};
 
 /// Markets namespace for market-related operations
 #[derive(Clone)]
 pub struct Markets {
-    pub(crate) client: Client,
-    pub(crate) base_url: Url,
+    pub(crate) http: Arc<HttpClient>,
 }
 
 impl Markets {
     /// Get a specific market by ID
     pub fn get(&self, id: impl Into<String>) -> Request<Market, GammaError> {
-        Request::new(
-            self.client.clone(),
-            self.base_url.clone(),
-            format!("/markets/{}", urlencoding::encode(&id.into())),
-        )
+        self.http
+            .request(format!("/markets/{}", urlencoding::encode(&id.into())))
     }
 
     /// Get a market by its slug
     pub fn get_by_slug(&self, slug: impl Into<String>) -> Request<Market, GammaError> {
-        Request::new(
-            self.client.clone(),
-            self.base_url.clone(),
-            format!("/markets/slug/{}", urlencoding::encode(&slug.into())),
-        )
+        self.http.request(format!(
+            "/markets/slug/{}",
+            urlencoding::encode(&slug.into())
+        ))
     }
 
     /// List markets with optional filtering
     pub fn list(&self) -> ListMarkets {
         ListMarkets {
-            request: Request::new(self.client.clone(), self.base_url.clone(), "/markets"),
+            request: self.http.request("/markets"),
         }
     }
+
+    /// Get several markets by condition ID, keyed by condition ID.
+    ///
+    /// Uses the list endpoint's `condition_ids` filter, splitting the IDs
+    /// into requests of at most [`GET_MANY_CHUNK_SIZE`] sent concurrently.
+    /// IDs with no matching market are left out of the map.
+    pub async fn get_many(
+        &self,
+        condition_ids: impl IntoIterator<Item = impl ToString>,
+    ) -> Result<HashMap<String, Market>, GammaError> {
+        let mut ids: Vec<String> = condition_ids.into_iter().map(|id| id.to_string()).collect();
+        ids.sort();
+        ids.dedup();
+
+        let pages = try_join_all(ids.chunks(GET_MANY_CHUNK_SIZE).map(|chunk| {
+            self.list()
+                .condition_ids(chunk)
+                .limit(chunk.len() as u32)
+                .send()
+        }))
+        .await?;
+
+        Ok(pages
+            .into_iter()
+            .flatten()
+            .map(|market| (market.condition_id.clone(), market))
+            .collect())
+    }
+}
+
+/// Maximum number of condition IDs per request in [`Markets::get_many`]
+pub const GET_MANY_CHUNK_SIZE: usize = 50;
+
+/// Page size used by [`ListMarkets::stream`] when no limit is set
+pub const DEFAULT_PAGE_SIZE: u32 = 100;
+
+/// Curated filters for common market queries, applied with
+/// [`ListMarkets::preset`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MarketPreset {
+    /// Open markets that can currently be traded
+    Tradeable,
+    /// Active markets with high volume (>$100k) sorted by 24h volume
+    Trending,
+    /// Active markets sorted by total volume (descending)
+    TopVolume,
+    /// Active markets with high liquidity (>$50k)
+    HighLiquidity,
+    /// New markets (recently created)
+    New,
+    /// Active competitive markets
+    Competitive,
 }
 
 /// Request builder for listing markets
@@ -44,6 +92,28 @@ pub struct ListMarkets {
 }
 
 impl ListMarkets {
+    /// Apply the filters and ordering of a preset.
+    ///
+    /// Filters set afterwards are added on top of the preset's.
+    pub fn preset(self, preset: MarketPreset) -> Self {
+        match preset {
+            MarketPreset::Tradeable => self.active(true).archived(false),
+            MarketPreset::Trending => self
+                .active(true)
+                .volume_num_min(100_000.0)
+                .order("volume24hr")
+                .ascending(false),
+            MarketPreset::TopVolume => self.active(true).order("volume").ascending(false),
+            MarketPreset::HighLiquidity => self
+                .active(true)
+                .liquidity_num_min(50_000.0)
+                .order("liquidity")
+                .ascending(false),
+            MarketPreset::New => self.active(true).order("startDate").ascending(false),
+            MarketPreset::Competitive => self.active(true).order("competitive").ascending(false),
+        }
+    }
+
     /// Set maximum number of results (minimum: 0)
     pub fn limit(mut self, limit: u32) -> Self {
         self.request = self.request.query("limit", limit);
@@ -102,29 +172,57 @@ impl ListMarkets {
     }
 
     /// Set minimum liquidity threshold
+    ///
+    /// Filters on the numeric `liquidityNum` market field (`liquidity_num_min` query parameter).
     pub fn liquidity_num_min(mut self, min: f64) -> Self {
         self.request = self.request.query("liquidity_num_min", min);
         self
     }
 
     /// Set maximum liquidity threshold
+    ///
+    /// Filters on the numeric `liquidityNum` market field (`liquidity_num_max` query parameter).
     pub fn liquidity_num_max(mut self, max: f64) -> Self {
         self.request = self.request.query("liquidity_num_max", max);
         self
     }
 
     /// Set minimum trading volume
+    ///
+    /// Filters on the numeric `volumeNum` market field (`volume_num_min` query parameter).
     pub fn volume_num_min(mut self, min: f64) -> Self {
         self.request = self.request.query("volume_num_min", min);
         self
     }
 
     /// Set maximum trading volume
+    ///
+    /// Filters on the numeric `volumeNum` market field (`volume_num_max` query parameter).
     pub fn volume_num_max(mut self, max: f64) -> Self {
         self.request = self.request.query("volume_num_max", max);
         self
     }
 
+    /// Set minimum liquidity threshold (alias for [`Self::liquidity_num_min`])
+    pub fn min_liquidity(self, min: f64) -> Self {
+        self.liquidity_num_min(min)
+    }
+
+    /// Set maximum liquidity threshold (alias for [`Self::liquidity_num_max`])
+    pub fn max_liquidity(self, max: f64) -> Self {
+        self.liquidity_num_max(max)
+    }
+
+    /// Set minimum trading volume (alias for [`Self::volume_num_min`])
+    pub fn min_volume(self, min: f64) -> Self {
+        self.volume_num_min(min)
+    }
+
+    /// Set maximum trading volume (alias for [`Self::volume_num_max`])
+    pub fn max_volume(self, max: f64) -> Self {
+        self.volume_num_max(max)
+    }
+
     /// Set earliest market start date (ISO 8601 format)
     pub fn start_date_min(mut self, date: impl Into<String>) -> Self {
         self.request = self.request.query("start_date_min", date.into());
@@ -225,4 +323,272 @@ impl ListMarkets {
     pub async fn send(self) -> Result<Vec<Market>, GammaError> {
         self.request.send().await
     }
+
+    /// Stream every matching market, fetching pages on demand.
+    ///
+    /// The configured `limit` is used as the page size ([`DEFAULT_PAGE_SIZE`]
+    /// if unset) and `offset` as the starting point. Each page advances the
+    /// offset by the page size, and the stream ends after the first page
+    /// with fewer results than the page size, or after the first error.
+    ///
+    /// ```no_run
+    /// use futures_util::StreamExt;
+    /// use polyte_gamma::Gamma;
+    ///
+    /// # async fn run() -> Result<(), polyte_gamma::GammaError> {
+    /// let gamma = Gamma::new()?;
+    /// let mut markets = gamma.markets().list().active(true).limit(50).stream();
+    ///
+    /// while let Some(market) = markets.next().await {
+    ///     println!("{}", market?.question);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn stream(self) -> impl Stream<Item = Result<Market, GammaError>> + Send + Unpin {
+        paginate(self.request, DEFAULT_PAGE_SIZE, u32::MAX)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use futures_util::{StreamExt, TryStreamExt};
+    use polyte_core::testing::{MockResponse, MockTransport};
+
+    use super::*;
+    use crate::Gamma;
+
+    fn gamma(transport: Arc<MockTransport>) -> Gamma {
+        Gamma::builder().transport(transport).build().unwrap()
+    }
+
+    /// Serve `total` markets, paginated by the request's `limit` and `offset`
+    fn markets(total: usize) -> Arc<MockTransport> {
+        MockTransport::new(move |request| {
+            let param = |key: &str| {
+                request
+                    .query(key)
+                    .and_then(|v| v.parse::<usize>().ok())
+                    .unwrap_or(0)
+            };
+            let (limit, offset) = (param("limit"), param("offset"));
+
+            let markets: Vec<_> = (offset..total.min(offset + limit))
+                .map(|i| {
+                    serde_json::json!({
+                        "id": i.to_string(),
+                        "conditionId": "0xabc",
+                        "description": "",
+                        "question": format!("Market {}", i),
+                        "marketMakerAddress": "",
+                    })
+                })
+                .collect();
+            MockResponse::json(&markets)
+        })
+    }
+
+    #[tokio::test]
+    async fn test_stream_walks_all_pages() {
+        let gamma = gamma(markets(5));
+
+        let markets: Vec<Market> = gamma
+            .markets()
+            .list()
+            .limit(2)
+            .stream()
+            .try_collect()
+            .await
+            .unwrap();
+
+        let ids: Vec<&str> = markets.iter().map(|m| m.id.as_str()).collect();
+        assert_eq!(ids, ["0", "1", "2", "3", "4"]);
+    }
+
+    #[tokio::test]
+    async fn test_stream_starts_at_offset() {
+        let gamma = gamma(markets(4));
+
+        let mut stream = gamma.markets().list().limit(2).offset(1).stream();
+        let mut ids = Vec::new();
+        while let Some(market) = stream.next().await {
+            ids.push(market.unwrap().id);
+        }
+
+        assert_eq!(ids, ["1", "2", "3"]);
+    }
+
+    /// Serve the markets among `known` whose condition ID is requested
+    fn condition_ids(known: &'static [&'static str]) -> Arc<MockTransport> {
+        MockTransport::new(move |request| {
+            let markets: Vec<_> = request
+                .url
+                .query_pairs()
+                .filter(|(k, v)| k == "condition_ids" && known.contains(&v.as_ref()))
+                .map(|(_, condition_id)| {
+                    serde_json::json!({
+                        "id": "1",
+                        "conditionId": condition_id,
+                        "description": "",
+                        "question": "",
+                        "marketMakerAddress": "",
+                    })
+                })
+                .collect();
+            MockResponse::json(&markets)
+        })
+    }
+
+    #[tokio::test]
+    async fn test_get_many_omits_missing_ids() {
+        let transport = condition_ids(&["0xa", "0xc"]);
+        let gamma = gamma(transport.clone());
+
+        let markets = gamma
+            .markets()
+            .get_many(["0xc", "0xa", "0xb", "0xa"])
+            .await
+            .unwrap();
+
+        assert_eq!(markets.len(), 2);
+        assert_eq!(markets["0xa"].condition_id, "0xa");
+        assert_eq!(markets["0xc"].condition_id, "0xc");
+        assert!(!markets.contains_key("0xb"));
+
+        assert_eq!(
+            transport.requests()[0].url.query(),
+            Some("condition_ids=0xa&condition_ids=0xb&condition_ids=0xc&limit=3")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_many_splits_requests() {
+        let transport = condition_ids(&[]);
+        let gamma = gamma(transport.clone());
+
+        let ids: Vec<String> = (0..GET_MANY_CHUNK_SIZE + 1)
+            .map(|i| format!("0x{:x}", i))
+            .collect();
+        let markets = gamma.markets().get_many(&ids).await.unwrap();
+        assert!(markets.is_empty());
+
+        let mut limits: Vec<String> = transport
+            .requests()
+            .iter()
+            .map(|request| request.query("limit").unwrap())
+            .collect();
+        limits.sort();
+        assert_eq!(limits, ["1", GET_MANY_CHUNK_SIZE.to_string().as_str()]);
+    }
+
+    #[tokio::test]
+    async fn test_get_many_without_ids() {
+        let transport = condition_ids(&[]);
+        let markets = gamma(transport.clone())
+            .markets()
+            .get_many(Vec::<String>::new())
+            .await
+            .unwrap();
+        assert!(markets.is_empty());
+        assert_eq!(transport.request_count(), 0);
+    }
+
+    fn keys(list: &ListMarkets) -> Vec<&str> {
+        list.request
+            .query_params()
+            .iter()
+            .map(|(key, _)| key.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_liquidity_and_volume_filters_use_num_fields() {
+        let list = Gamma::new()
+            .unwrap()
+            .markets()
+            .list()
+            .min_liquidity(1_000.0)
+            .max_liquidity(5_000.0)
+            .min_volume(10_000.0)
+            .max_volume(50_000.0);
+
+        assert_eq!(
+            keys(&list),
+            [
+                "liquidity_num_min",
+                "liquidity_num_max",
+                "volume_num_min",
+                "volume_num_max"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_presets() {
+        let params = |preset: MarketPreset| -> Vec<(String, String)> {
+            Gamma::new()
+                .unwrap()
+                .markets()
+                .list()
+                .preset(preset)
+                .request
+                .query_params()
+                .to_vec()
+        };
+        let pairs = |pairs: &[(&str, &str)]| -> Vec<(String, String)> {
+            pairs
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect()
+        };
+
+        assert_eq!(
+            params(MarketPreset::Tradeable),
+            pairs(&[("closed", "false"), ("archived", "false")])
+        );
+        assert_eq!(
+            params(MarketPreset::Trending),
+            pairs(&[
+                ("closed", "false"),
+                ("volume_num_min", "100000"),
+                ("order", "volume24hr"),
+                ("ascending", "false")
+            ])
+        );
+        assert_eq!(
+            params(MarketPreset::TopVolume),
+            pairs(&[
+                ("closed", "false"),
+                ("order", "volume"),
+                ("ascending", "false")
+            ])
+        );
+        assert_eq!(
+            params(MarketPreset::HighLiquidity),
+            pairs(&[
+                ("closed", "false"),
+                ("liquidity_num_min", "50000"),
+                ("order", "liquidity"),
+                ("ascending", "false")
+            ])
+        );
+        assert_eq!(
+            params(MarketPreset::New),
+            pairs(&[
+                ("closed", "false"),
+                ("order", "startDate"),
+                ("ascending", "false")
+            ])
+        );
+        assert_eq!(
+            params(MarketPreset::Competitive),
+            pairs(&[
+                ("closed", "false"),
+                ("order", "competitive"),
+                ("ascending", "false")
+            ])
+        );
+    }
 }
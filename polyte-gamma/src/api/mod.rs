@@ -1,6 +1,7 @@
 pub mod comments;
 pub mod events;
 pub mod markets;
+pub mod search;
 pub mod series;
 pub mod sports;
 pub mod tags;
@@ -0,0 +1,75 @@
+use polyte_core::{QueryBuilder, Request};
+
+use crate::{error::GammaError, types::SearchResults};
+
+/// Request builder for full-text search across events, tags and profiles
+pub struct Search {
+    pub(crate) request: Request<SearchResults, GammaError>,
+}
+
+impl Search {
+    /// Set the search text
+    pub fn query(mut self, text: impl Into<String>) -> Self {
+        self.request = self.request.query("q", text.into());
+        self
+    }
+
+    /// Set maximum number of results per result type
+    pub fn limit_per_type(mut self, limit: u32) -> Self {
+        self.request = self.request.query("limit_per_type", limit);
+        self
+    }
+
+    /// Filter events by status (e.g. "active", "closed")
+    pub fn events_status(mut self, status: impl Into<String>) -> Self {
+        self.request = self.request.query("events_status", status.into());
+        self
+    }
+
+    /// Set results page (starting at 1)
+    pub fn page(mut self, page: u32) -> Self {
+        self.request = self.request.query("page", page);
+        self
+    }
+
+    /// Include matching tags in the results
+    pub fn search_tags(mut self, include: bool) -> Self {
+        self.request = self.request.query("search_tags", include);
+        self
+    }
+
+    /// Include matching user profiles in the results
+    pub fn search_profiles(mut self, include: bool) -> Self {
+        self.request = self.request.query("search_profiles", include);
+        self
+    }
+
+    /// Execute the request
+    pub async fn send(self) -> Result<SearchResults, GammaError> {
+        self.request.send().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Gamma;
+
+    #[test]
+    fn test_search_query_params() {
+        let search = Gamma::new()
+            .unwrap()
+            .search()
+            .query("election")
+            .limit_per_type(5)
+            .events_status("active");
+
+        assert_eq!(
+            search.request.query_params(),
+            [
+                ("q".to_string(), "election".to_string()),
+                ("limit_per_type".to_string(), "5".to_string()),
+                ("events_status".to_string(), "active".to_string()),
+            ]
+        );
+    }
+}
@@ -1,31 +1,35 @@
-use polyte_core::{QueryBuilder, Request};
-use reqwest::Client;
-use url::Url;
+use std::sync::Arc;
+
+use polyte_core::{HttpClient, QueryBuilder, Request};
 
 use crate::{error::GammaError, types::SeriesData};
 
 /// Series namespace for series-related operations
 #[derive(Clone)]
 pub struct Series {
-    pub(crate) client: Client,
-    pub(crate) base_url: Url,
+    pub(crate) http: Arc<HttpClient>,
 }
 
 impl Series {
     /// List series with optional filtering
     pub fn list(&self) -> ListSeries {
         ListSeries {
-            request: Request::new(self.client.clone(), self.base_url.clone(), "/series"),
+            request: self.http.request("/series"),
         }
     }
 
     /// Get a series by ID
     pub fn get(&self, id: impl Into<String>) -> Request<SeriesData, GammaError> {
-        Request::new(
-            self.client.clone(),
-            self.base_url.clone(),
-            format!("/series/{}", urlencoding::encode(&id.into())),
-        )
+        self.http
+            .request(format!("/series/{}", urlencoding::encode(&id.into())))
+    }
+
+    /// Get a series by slug
+    pub fn get_by_slug(&self, slug: impl Into<String>) -> Request<SeriesData, GammaError> {
+        self.http.request(format!(
+            "/series/slug/{}",
+            urlencoding::encode(&slug.into())
+        ))
     }
 }
 
@@ -47,6 +51,12 @@ impl ListSeries {
         self
     }
 
+    /// Set order fields (comma-separated list)
+    pub fn order(mut self, order: impl Into<String>) -> Self {
+        self.request = self.request.query("order", order.into());
+        self
+    }
+
     /// Sort in ascending order
     pub fn ascending(mut self, ascending: bool) -> Self {
         self.request = self.request.query("ascending", ascending);
@@ -59,8 +69,88 @@ impl ListSeries {
         self
     }
 
+    /// Filter active series only
+    pub fn active(mut self, active: bool) -> Self {
+        self.request = self.request.query("active", active);
+        self
+    }
+
+    /// Filter archived series
+    pub fn archived(mut self, archived: bool) -> Self {
+        self.request = self.request.query("archived", archived);
+        self
+    }
+
+    /// Filter by tag identifier
+    pub fn tag_id(mut self, tag_id: i64) -> Self {
+        self.request = self.request.query("tag_id", tag_id);
+        self
+    }
+
+    /// Filter by any of several tag identifiers
+    pub fn tag_ids(mut self, tag_ids: impl IntoIterator<Item = i64>) -> Self {
+        self.request = self.request.query_many("tag_id", tag_ids);
+        self
+    }
+
     /// Execute the request
     pub async fn send(self) -> Result<Vec<SeriesData>, GammaError> {
         self.request.send().await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::Gamma;
+
+    fn pair(key: &str, value: &str) -> (String, String) {
+        (key.to_string(), value.to_string())
+    }
+
+    #[test]
+    fn test_list_series_query_params() {
+        let list = Gamma::new()
+            .unwrap()
+            .series()
+            .list()
+            .limit(10)
+            .offset(20)
+            .order("volume")
+            .ascending(false)
+            .archived(false)
+            .tag_ids([1, 2]);
+
+        assert_eq!(
+            list.request.query_params(),
+            [
+                pair("limit", "10"),
+                pair("offset", "20"),
+                pair("order", "volume"),
+                pair("ascending", "false"),
+                pair("archived", "false"),
+                pair("tag_id", "1"),
+                pair("tag_id", "2"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_closed_and_active_coexist() {
+        let list = Gamma::new()
+            .unwrap()
+            .series()
+            .list()
+            .closed(false)
+            .active(true)
+            .tag_id(7);
+
+        assert_eq!(
+            list.request.query_params(),
+            [
+                pair("closed", "false"),
+                pair("active", "true"),
+                pair("tag_id", "7"),
+            ]
+        );
+    }
+}
@@ -1,6 +1,6 @@
-use polyte_core::{QueryBuilder, Request};
-use reqwest::Client;
-use url::Url;
+use std::sync::Arc;
+
+use polyte_core::{HttpClient, QueryBuilder, Request};
 
 use crate::{
     error::GammaError,
@@ -10,20 +10,19 @@ use crate::{
 /// Sport namespace for sports-related operations
 #[derive(Clone)]
 pub struct Sports {
-    pub(crate) client: Client,
-    pub(crate) base_url: Url,
+    pub(crate) http: Arc<HttpClient>,
 }
 
 impl Sports {
     /// Get all sports metadata
     pub fn list(&self) -> Request<Vec<SportMetadata>, GammaError> {
-        Request::new(self.client.clone(), self.base_url.clone(), "/sports")
+        self.http.request("/sports")
     }
 
     /// List teams with optional filtering
     pub fn list_teams(&self) -> ListTeams {
         ListTeams {
-            request: Request::new(self.client.clone(), self.base_url.clone(), "/teams"),
+            request: self.http.request("/teams"),
         }
     }
 }
@@ -1,61 +1,49 @@
-use polyte_core::{QueryBuilder, Request};
-use reqwest::Client;
-use url::Url;
+use std::sync::Arc;
+
+use polyte_core::{HttpClient, QueryBuilder, Request};
 
 use crate::{error::GammaError, types::Tag};
 
 /// Tags namespace for tag-related operations
 #[derive(Clone)]
 pub struct Tags {
-    pub(crate) client: Client,
-    pub(crate) base_url: Url,
+    pub(crate) http: Arc<HttpClient>,
 }
 
 impl Tags {
     /// List tags with optional filtering
     pub fn list(&self) -> ListTags {
         ListTags {
-            request: Request::new(self.client.clone(), self.base_url.clone(), "/tags"),
+            request: self.http.request("/tags"),
         }
     }
 
     /// Get a tag by ID
     pub fn get(&self, id: impl Into<String>) -> Request<Tag, GammaError> {
-        Request::new(
-            self.client.clone(),
-            self.base_url.clone(),
-            format!("/tags/{}", urlencoding::encode(&id.into())),
-        )
+        self.http
+            .request(format!("/tags/{}", urlencoding::encode(&id.into())))
     }
 
     /// Get a tag by slug
     pub fn get_by_slug(&self, slug: impl Into<String>) -> Request<Tag, GammaError> {
-        Request::new(
-            self.client.clone(),
-            self.base_url.clone(),
-            format!("/tags/slug/{}", urlencoding::encode(&slug.into())),
-        )
+        self.http
+            .request(format!("/tags/slug/{}", urlencoding::encode(&slug.into())))
     }
 
     /// Get related tags by tag ID
     pub fn get_related(&self, id: impl Into<String>) -> Request<Vec<Tag>, GammaError> {
-        Request::new(
-            self.client.clone(),
-            self.base_url.clone(),
-            format!("/tags/{}/related-tags", urlencoding::encode(&id.into())),
-        )
+        self.http.request(format!(
+            "/tags/{}/related-tags",
+            urlencoding::encode(&id.into())
+        ))
     }
 
     /// Get related tags by tag slug
     pub fn get_related_by_slug(&self, slug: impl Into<String>) -> Request<Vec<Tag>, GammaError> {
-        Request::new(
-            self.client.clone(),
-            self.base_url.clone(),
-            format!(
-                "/tags/slug/{}/related-tags",
-                urlencoding::encode(&slug.into())
-            ),
-        )
+        self.http.request(format!(
+            "/tags/slug/{}/related-tags",
+            urlencoding::encode(&slug.into())
+        ))
     }
 }
 
@@ -1,11 +1,11 @@
-use polyte_core::{HttpClient, HttpClientBuilder, DEFAULT_POOL_SIZE, DEFAULT_TIMEOUT_MS};
-use reqwest::Client;
-use url::Url;
+use std::sync::Arc;
+
+use polyte_core::{HttpClient, HttpClientBuilder, HttpTransport, RateLimit, RetryPolicy};
 
 use crate::{
     api::{
-        comments::Comments, events::Events, markets::Markets, series::Series, sports::Sports,
-        tags::Tags,
+        comments::Comments, events::Events, markets::Markets, search::Search, series::Series,
+        sports::Sports, tags::Tags,
     },
     error::GammaError,
 };
@@ -15,8 +15,7 @@ const DEFAULT_BASE_URL: &str = "https://gamma-api.polymarket.com";
 /// Main Gamma API client
 #[derive(Clone)]
 pub struct Gamma {
-    pub(crate) client: Client,
-    pub(crate) base_url: Url,
+    pub(crate) http: Arc<HttpClient>,
 }
 
 impl Gamma {
@@ -30,97 +29,152 @@ impl Gamma {
         GammaBuilder::new()
     }
 
+    /// Rate limit reported by the most recent response that carried
+    /// `x-ratelimit-*` headers, if any
+    pub fn last_rate_limit(&self) -> Option<RateLimit> {
+        self.http.rate_limit.last()
+    }
+
     /// Get markets namespace
     pub fn markets(&self) -> Markets {
         Markets {
-            client: self.client.clone(),
-            base_url: self.base_url.clone(),
+            http: self.http.clone(),
         }
     }
 
     /// Get events namespace
     pub fn events(&self) -> Events {
         Events {
-            client: self.client.clone(),
-            base_url: self.base_url.clone(),
+            http: self.http.clone(),
         }
     }
 
     /// Get series namespace
     pub fn series(&self) -> Series {
         Series {
-            client: self.client.clone(),
-            base_url: self.base_url.clone(),
+            http: self.http.clone(),
         }
     }
 
     /// Get tags namespace
     pub fn tags(&self) -> Tags {
         Tags {
-            client: self.client.clone(),
-            base_url: self.base_url.clone(),
+            http: self.http.clone(),
         }
     }
 
     /// Get sports namespace
     pub fn sports(&self) -> Sports {
         Sports {
-            client: self.client.clone(),
-            base_url: self.base_url.clone(),
+            http: self.http.clone(),
         }
     }
 
     /// Get comments namespace
     pub fn comments(&self) -> Comments {
         Comments {
-            client: self.client.clone(),
-            base_url: self.base_url.clone(),
+            http: self.http.clone(),
+        }
+    }
+
+    /// Search events, tags and user profiles by keyword
+    pub fn search(&self) -> Search {
+        Search {
+            request: self.http.request("/public-search"),
         }
     }
 }
 
 /// Builder for configuring Gamma client
 pub struct GammaBuilder {
-    base_url: String,
-    timeout_ms: u64,
-    pool_size: usize,
+    http: HttpClientBuilder,
 }
 
 impl GammaBuilder {
     fn new() -> Self {
         Self {
-            base_url: DEFAULT_BASE_URL.to_string(),
-            timeout_ms: DEFAULT_TIMEOUT_MS,
-            pool_size: DEFAULT_POOL_SIZE,
+            http: HttpClientBuilder::new(DEFAULT_BASE_URL),
         }
     }
 
     /// Set base URL for the API
     pub fn base_url(mut self, url: impl Into<String>) -> Self {
-        self.base_url = url.into();
+        self.http = self.http.base_url(url);
         self
     }
 
     /// Set request timeout in milliseconds
     pub fn timeout_ms(mut self, timeout: u64) -> Self {
-        self.timeout_ms = timeout;
+        self.http = self.http.timeout_ms(timeout);
         self
     }
 
     /// Set connection pool size
     pub fn pool_size(mut self, size: usize) -> Self {
-        self.pool_size = size;
+        self.http = self.http.pool_size(size);
+        self
+    }
+
+    /// Set the maximum response body size in bytes, see
+    /// [`HttpClientBuilder::max_response_bytes`]
+    pub fn max_response_bytes(mut self, max: usize) -> Self {
+        self.http = self.http.max_response_bytes(max);
+        self
+    }
+
+    /// Set the retry policy for transient failures, see
+    /// [`HttpClientBuilder::retry_policy`]
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.http = self.http.retry_policy(policy);
+        self
+    }
+
+    /// Set the `User-Agent` header, see [`HttpClientBuilder::user_agent`]
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.http = self.http.user_agent(user_agent);
+        self
+    }
+
+    /// Add a header sent with every request, see
+    /// [`HttpClientBuilder::default_header`]
+    pub fn default_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.http = self.http.default_header(name, value);
+        self
+    }
+
+    /// Route every request through an `http://`, `https://`, `socks5://`
+    /// or `socks5h://` proxy, see [`HttpClientBuilder::proxy`]
+    pub fn proxy(mut self, url: impl Into<String>) -> Self {
+        self.http = self.http.proxy(url);
+        self
+    }
+
+    /// Ignore proxies configured through the environment, see
+    /// [`HttpClientBuilder::no_proxy`]
+    pub fn no_proxy(mut self) -> Self {
+        self.http = self.http.no_proxy();
+        self
+    }
+
+    /// Send requests through a custom transport, see
+    /// [`HttpClientBuilder::transport`]
+    pub fn transport(mut self, transport: Arc<dyn HttpTransport>) -> Self {
+        self.http = self.http.transport(transport);
+        self
+    }
+
+    /// Send requests to a fallback base URL when the primary host keeps
+    /// failing, see [`HttpClientBuilder::fallback_base_url`]
+    pub fn fallback_base_url(mut self, url: impl Into<String>) -> Self {
+        self.http = self.http.fallback_base_url(url);
         self
     }
 
     /// Build the Gamma client
     pub fn build(self) -> Result<Gamma, GammaError> {
-        let HttpClient { client, base_url } = HttpClientBuilder::new(&self.base_url)
-            .timeout_ms(self.timeout_ms)
-            .pool_size(self.pool_size)
-            .build()?;
-
-        Ok(Gamma { client, base_url })
+        Ok(Gamma {
+            http: Arc::new(self.http.build()?),
+        })
     }
 }
 
@@ -129,3 +183,124 @@ impl Default for GammaBuilder {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use polyte_core::testing::MockTransport;
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpListener,
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_get_uses_shared_retrying_client() {
+        let gamma = Gamma::builder()
+            .transport(MockTransport::after_unavailable("[]"))
+            .build()
+            .unwrap();
+
+        let sports = gamma.sports().list().send().await.unwrap();
+        assert!(sports.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_malformed_body_is_decode_error() {
+        let gamma = Gamma::builder()
+            .transport(MockTransport::after_unavailable("{not json"))
+            .build()
+            .unwrap();
+
+        let err = gamma.sports().list().send().await.unwrap_err();
+        assert!(matches!(err, GammaError::Decode { ref body, .. } if body == "{not json"));
+    }
+
+    #[tokio::test]
+    async fn test_last_rate_limit_captured() {
+        let gamma = Gamma::builder()
+            .transport(MockTransport::rate_limited("[]"))
+            .build()
+            .unwrap();
+        assert_eq!(gamma.last_rate_limit(), None);
+
+        gamma.sports().list().send().await.unwrap();
+        assert_eq!(
+            gamma.last_rate_limit(),
+            Some(RateLimit {
+                limit: Some(100),
+                remaining: Some(42),
+                reset: Some(10),
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_retry_policy_from_builder() {
+        let gamma = Gamma::builder()
+            .transport(MockTransport::after_unavailable("[]"))
+            .retry_policy(RetryPolicy::none())
+            .build()
+            .unwrap();
+
+        let err = gamma.sports().list().send().await.unwrap_err();
+        assert!(matches!(
+            err,
+            GammaError::Api(polyte_core::ApiError::Api { status: 503, .. })
+        ));
+    }
+
+    /// Serve one empty JSON list and hand back the raw request
+    async fn serve_capture() -> (String, tokio::sync::oneshot::Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let (tx, rx) = tokio::sync::oneshot::channel();
+
+        tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut request = [0u8; 4096];
+            let n = socket.read(&mut request).await.unwrap();
+            let _ = tx.send(String::from_utf8_lossy(&request[..n]).to_string());
+
+            let response = "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: 2\r\nConnection: close\r\n\r\n[]";
+            let _ = socket.write_all(response.as_bytes()).await;
+            let _ = socket.shutdown().await;
+        });
+
+        (format!("http://{}", addr), rx)
+    }
+
+    #[tokio::test]
+    async fn test_user_agent_and_default_headers() {
+        let (base_url, request) = serve_capture().await;
+        let gamma = Gamma::builder()
+            .base_url(base_url)
+            .user_agent("my-bot/1.0")
+            .default_header("X-Client-Id", "abc")
+            .build()
+            .unwrap();
+        gamma.sports().list().send().await.unwrap();
+
+        let request = request.await.unwrap().to_lowercase();
+        assert!(request.contains("user-agent: my-bot/1.0\r\n"));
+        assert!(request.contains("x-client-id: abc\r\n"));
+
+        let (base_url, request) = serve_capture().await;
+        let gamma = Gamma::builder().base_url(base_url).build().unwrap();
+        gamma.sports().list().send().await.unwrap();
+
+        let expected = format!("user-agent: {}\r\n", polyte_core::DEFAULT_USER_AGENT);
+        assert!(request.await.unwrap().to_lowercase().contains(&expected));
+    }
+
+    #[test]
+    fn test_invalid_default_header() {
+        let result = Gamma::builder()
+            .default_header("Bad Header", "value")
+            .build();
+        assert!(matches!(
+            result,
+            Err(GammaError::Api(polyte_core::ApiError::Validation(_)))
+        ));
+    }
+}
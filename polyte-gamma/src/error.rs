@@ -1,4 +1,4 @@
-use polyte_core::{ApiError, RequestError};
+use polyte_core::{body_snippet, ApiError, RequestError};
 use thiserror::Error;
 
 /// Error types for gamma API operations
@@ -7,12 +7,28 @@ pub enum GammaError {
     /// Core API error
     #[error(transparent)]
     Api(#[from] ApiError),
+
+    /// Response body did not match the expected schema
+    #[error("Failed to decode response: {source}")]
+    Decode {
+        #[source]
+        source: serde_json::Error,
+        /// Start of the raw response body
+        body: String,
+    },
 }
 
 impl RequestError for GammaError {
     async fn from_response(response: reqwest::Response) -> Self {
         Self::Api(ApiError::from_response(response).await)
     }
+
+    fn from_decode(source: serde_json::Error, body: &[u8]) -> Self {
+        Self::Decode {
+            source,
+            body: body_snippet(body),
+        }
+    }
 }
 
 impl From<reqwest::Error> for GammaError {
@@ -50,3 +50,4 @@ pub mod types;
 
 pub use client::{Gamma, GammaBuilder};
 pub use error::GammaError;
+pub use polyte_core::RetryPolicy;
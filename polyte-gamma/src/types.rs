@@ -1,7 +1,8 @@
 use std::collections::HashMap;
 
-use chrono::{DateTime, Utc};
-use serde::{Deserialize, Serialize};
+use chrono::{DateTime, NaiveDate, Utc};
+use polyte_core::de::{sentinel_as_none, string_or_number};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 
 /// Market data from Gamma API
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -24,17 +25,22 @@ pub struct Market {
     pub min_incentive_size: Option<String>,
     pub max_incentive_spread: Option<String>,
     pub submitted_by: Option<String>,
+    #[serde(default, deserialize_with = "string_or_number")]
     pub volume_24hr: Option<f64>,
+    #[serde(default, deserialize_with = "string_or_number")]
     pub volume_1wk: Option<f64>,
+    #[serde(default, deserialize_with = "string_or_number")]
     pub volume_1mo: Option<f64>,
+    #[serde(default, deserialize_with = "string_or_number")]
     pub volume_1yr: Option<f64>,
-    pub liquidity: Option<String>,
+    #[serde(default, deserialize_with = "string_or_number")]
+    pub liquidity: Option<f64>,
     #[serde(default)]
     pub tags: Vec<Tag>,
     pub neg_risk: Option<bool>,
     pub neg_risk_market_id: Option<String>,
     pub neg_risk_request_id: Option<String>,
-    // Use i64 instead of u64 to prevent sentinel value
+    #[serde(default, deserialize_with = "sentinel_as_none")]
     pub comment_count: Option<i64>,
     pub twitter_card_image: Option<String>,
     pub resolution_source: Option<String>,
@@ -52,7 +58,8 @@ pub struct Market {
     pub upper_bound: Option<String>,
     pub outcomes: Option<String>,
     pub outcome_prices: Option<String>,
-    pub volume: Option<String>,
+    #[serde(default, deserialize_with = "string_or_number")]
+    pub volume: Option<f64>,
     pub active: Option<bool>,
     pub market_type: Option<String>,
     pub format_type: Option<String>,
@@ -60,7 +67,9 @@ pub struct Market {
     pub upper_bound_date: Option<String>,
     pub closed: Option<bool>,
     pub market_maker_address: String,
+    #[serde(default, deserialize_with = "sentinel_as_none")]
     pub created_by: Option<i64>,
+    #[serde(default, deserialize_with = "sentinel_as_none")]
     pub updated_by: Option<i64>,
     pub created_at: Option<String>,
     pub updated_at: Option<String>,
@@ -83,7 +92,9 @@ pub struct Market {
     pub order_price_min_tick_size: Option<f64>,
     pub order_min_size: Option<f64>,
     pub curation_order: Option<i64>,
+    #[serde(default, deserialize_with = "string_or_number")]
     pub volume_num: Option<f64>,
+    #[serde(default, deserialize_with = "string_or_number")]
     pub liquidity_num: Option<f64>,
     pub has_review_dates: Option<bool>,
     pub ready_for_cron: Option<bool>,
@@ -98,17 +109,29 @@ pub struct Market {
     pub uma_bond: Option<String>,
     pub uma_reward: Option<String>,
     pub fpmm_live: Option<bool>,
+    #[serde(default, deserialize_with = "string_or_number")]
     pub volume_24hr_amm: Option<f64>,
+    #[serde(default, deserialize_with = "string_or_number")]
     pub volume_1wk_amm: Option<f64>,
+    #[serde(default, deserialize_with = "string_or_number")]
     pub volume_1mo_amm: Option<f64>,
+    #[serde(default, deserialize_with = "string_or_number")]
     pub volume_1yr_amm: Option<f64>,
+    #[serde(default, deserialize_with = "string_or_number")]
     pub volume_24hr_clob: Option<f64>,
+    #[serde(default, deserialize_with = "string_or_number")]
     pub volume_1wk_clob: Option<f64>,
+    #[serde(default, deserialize_with = "string_or_number")]
     pub volume_1mo_clob: Option<f64>,
+    #[serde(default, deserialize_with = "string_or_number")]
     pub volume_1yr_clob: Option<f64>,
+    #[serde(default, deserialize_with = "string_or_number")]
     pub volume_amm: Option<f64>,
+    #[serde(default, deserialize_with = "string_or_number")]
     pub volume_clob: Option<f64>,
+    #[serde(default, deserialize_with = "string_or_number")]
     pub liquidity_amm: Option<f64>,
+    #[serde(default, deserialize_with = "string_or_number")]
     pub liquidity_clob: Option<f64>,
     pub maker_base_fee: Option<i64>,
     pub taker_base_fee: Option<i64>,
@@ -156,6 +179,316 @@ pub struct Market {
     pub event_start_time: Option<String>,
 }
 
+impl Market {
+    /// Primary category of the market, taken from `category` or the first tag label
+    pub fn primary_category(&self) -> Option<&str> {
+        self.category
+            .as_deref()
+            .filter(|category| !category.is_empty())
+            .or_else(|| self.tags.first().map(|tag| tag.label.as_str()))
+    }
+
+    /// Best available image URL: `image`, then `icon`, then
+    /// `twitterCardImage`, skipping empty values
+    pub fn best_image(&self) -> Option<&str> {
+        first_non_empty([
+            self.image.as_deref(),
+            self.icon.as_deref(),
+            self.twitter_card_image.as_deref(),
+        ])
+    }
+
+    /// Parsed `endDateIso`
+    pub fn end_date(&self) -> Option<DateTime<Utc>> {
+        self.end_date_iso.as_deref().and_then(parse_timestamp)
+    }
+
+    /// Parsed `startDateIso`
+    pub fn start_date(&self) -> Option<DateTime<Utc>> {
+        self.start_date_iso.as_deref().and_then(parse_timestamp)
+    }
+
+    /// Parsed `createdAt`
+    pub fn created(&self) -> Option<DateTime<Utc>> {
+        self.created_at.as_deref().and_then(parse_timestamp)
+    }
+
+    /// Parsed `updatedAt`
+    pub fn updated(&self) -> Option<DateTime<Utc>> {
+        self.updated_at.as_deref().and_then(parse_timestamp)
+    }
+
+    /// Parsed `closedTime`
+    pub fn closed_at(&self) -> Option<DateTime<Utc>> {
+        self.closed_time.as_deref().and_then(parse_timestamp)
+    }
+
+    /// Parsed `gameStartTime`
+    pub fn game_start(&self) -> Option<DateTime<Utc>> {
+        self.game_start_time.as_deref().and_then(parse_timestamp)
+    }
+
+    /// Parsed `umaEndDate`
+    pub fn uma_end(&self) -> Option<DateTime<Utc>> {
+        self.uma_end_date.as_deref().and_then(parse_timestamp)
+    }
+
+    /// Total volume, from `volumeNum` or the `volume` field
+    pub fn total_volume(&self) -> Option<f64> {
+        self.volume_num.or(self.volume)
+    }
+
+    /// Total liquidity, from `liquidityNum` or the `liquidity` field
+    pub fn total_liquidity(&self) -> Option<f64> {
+        self.liquidity_num.or(self.liquidity)
+    }
+
+    /// Outcome labels decoded from the JSON-encoded `outcomes` field.
+    ///
+    /// Returns `None` when the field is missing, empty or malformed.
+    pub fn outcomes_parsed(&self) -> Option<Vec<String>> {
+        parse_json_list(self.outcomes.as_deref())
+    }
+
+    /// Outcome prices decoded from the JSON-encoded `outcomePrices` field.
+    ///
+    /// Returns `None` when the field is missing, empty or malformed.
+    pub fn outcome_prices_parsed(&self) -> Option<Vec<f64>> {
+        let prices: Vec<String> = parse_json_list(self.outcome_prices.as_deref())?;
+        prices.iter().map(|price| price.parse().ok()).collect()
+    }
+
+    /// CLOB token IDs decoded from the JSON-encoded `clobTokenIds` field.
+    ///
+    /// Returns `None` when the field is missing, empty or malformed.
+    pub fn clob_token_ids_parsed(&self) -> Option<Vec<String>> {
+        parse_json_list(self.clob_token_ids.as_deref())
+    }
+
+    /// Resolve a token ID (e.g. a position's `asset`) to its outcome index and label.
+    ///
+    /// Uses `tokens` when present, otherwise the JSON-encoded `clobTokenIds`
+    /// and `outcomes` fields.
+    pub fn outcome_for_token(&self, token_id: &str) -> Option<(usize, String)> {
+        if let Some(index) = self.tokens.iter().position(|t| t.token_id == token_id) {
+            return Some((index, self.tokens[index].outcome.clone()));
+        }
+
+        let token_ids = self.clob_token_ids_parsed()?;
+        let outcomes = self.outcomes_parsed()?;
+
+        let index = token_ids.iter().position(|id| id == token_id)?;
+        let outcome = outcomes.get(index)?.clone();
+        Some((index, outcome))
+    }
+
+    /// Populate `tokens` from the JSON-encoded `clobTokenIds`, `outcomes`
+    /// and `outcomePrices` fields when the API left it empty.
+    ///
+    /// Tokens already present are kept as-is. If the encoded fields are
+    /// missing or malformed, `tokens` stays empty.
+    pub fn ensure_tokens(&mut self) -> &[MarketToken] {
+        if self.tokens.is_empty() {
+            self.tokens = self.tokens_from_encoded_fields().unwrap_or_default();
+        }
+        &self.tokens
+    }
+
+    /// Whether the market belongs to a neg-risk (mutually exclusive) group
+    pub fn is_neg_risk(&self) -> bool {
+        self.neg_risk == Some(true) && self.neg_risk_market_id.is_some()
+    }
+
+    /// Number of outcomes in this market's neg-risk group, counted among
+    /// `markets` sharing its `negRiskMarketId`.
+    ///
+    /// `markets` may or may not include `self`. Returns `None` for markets
+    /// outside a neg-risk group.
+    pub fn neg_risk_outcome_count(&self, markets: &[Market]) -> Option<usize> {
+        if !self.is_neg_risk() {
+            return None;
+        }
+
+        let siblings = markets
+            .iter()
+            .filter(|m| m.condition_id != self.condition_id)
+            .filter(|m| m.is_neg_risk() && m.neg_risk_market_id == self.neg_risk_market_id)
+            .count();
+        Some(siblings + 1)
+    }
+
+    /// Implied probability of the first ("Yes") outcome.
+    ///
+    /// Uses the mid of `bestBid`/`bestAsk`, falling back to the last trade
+    /// price and then to the first encoded outcome price.
+    pub fn implied_probability(&self) -> Option<f64> {
+        match (self.best_bid, self.best_ask) {
+            (Some(bid), Some(ask)) if bid > 0.0 && ask > 0.0 => Some((bid + ask) / 2.0),
+            _ => self
+                .last_trade_price
+                .filter(|price| *price > 0.0)
+                .or_else(|| self.outcome_prices_parsed()?.first().copied()),
+        }
+    }
+
+    /// Token flagged as the winner once the market resolved.
+    ///
+    /// Only looks at `tokens`; [`Market::resolution`] also infers the winner
+    /// from the outcome prices of closed markets.
+    pub fn winning_token(&self) -> Option<&MarketToken> {
+        self.tokens.iter().find(|token| token.is_winner())
+    }
+
+    /// Label of the winning outcome, see [`Market::winning_token`]
+    pub fn resolution_outcome(&self) -> Option<&str> {
+        self.winning_token().map(|token| token.outcome.as_str())
+    }
+
+    /// "Yes" token of a binary market, matched case-insensitively
+    pub fn yes_token(&self) -> Option<&MarketToken> {
+        self.token_for_outcome("yes")
+    }
+
+    /// "No" token of a binary market, matched case-insensitively
+    pub fn no_token(&self) -> Option<&MarketToken> {
+        self.token_for_outcome("no")
+    }
+
+    fn token_for_outcome(&self, outcome: &str) -> Option<&MarketToken> {
+        self.tokens
+            .iter()
+            .find(|token| token.outcome.trim().eq_ignore_ascii_case(outcome))
+    }
+
+    /// Whether the market is live: `active` and neither closed nor archived.
+    ///
+    /// `closed` and `archived` take precedence over `active`, which Gamma
+    /// often leaves set on finished markets. A missing `active` counts as
+    /// inactive.
+    pub fn is_open(&self) -> bool {
+        self.active == Some(true) && !self.is_closed()
+    }
+
+    /// Whether the market is closed or archived, whatever `active` says
+    pub fn is_closed(&self) -> bool {
+        self.closed == Some(true) || self.archived == Some(true)
+    }
+
+    /// Whether the outcome has been settled, see [`Market::resolution`]
+    pub fn is_resolved(&self) -> bool {
+        self.resolution()
+            .is_some_and(|resolution| resolution.is_resolved)
+    }
+
+    /// Whether the market is open and its order book takes new orders.
+    ///
+    /// Missing `enableOrderBook` and `acceptingOrders` flags are not held
+    /// against the market; only an explicit `false` is.
+    pub fn is_accepting_orders(&self) -> bool {
+        self.is_open()
+            && self.enable_order_book != Some(false)
+            && self.accepting_orders != Some(false)
+    }
+
+    /// Resolution details of the market, gathered from its scattered
+    /// resolution fields.
+    ///
+    /// The winning outcome is the token with `winner == Some(true)`, falling
+    /// back to the outcome priced at 1 once the market is closed. Returns
+    /// `None` while the market is open and nothing points to a resolution.
+    pub fn resolution(&self) -> Option<Resolution> {
+        let winner = self
+            .tokens
+            .iter()
+            .position(MarketToken::is_winner)
+            .map(|index| (index, self.tokens[index].outcome.clone()))
+            .or_else(|| self.winner_from_prices());
+        let uma_resolved = self
+            .uma_resolution_status
+            .as_deref()
+            .is_some_and(|status| status.eq_ignore_ascii_case("resolved"));
+        let closed = self.closed == Some(true);
+
+        if !closed && !uma_resolved && winner.is_none() {
+            return None;
+        }
+
+        let (winning_index, winning_outcome) = winner.unzip();
+        Some(Resolution {
+            is_resolved: uma_resolved || winning_index.is_some(),
+            winning_outcome,
+            winning_index,
+            resolved_at: self.closed_at(),
+            source: self.resolution_source.clone().filter(|s| !s.is_empty()),
+            resolved_by: self.resolved_by.clone().filter(|s| !s.is_empty()),
+            uma_status: self.uma_resolution_status.clone(),
+        })
+    }
+
+    /// Winning outcome of a closed market from the encoded outcome prices
+    fn winner_from_prices(&self) -> Option<(usize, String)> {
+        if self.closed != Some(true) {
+            return None;
+        }
+
+        let prices = self.outcome_prices_parsed()?;
+        let index = prices.iter().position(|price| *price == 1.0)?;
+        let outcome = self.outcomes_parsed()?.get(index)?.clone();
+        Some((index, outcome))
+    }
+
+    /// Build tokens by zipping the encoded token IDs, outcomes and prices
+    fn tokens_from_encoded_fields(&self) -> Option<Vec<MarketToken>> {
+        let token_ids = self.clob_token_ids_parsed()?;
+        let outcomes = self.outcomes_parsed()?;
+        let prices: Vec<String> =
+            parse_json_list(self.outcome_prices.as_deref()).unwrap_or_default();
+
+        let tokens = token_ids
+            .into_iter()
+            .zip(outcomes)
+            .enumerate()
+            .map(|(index, (token_id, outcome))| MarketToken {
+                token_id,
+                outcome,
+                price: prices.get(index).cloned(),
+                winner: None,
+            })
+            .collect();
+
+        Some(tokens)
+    }
+}
+
+/// Parse a Gamma timestamp: RFC 3339, `2024-01-01 12:00:00+00` or a bare
+/// date (midnight UTC). Empty strings are `None`.
+fn parse_timestamp(value: &str) -> Option<DateTime<Utc>> {
+    let value = value.trim();
+    if value.is_empty() {
+        return None;
+    }
+
+    DateTime::parse_from_rfc3339(value)
+        .or_else(|_| DateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S%.f%#z"))
+        .map(|time| time.with_timezone(&Utc))
+        .ok()
+        .or_else(|| {
+            NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                .ok()?
+                .and_hms_opt(0, 0, 0)
+                .map(|time| time.and_utc())
+        })
+}
+
+/// Decode a field holding a JSON-encoded array, such as `"[\"Yes\", \"No\"]"`
+fn parse_json_list<T: DeserializeOwned>(value: Option<&str>) -> Option<Vec<T>> {
+    let value = value?.trim();
+    if value.is_empty() {
+        return None;
+    }
+    serde_json::from_str(value).ok()
+}
+
 /// Market token (outcome)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all(deserialize = "camelCase"))]
@@ -166,6 +499,76 @@ pub struct MarketToken {
     pub winner: Option<bool>,
 }
 
+impl MarketToken {
+    /// Whether this token is flagged as the winning outcome
+    pub fn is_winner(&self) -> bool {
+        self.winner == Some(true)
+    }
+}
+
+/// Resolution details of a market, see [`Market::resolution`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Resolution {
+    /// Whether the outcome has been settled
+    pub is_resolved: bool,
+    /// Label of the winning outcome
+    pub winning_outcome: Option<String>,
+    /// Index of the winning outcome
+    pub winning_index: Option<usize>,
+    /// When the market closed
+    pub resolved_at: Option<DateTime<Utc>>,
+    /// Source used to resolve the market
+    pub source: Option<String>,
+    /// Address of the resolver
+    pub resolved_by: Option<String>,
+    /// Raw UMA resolution status
+    pub uma_status: Option<String>,
+}
+
+/// Markets of a neg-risk group, where exactly one outcome resolves YES.
+///
+/// Since the outcomes are mutually exclusive, their implied probabilities
+/// should sum to 1; the gap to 1 is what a neg-risk conversion captures.
+#[derive(Debug, Clone)]
+pub struct NegRiskGroup {
+    /// Shared `negRiskMarketId` of the group
+    pub neg_risk_market_id: String,
+    /// Markets of the group, one per outcome
+    pub markets: Vec<Market>,
+}
+
+impl NegRiskGroup {
+    /// Group neg-risk markets by `negRiskMarketId`, dropping other markets.
+    ///
+    /// Groups are returned in order of first appearance.
+    pub fn from_markets(markets: impl IntoIterator<Item = Market>) -> Vec<NegRiskGroup> {
+        let mut groups: Vec<NegRiskGroup> = Vec::new();
+        for market in markets.into_iter().filter(Market::is_neg_risk) {
+            let id = market.neg_risk_market_id.clone().unwrap_or_default();
+            match groups.iter_mut().find(|g| g.neg_risk_market_id == id) {
+                Some(group) => group.markets.push(market),
+                None => groups.push(NegRiskGroup {
+                    neg_risk_market_id: id,
+                    markets: vec![market],
+                }),
+            }
+        }
+        groups
+    }
+
+    /// Number of outcomes in the group
+    pub fn outcome_count(&self) -> usize {
+        self.markets.len()
+    }
+
+    /// Sum of the implied probabilities of every outcome.
+    ///
+    /// Returns `None` if any market has no price to derive one from.
+    pub fn total_implied_probability(&self) -> Option<f64> {
+        self.markets.iter().map(Market::implied_probability).sum()
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all(deserialize = "camelCase"))]
 pub struct Event {
@@ -189,7 +592,11 @@ pub struct Event {
     pub new: Option<bool>,
     pub featured: Option<bool>,
     pub restricted: Option<bool>,
+    #[serde(default, deserialize_with = "string_or_number")]
     pub liquidity: Option<f64>,
+    #[serde(default, deserialize_with = "string_or_number")]
+    pub volume: Option<f64>,
+    #[serde(default, deserialize_with = "string_or_number")]
     pub open_interest: Option<f64>,
     pub sort_by: Option<String>,
     pub category: Option<String>,
@@ -203,19 +610,21 @@ pub struct Event {
     pub updated_at: Option<String>,
     pub comments_enabled: Option<bool>,
     pub competitive: Option<f64>,
-    #[serde(rename = "volume24h")]
+    #[serde(rename = "volume24h", default, deserialize_with = "string_or_number")]
     pub volume_24hr: Option<f64>,
-    #[serde(rename = "volume1wk")]
+    #[serde(rename = "volume1wk", default, deserialize_with = "string_or_number")]
     pub volume_1wk: Option<f64>,
-    #[serde(rename = "volume1mo")]
+    #[serde(rename = "volume1mo", default, deserialize_with = "string_or_number")]
     pub volume_1mo: Option<f64>,
-    #[serde(rename = "volume1yr")]
+    #[serde(rename = "volume1yr", default, deserialize_with = "string_or_number")]
     pub volume_1yr: Option<f64>,
     pub featured_image: Option<String>,
     pub disqus_thread: Option<String>,
     pub parent_event: Option<String>,
     pub enable_order_book: Option<bool>,
+    #[serde(default, deserialize_with = "string_or_number")]
     pub liquidity_amm: Option<f64>,
+    #[serde(default, deserialize_with = "string_or_number")]
     pub liquidity_clob: Option<f64>,
     pub neg_risk: Option<bool>,
     pub neg_risk_market_id: Option<String>,
@@ -247,6 +656,7 @@ pub struct Event {
     pub ended: Option<bool>,
     pub finished_timestamp: Option<String>,
     pub gmp_chart_mode: Option<String>,
+    #[serde(default, deserialize_with = "sentinel_as_none")]
     pub tweet_count: Option<i64>,
     pub featured_order: Option<i64>,
     pub estimate_value: Option<bool>,
@@ -261,6 +671,59 @@ pub struct Event {
     pub game_status: Option<String>,
 }
 
+impl Event {
+    /// Check whether the event is tagged with the given tag slug
+    pub fn has_tag(&self, slug: &str) -> bool {
+        self.tags.iter().any(|tag| tag.slug == slug)
+    }
+
+    /// Neg-risk group formed by the event's markets, if it is a neg-risk event
+    pub fn neg_risk_group(&self) -> Option<NegRiskGroup> {
+        if self.neg_risk != Some(true) {
+            return None;
+        }
+        NegRiskGroup::from_markets(self.markets.iter().cloned())
+            .into_iter()
+            .next()
+    }
+
+    /// Best available image URL: `image`, then `icon`, skipping empty values
+    pub fn best_image(&self) -> Option<&str> {
+        first_non_empty([self.image.as_deref(), self.icon.as_deref()])
+    }
+
+    /// Total volume, from the event's `volume` or summed across its markets
+    pub fn total_volume(&self) -> f64 {
+        self.volume.unwrap_or_else(|| self.total_market_volume())
+    }
+
+    /// Volume summed across the event's markets, ignoring the event's own
+    /// `volume` field
+    pub fn total_market_volume(&self) -> f64 {
+        self.markets.iter().filter_map(Market::total_volume).sum()
+    }
+
+    /// Total liquidity, from the event's `liquidity` or summed across its markets
+    pub fn total_liquidity(&self) -> f64 {
+        self.liquidity.unwrap_or_else(|| {
+            self.markets
+                .iter()
+                .filter_map(Market::total_liquidity)
+                .sum()
+        })
+    }
+
+    /// Whether the event is active and not closed
+    pub fn is_active(&self) -> bool {
+        self.active == Some(true) && self.closed != Some(true)
+    }
+}
+
+/// First present, non-empty value
+fn first_non_empty<const N: usize>(values: [Option<&str>; N]) -> Option<&str> {
+    values.into_iter().flatten().find(|value| !value.is_empty())
+}
+
 /// Series information within an event
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all(deserialize = "camelCase"))]
@@ -285,13 +748,65 @@ pub struct SeriesData {
     pub archived: bool,
     #[serde(default)]
     pub tags: Vec<String>,
+    #[serde(default, deserialize_with = "string_or_number")]
     pub volume: Option<f64>,
+    #[serde(default, deserialize_with = "string_or_number")]
     pub liquidity: Option<f64>,
     #[serde(default)]
     pub events: Vec<Event>,
     pub competitive: Option<String>,
 }
 
+/// Relative difference above which reported and computed series totals are flagged
+pub const SERIES_TOTAL_TOLERANCE: f64 = 0.05;
+
+impl SeriesData {
+    /// Total volume summed across the series' events
+    pub fn total_volume(&self) -> f64 {
+        self.events.iter().map(Event::total_volume).sum()
+    }
+
+    /// Total liquidity summed across the series' events
+    pub fn total_liquidity(&self) -> f64 {
+        self.events.iter().map(Event::total_liquidity).sum()
+    }
+
+    /// Number of events that are active and not closed
+    pub fn active_event_count(&self) -> usize {
+        self.events.iter().filter(|event| event.is_active()).count()
+    }
+
+    /// Compare the reported `volume`/`liquidity` with the sums across events.
+    ///
+    /// Logs a warning and returns `false` when either differs by more than
+    /// [`SERIES_TOTAL_TOLERANCE`]. Missing reported values are not checked.
+    pub fn reconcile_totals(&self) -> bool {
+        let checks = [
+            ("volume", self.volume, self.total_volume()),
+            ("liquidity", self.liquidity, self.total_liquidity()),
+        ];
+
+        let mut consistent = true;
+        for (field, reported, computed) in checks {
+            let Some(reported) = reported else {
+                continue;
+            };
+            let scale = reported.abs().max(computed.abs());
+            if scale > 0.0 && (reported - computed).abs() / scale > SERIES_TOTAL_TOLERANCE {
+                tracing::warn!(
+                    "Series {} reports {} {} but its events sum to {}",
+                    self.slug,
+                    field,
+                    reported,
+                    computed
+                );
+                consistent = false;
+            }
+        }
+        consistent
+    }
+}
+
 /// Tag for categorizing markets/events
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all(deserialize = "camelCase"))]
@@ -301,7 +816,9 @@ pub struct Tag {
     pub label: String,
     pub force_show: Option<bool>,
     pub published_at: Option<String>,
+    #[serde(default, deserialize_with = "sentinel_as_none")]
     pub created_by: Option<u64>,
+    #[serde(default, deserialize_with = "sentinel_as_none")]
     pub updated_by: Option<u64>,
     pub created_at: Option<String>,
     pub updated_at: Option<String>,
@@ -361,6 +878,86 @@ pub struct Comment {
     pub reply_count: u32,
 }
 
+impl Comment {
+    /// Group a flat list of comments into threads using `parent_id`.
+    ///
+    /// Comments without a parent, or whose parent is not in `comments`,
+    /// become roots. Comments whose parents form a cycle are never dropped:
+    /// each cycle is broken at its earliest comment, which becomes a root
+    /// after the regular ones. Input order is kept among roots and among
+    /// siblings.
+    pub fn thread(comments: Vec<Comment>) -> Vec<CommentThread> {
+        let order: HashMap<String, usize> = comments
+            .iter()
+            .enumerate()
+            .map(|(i, c)| (c.id.clone(), i))
+            .collect();
+        let mut roots = Vec::new();
+        let mut replies: HashMap<String, Vec<Comment>> = HashMap::new();
+
+        for comment in comments {
+            match comment.parent_id.clone() {
+                Some(parent) if parent != comment.id && order.contains_key(&parent) => {
+                    replies.entry(parent).or_default().push(comment)
+                }
+                _ => roots.push(comment),
+            }
+        }
+
+        let mut threads: Vec<CommentThread> = roots
+            .into_iter()
+            .map(|comment| CommentThread::build(comment, &mut replies))
+            .collect();
+
+        // Anything left is unreachable from a root, i.e. part of (or below) a cycle
+        while let Some((parent, index)) = replies
+            .iter()
+            .flat_map(|(parent, children)| {
+                children
+                    .iter()
+                    .enumerate()
+                    .map(move |(i, c)| (parent, i, c))
+            })
+            .min_by_key(|(_, _, c)| order[&c.id])
+            .map(|(parent, i, _)| (parent.clone(), i))
+        {
+            let siblings = replies.get_mut(&parent).expect("parent has replies");
+            let comment = siblings.remove(index);
+            if siblings.is_empty() {
+                replies.remove(&parent);
+            }
+            threads.push(CommentThread::build(comment, &mut replies));
+        }
+
+        threads
+    }
+}
+
+/// Comment with its nested replies, see [`Comment::thread`]
+#[derive(Debug, Clone, Serialize)]
+pub struct CommentThread {
+    pub comment: Comment,
+    pub replies: Vec<CommentThread>,
+}
+
+impl CommentThread {
+    fn build(comment: Comment, replies: &mut HashMap<String, Vec<Comment>>) -> Self {
+        let children = replies.remove(&comment.id).unwrap_or_default();
+        Self {
+            replies: children
+                .into_iter()
+                .map(|child| Self::build(child, replies))
+                .collect(),
+            comment,
+        }
+    }
+
+    /// Number of comments in the thread, including this one
+    pub fn count(&self) -> usize {
+        1 + self.replies.iter().map(CommentThread::count).sum::<usize>()
+    }
+}
+
 /// User who created a comment
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all(deserialize = "camelCase"))]
@@ -387,6 +984,32 @@ pub struct CommentPosition {
     pub shares: String,
 }
 
+/// User profile returned by search
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all(deserialize = "camelCase"))]
+pub struct Profile {
+    pub id: String,
+    pub name: Option<String>,
+    pub pseudonym: Option<String>,
+    pub display_username_public: Option<bool>,
+    pub bio: Option<String>,
+    pub proxy_wallet: Option<String>,
+    pub profile_image: Option<String>,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+}
+
+/// Results of a keyword search
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SearchResults {
+    #[serde(default)]
+    pub events: Vec<Event>,
+    #[serde(default)]
+    pub tags: Vec<Tag>,
+    #[serde(default)]
+    pub profiles: Vec<Profile>,
+}
+
 /// Pagination cursor for list operations
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all(deserialize = "camelCase"))]
@@ -401,3 +1024,706 @@ pub struct PaginatedResponse<T> {
     pub data: Vec<T>,
     pub next_cursor: Option<String>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_market(fields: serde_json::Value) -> Market {
+        let mut json = serde_json::json!({
+            "id": "1",
+            "conditionId": "0xabc",
+            "description": "",
+            "question": "Will it happen?",
+            "marketMakerAddress": "",
+        });
+        json.as_object_mut()
+            .unwrap()
+            .extend(fields.as_object().unwrap().clone());
+        serde_json::from_value(json).unwrap()
+    }
+
+    fn tag(slug: &str, label: &str) -> serde_json::Value {
+        serde_json::json!({ "id": slug, "slug": slug, "label": label })
+    }
+
+    #[test]
+    fn test_sentinel_counts_are_none() {
+        let market = parse_market(serde_json::json!({
+            "commentCount": -1,
+            "createdBy": 18446744073709551615u64,
+            "updatedBy": 12,
+        }));
+
+        assert_eq!(market.comment_count, None);
+        assert_eq!(market.created_by, None);
+        assert_eq!(market.updated_by, Some(12));
+    }
+
+    #[test]
+    fn test_outcome_for_token_from_clob_token_ids() {
+        let market = parse_market(serde_json::json!({
+            "clobTokenIds": "[\"111\", \"222\"]",
+            "outcomes": "[\"Yes\", \"No\"]",
+        }));
+
+        assert_eq!(
+            market.outcome_for_token("111"),
+            Some((0, "Yes".to_string()))
+        );
+        assert_eq!(market.outcome_for_token("222"), Some((1, "No".to_string())));
+        assert_eq!(market.outcome_for_token("333"), None);
+    }
+
+    #[test]
+    fn test_outcome_for_token_from_tokens() {
+        let market = parse_market(serde_json::json!({
+            "tokens": [
+                { "tokenId": "111", "outcome": "Up" },
+                { "tokenId": "222", "outcome": "Down" },
+            ],
+        }));
+
+        assert_eq!(
+            market.outcome_for_token("222"),
+            Some((1, "Down".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_parsed_encoded_fields() {
+        let market = parse_market(serde_json::json!({
+            "outcomes": "[\"Yes\", \"No\"]",
+            "outcomePrices": "[\"0.65\", \"0.35\"]",
+            "clobTokenIds": "[\"111\", \"222\"]",
+        }));
+
+        assert_eq!(
+            market.outcomes_parsed(),
+            Some(vec!["Yes".to_string(), "No".to_string()])
+        );
+        assert_eq!(market.outcome_prices_parsed(), Some(vec![0.65, 0.35]));
+        assert_eq!(
+            market.clob_token_ids_parsed(),
+            Some(vec!["111".to_string(), "222".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_parsed_empty_string_fields() {
+        let market = parse_market(serde_json::json!({
+            "outcomes": "",
+            "outcomePrices": "",
+            "clobTokenIds": "",
+        }));
+
+        assert_eq!(market.outcomes_parsed(), None);
+        assert_eq!(market.outcome_prices_parsed(), None);
+        assert_eq!(market.clob_token_ids_parsed(), None);
+    }
+
+    #[test]
+    fn test_parsed_missing_and_malformed_fields() {
+        let market = parse_market(serde_json::json!({
+            "outcomes": "Yes,No",
+            "outcomePrices": "[\"0.65\", \"abc\"]",
+        }));
+
+        assert_eq!(market.outcomes_parsed(), None);
+        assert_eq!(market.outcome_prices_parsed(), None);
+        assert_eq!(market.clob_token_ids_parsed(), None);
+    }
+
+    #[test]
+    fn test_ensure_tokens_reconstructs_from_encoded_fields() {
+        let mut market = parse_market(serde_json::json!({
+            "tokens": [],
+            "clobTokenIds": "[\"111\", \"222\"]",
+            "outcomes": "[\"Yes\", \"No\"]",
+            "outcomePrices": "[\"0.65\", \"0.35\"]",
+        }));
+
+        let tokens = market.ensure_tokens();
+        assert_eq!(tokens.len(), 2);
+        assert_eq!(tokens[0].token_id, "111");
+        assert_eq!(tokens[0].outcome, "Yes");
+        assert_eq!(tokens[0].price.as_deref(), Some("0.65"));
+        assert_eq!(tokens[1].token_id, "222");
+        assert_eq!(tokens[1].outcome, "No");
+        assert_eq!(tokens[1].price.as_deref(), Some("0.35"));
+    }
+
+    #[test]
+    fn test_ensure_tokens_keeps_populated_tokens() {
+        let mut market = parse_market(serde_json::json!({
+            "tokens": [
+                { "tokenId": "111", "outcome": "Up", "price": "0.4", "winner": false },
+            ],
+            "clobTokenIds": "[\"999\", \"888\"]",
+            "outcomes": "[\"Yes\", \"No\"]",
+        }));
+
+        let tokens = market.ensure_tokens();
+        assert_eq!(tokens.len(), 1);
+        assert_eq!(tokens[0].token_id, "111");
+        assert_eq!(tokens[0].outcome, "Up");
+        assert_eq!(tokens[0].winner, Some(false));
+    }
+
+    #[test]
+    fn test_ensure_tokens_without_prices() {
+        let mut market = parse_market(serde_json::json!({
+            "clobTokenIds": "[\"111\", \"222\"]",
+            "outcomes": "[\"Yes\", \"No\"]",
+        }));
+
+        let tokens = market.ensure_tokens();
+        assert_eq!(tokens.len(), 2);
+        assert!(tokens.iter().all(|t| t.price.is_none()));
+    }
+
+    fn series(volume: f64, liquidity: f64) -> SeriesData {
+        serde_json::from_value(serde_json::json!({
+            "id": "1",
+            "slug": "nba-2025",
+            "title": "NBA 2025",
+            "active": true,
+            "closed": false,
+            "archived": false,
+            "volume": volume,
+            "liquidity": liquidity,
+            "events": [
+                { "id": "10", "active": true, "closed": false, "volume": 1000.0, "liquidity": 200.0 },
+                { "id": "11", "active": true, "closed": true, "volume": 500.0, "liquidity": 0.0 },
+                {
+                    "id": "12",
+                    "active": true,
+                    "markets": [
+                        {
+                            "id": "1",
+                            "conditionId": "0xabc",
+                            "description": "",
+                            "question": "",
+                            "marketMakerAddress": "",
+                            "volumeNum": 250.0,
+                            "liquidity": "50"
+                        }
+                    ]
+                }
+            ]
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_series_rollup() {
+        let series = series(1750.0, 250.0);
+        assert_eq!(series.total_volume(), 1750.0);
+        assert_eq!(series.total_liquidity(), 250.0);
+        assert_eq!(series.active_event_count(), 2);
+        assert!(series.reconcile_totals());
+    }
+
+    #[test]
+    fn test_series_rollup_discrepancy() {
+        assert!(!series(5000.0, 250.0).reconcile_totals());
+    }
+
+    #[test]
+    fn test_event_has_tag() {
+        let event: Event = serde_json::from_value(serde_json::json!({
+            "id": "1",
+            "tags": [tag("politics", "Politics"), tag("us-election", "US Election")],
+        }))
+        .unwrap();
+
+        assert!(event.has_tag("politics"));
+        assert!(event.has_tag("us-election"));
+        assert!(!event.has_tag("sports"));
+    }
+
+    #[test]
+    fn test_event_total_market_volume() {
+        let market = |volume: Option<f64>| {
+            serde_json::json!({
+                "id": "1",
+                "conditionId": "0xabc",
+                "description": "",
+                "question": "Will it happen?",
+                "marketMakerAddress": "",
+                "volumeNum": volume,
+            })
+        };
+        let event: Event = serde_json::from_value(serde_json::json!({
+            "id": "1",
+            "volume": 10.0,
+            "markets": [market(Some(250.5)), market(Some(100.0)), market(None)],
+        }))
+        .unwrap();
+
+        assert_eq!(event.total_market_volume(), 350.5);
+        assert_eq!(event.total_volume(), 10.0);
+    }
+
+    #[test]
+    fn test_market_amounts_as_strings_or_numbers() {
+        let market = |volume: serde_json::Value, liquidity: serde_json::Value| -> Market {
+            serde_json::from_value(serde_json::json!({
+                "id": "1",
+                "conditionId": "0xabc",
+                "description": "",
+                "question": "",
+                "marketMakerAddress": "",
+                "volume": volume,
+                "liquidityNum": liquidity,
+            }))
+            .unwrap()
+        };
+
+        let quoted = market("123.4".into(), "56.7".into());
+        let bare = market(123.4.into(), 56.7.into());
+        for market in [quoted, bare] {
+            assert_eq!(market.volume, Some(123.4));
+            assert_eq!(market.liquidity_num, Some(56.7));
+            assert_eq!(market.total_volume(), Some(123.4));
+            assert_eq!(market.total_liquidity(), Some(56.7));
+        }
+    }
+
+    #[test]
+    fn test_event_has_tag_without_tags() {
+        let event: Event = serde_json::from_value(serde_json::json!({ "id": "1" })).unwrap();
+        assert!(!event.has_tag("politics"));
+    }
+
+    #[test]
+    fn test_primary_category_from_category() {
+        let market = parse_market(serde_json::json!({
+            "category": "Crypto",
+            "tags": [tag("sports", "Sports")],
+        }));
+        assert_eq!(market.primary_category(), Some("Crypto"));
+    }
+
+    #[test]
+    fn test_primary_category_from_first_tag() {
+        let market = parse_market(serde_json::json!({
+            "tags": [tag("sports", "Sports"), tag("nba", "NBA")],
+        }));
+        assert_eq!(market.primary_category(), Some("Sports"));
+    }
+
+    #[test]
+    fn test_primary_category_ignores_empty_category() {
+        let market = parse_market(serde_json::json!({
+            "category": "",
+            "tags": [tag("sports", "Sports")],
+        }));
+        assert_eq!(market.primary_category(), Some("Sports"));
+    }
+
+    #[test]
+    fn test_primary_category_without_tags() {
+        let market = parse_market(serde_json::json!({}));
+        assert_eq!(market.primary_category(), None);
+    }
+
+    #[test]
+    fn test_resolution_winner_from_tokens() {
+        let market = parse_market(serde_json::json!({
+            "closed": true,
+            "closedTime": "2024-11-06 12:30:00+00",
+            "resolutionSource": "https://example.com",
+            "resolvedBy": "0x6A9D222616C90FcA5754cd1333cFD9b7fb6a4F74",
+            "umaResolutionStatus": "resolved",
+            "tokens": [
+                { "tokenId": "111", "outcome": "Yes", "price": "0", "winner": false },
+                { "tokenId": "222", "outcome": "No", "price": "1", "winner": true },
+            ],
+        }));
+
+        let resolution = market.resolution().unwrap();
+        assert!(resolution.is_resolved);
+        assert_eq!(resolution.winning_outcome.as_deref(), Some("No"));
+        assert_eq!(resolution.winning_index, Some(1));
+        assert_eq!(
+            resolution.resolved_at,
+            Some("2024-11-06T12:30:00Z".parse().unwrap())
+        );
+        assert_eq!(resolution.source.as_deref(), Some("https://example.com"));
+        assert_eq!(resolution.uma_status.as_deref(), Some("resolved"));
+    }
+
+    #[test]
+    fn test_resolution_winner_from_prices() {
+        let market = parse_market(serde_json::json!({
+            "closed": true,
+            "outcomes": "[\"Yes\", \"No\"]",
+            "outcomePrices": "[\"1\", \"0\"]",
+        }));
+
+        let resolution = market.resolution().unwrap();
+        assert!(resolution.is_resolved);
+        assert_eq!(resolution.winning_outcome.as_deref(), Some("Yes"));
+        assert_eq!(resolution.winning_index, Some(0));
+    }
+
+    #[test]
+    fn test_resolution_pending() {
+        // Closed but not yet settled
+        let market = parse_market(serde_json::json!({
+            "closed": true,
+            "umaResolutionStatus": "proposed",
+            "outcomes": "[\"Yes\", \"No\"]",
+            "outcomePrices": "[\"0.97\", \"0.03\"]",
+        }));
+
+        let resolution = market.resolution().unwrap();
+        assert!(!resolution.is_resolved);
+        assert_eq!(resolution.winning_outcome, None);
+
+        let open = parse_market(serde_json::json!({ "closed": false }));
+        assert_eq!(open.resolution(), None);
+    }
+
+    fn neg_risk_market(condition_id: &str, group: &str, bid: f64, ask: f64) -> Market {
+        parse_market(serde_json::json!({
+            "conditionId": condition_id,
+            "negRisk": true,
+            "negRiskMarketId": group,
+            "bestBid": bid,
+            "bestAsk": ask,
+        }))
+    }
+
+    #[test]
+    fn test_neg_risk_outcome_count() {
+        let markets = vec![
+            neg_risk_market("0x1", "0xgroup", 0.50, 0.52),
+            neg_risk_market("0x2", "0xgroup", 0.30, 0.32),
+            neg_risk_market("0x3", "0xother", 0.10, 0.12),
+        ];
+
+        assert_eq!(markets[0].neg_risk_outcome_count(&markets), Some(2));
+        assert_eq!(markets[2].neg_risk_outcome_count(&markets), Some(1));
+        assert_eq!(
+            parse_market(serde_json::json!({})).neg_risk_outcome_count(&markets),
+            None
+        );
+    }
+
+    #[test]
+    fn test_neg_risk_group_total_implied_probability() {
+        let groups = NegRiskGroup::from_markets(vec![
+            neg_risk_market("0x1", "0xgroup", 0.50, 0.52),
+            neg_risk_market("0x2", "0xgroup", 0.30, 0.32),
+            parse_market(serde_json::json!({ "conditionId": "0x9" })),
+            neg_risk_market("0x3", "0xgroup", 0.14, 0.18),
+        ]);
+
+        assert_eq!(groups.len(), 1);
+        let group = &groups[0];
+        assert_eq!(group.neg_risk_market_id, "0xgroup");
+        assert_eq!(group.outcome_count(), 3);
+
+        // 0.51 + 0.31 + 0.16
+        let total = group.total_implied_probability().unwrap();
+        assert!((total - 0.98).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_neg_risk_group_missing_price() {
+        let unpriced = parse_market(serde_json::json!({
+            "conditionId": "0x2",
+            "negRisk": true,
+            "negRiskMarketId": "0xgroup",
+        }));
+        let groups = NegRiskGroup::from_markets(vec![
+            neg_risk_market("0x1", "0xgroup", 0.50, 0.52),
+            unpriced,
+        ]);
+
+        assert_eq!(groups[0].total_implied_probability(), None);
+    }
+
+    #[test]
+    fn test_search_results() {
+        let results: SearchResults = serde_json::from_value(serde_json::json!({
+            "events": [{"id": "16167", "title": "Presidential Election Winner 2028"}],
+            "tags": [{"id": "2", "slug": "politics", "label": "Politics", "event_count": 120}],
+            "profiles": [{
+                "id": "42",
+                "name": "trader",
+                "pseudonym": "Quiet-Fox",
+                "proxyWallet": "0x0000000000000000000000000000000000000001"
+            }]
+        }))
+        .unwrap();
+
+        assert_eq!(results.events[0].id, "16167");
+        assert_eq!(results.tags[0].slug, "politics");
+        assert_eq!(results.profiles[0].pseudonym.as_deref(), Some("Quiet-Fox"));
+        assert!(results.profiles[0].proxy_wallet.is_some());
+
+        let empty: SearchResults = serde_json::from_str(r#"{"events": []}"#).unwrap();
+        assert!(empty.tags.is_empty() && empty.profiles.is_empty());
+    }
+
+    #[test]
+    fn test_market_best_image_precedence() {
+        let cases = [
+            (Some("image"), Some("icon"), Some("card"), Some("image")),
+            (None, Some("icon"), Some("card"), Some("icon")),
+            (Some(""), Some("icon"), None, Some("icon")),
+            (None, None, Some("card"), Some("card")),
+            (Some(""), Some(""), Some("card"), Some("card")),
+            (Some("image"), None, None, Some("image")),
+            (None, None, None, None),
+            (Some(""), None, Some(""), None),
+        ];
+
+        for (image, icon, card, expected) in cases {
+            let market = parse_market(serde_json::json!({
+                "image": image,
+                "icon": icon,
+                "twitterCardImage": card,
+            }));
+            assert_eq!(
+                market.best_image(),
+                expected,
+                "{:?} {:?} {:?}",
+                image,
+                icon,
+                card
+            );
+        }
+    }
+
+    #[test]
+    fn test_event_best_image_precedence() {
+        let cases = [
+            (Some("image"), Some("icon"), Some("image")),
+            (None, Some("icon"), Some("icon")),
+            (Some(""), Some("icon"), Some("icon")),
+            (None, None, None),
+        ];
+
+        for (image, icon, expected) in cases {
+            let event: Event = serde_json::from_value(serde_json::json!({
+                "id": "1",
+                "image": image,
+                "icon": icon,
+            }))
+            .unwrap();
+            assert_eq!(event.best_image(), expected);
+        }
+    }
+
+    #[test]
+    fn test_market_dates() {
+        let market = parse_market(serde_json::json!({
+            "endDateIso": "2024-11-05",
+            "startDateIso": "2024-01-04T22:58:00Z",
+            "createdAt": "2024-01-04T22:58:00.123456Z",
+            "updatedAt": "2024-11-06T01:30:00+02:00",
+            "closedTime": "2024-11-06 12:30:00+00",
+            "gameStartTime": "2024-11-05 23:00:00.5+00",
+            "umaEndDate": "",
+        }));
+
+        let utc = |s: &str| s.parse::<DateTime<Utc>>().unwrap();
+        assert_eq!(market.end_date(), Some(utc("2024-11-05T00:00:00Z")));
+        assert_eq!(market.start_date(), Some(utc("2024-01-04T22:58:00Z")));
+        assert_eq!(market.created(), Some(utc("2024-01-04T22:58:00.123456Z")));
+        assert_eq!(market.updated(), Some(utc("2024-11-05T23:30:00Z")));
+        assert_eq!(market.closed_at(), Some(utc("2024-11-06T12:30:00Z")));
+        assert_eq!(market.game_start(), Some(utc("2024-11-05T23:00:00.5Z")));
+        assert_eq!(market.uma_end(), None);
+        assert_eq!(market.end_date_iso.as_deref(), Some("2024-11-05"));
+    }
+
+    #[test]
+    fn test_market_dates_missing_or_malformed() {
+        let market = parse_market(serde_json::json!({
+            "endDateIso": "  ",
+            "startDateIso": "soon",
+        }));
+
+        assert_eq!(market.end_date(), None);
+        assert_eq!(market.start_date(), None);
+        assert_eq!(market.created(), None);
+    }
+
+    fn comment(id: &str, parent_id: Option<&str>) -> Comment {
+        serde_json::from_value(serde_json::json!({
+            "id": id,
+            "body": format!("comment {}", id),
+            "createdAt": "2024-01-01T00:00:00Z",
+            "updatedAt": "2024-01-01T00:00:00Z",
+            "user": { "id": "1", "name": "alice" },
+            "parentId": parent_id,
+            "likeCount": 0,
+            "dislikeCount": 0,
+            "replyCount": 0,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_comment_thread() {
+        let comments = vec![
+            comment("3", Some("1")),
+            comment("1", None),
+            comment("4", Some("3")),
+            comment("2", None),
+            comment("5", Some("1")),
+            comment("6", Some("missing")),
+        ];
+
+        let threads = Comment::thread(comments);
+        let roots: Vec<&str> = threads.iter().map(|t| t.comment.id.as_str()).collect();
+        assert_eq!(roots, ["1", "2", "6"]);
+
+        let first = &threads[0];
+        let replies: Vec<&str> = first
+            .replies
+            .iter()
+            .map(|t| t.comment.id.as_str())
+            .collect();
+        assert_eq!(replies, ["3", "5"]);
+        assert_eq!(first.replies[0].replies[0].comment.id, "4");
+        assert_eq!(first.count(), 4);
+        assert!(threads[1].replies.is_empty());
+        assert_eq!(threads.iter().map(CommentThread::count).sum::<usize>(), 6);
+    }
+
+    #[test]
+    fn test_comment_thread_keeps_cycles() {
+        let comments = vec![
+            comment("1", None),
+            comment("3", Some("2")),
+            comment("2", Some("3")),
+            comment("4", Some("2")),
+            comment("5", Some("5")),
+        ];
+
+        let threads = Comment::thread(comments);
+        let roots: Vec<&str> = threads.iter().map(|t| t.comment.id.as_str()).collect();
+        assert_eq!(roots, ["1", "5", "3"]);
+
+        let cycle = &threads[2];
+        assert_eq!(cycle.replies[0].comment.id, "2");
+        assert_eq!(cycle.replies[0].replies[0].comment.id, "4");
+        assert_eq!(threads.iter().map(CommentThread::count).sum::<usize>(), 5);
+    }
+
+    #[test]
+    fn test_closed_overrides_active() {
+        let market = parse_market(serde_json::json!({ "active": true, "closed": true }));
+        assert!(!market.is_open());
+        assert!(market.is_closed());
+        assert!(!market.is_accepting_orders());
+
+        let market = parse_market(serde_json::json!({ "active": true, "archived": true }));
+        assert!(!market.is_open());
+        assert!(market.is_closed());
+    }
+
+    #[test]
+    fn test_open_market_lifecycle() {
+        let market = parse_market(serde_json::json!({ "active": true, "closed": false }));
+        assert!(market.is_open());
+        assert!(!market.is_closed());
+        assert!(!market.is_resolved());
+        assert!(market.is_accepting_orders());
+
+        let paused = parse_market(serde_json::json!({
+            "active": true,
+            "closed": false,
+            "acceptingOrders": false,
+        }));
+        assert!(paused.is_open());
+        assert!(!paused.is_accepting_orders());
+
+        // Without `active` the market is not considered open
+        let unknown = parse_market(serde_json::json!({}));
+        assert!(!unknown.is_open());
+        assert!(!unknown.is_closed());
+    }
+
+    #[test]
+    fn test_closed_market_resolution_state() {
+        let pending = parse_market(serde_json::json!({
+            "active": true,
+            "closed": true,
+            "outcomes": "[\"Yes\", \"No\"]",
+            "outcomePrices": "[\"0.5\", \"0.5\"]",
+        }));
+        assert!(pending.is_closed());
+        assert!(!pending.is_resolved());
+
+        let resolved = parse_market(serde_json::json!({
+            "active": true,
+            "closed": true,
+            "outcomes": "[\"Yes\", \"No\"]",
+            "outcomePrices": "[\"1\", \"0\"]",
+        }));
+        assert!(resolved.is_resolved());
+        assert!(!resolved.is_open());
+    }
+
+    #[test]
+    fn test_winning_token_of_resolved_market() {
+        let market = parse_market(serde_json::json!({
+            "closed": true,
+            "tokens": [
+                { "tokenId": "111", "outcome": "YES", "price": "0", "winner": false },
+                { "tokenId": "222", "outcome": "No", "price": "1", "winner": true },
+            ],
+        }));
+
+        assert_eq!(market.winning_token().unwrap().token_id, "222");
+        assert_eq!(market.resolution_outcome(), Some("No"));
+        assert_eq!(market.yes_token().unwrap().token_id, "111");
+        assert_eq!(market.no_token().unwrap().token_id, "222");
+        assert!(!market.yes_token().unwrap().is_winner());
+    }
+
+    #[test]
+    fn test_no_winner_flagged() {
+        let open = parse_market(serde_json::json!({
+            "tokens": [
+                { "tokenId": "111", "outcome": "Yes", "price": "0.4" },
+                { "tokenId": "222", "outcome": "No", "price": "0.6", "winner": false },
+            ],
+        }));
+        assert!(open.winning_token().is_none());
+        assert_eq!(open.resolution_outcome(), None);
+
+        // Closed at a price of 1 but no token flagged: only `resolution`
+        // infers the winner
+        let closed = parse_market(serde_json::json!({
+            "closed": true,
+            "outcomes": "[\"Yes\", \"No\"]",
+            "outcomePrices": "[\"1\", \"0\"]",
+        }));
+        assert_eq!(closed.resolution_outcome(), None);
+        assert!(closed.yes_token().is_none());
+        assert_eq!(
+            closed.resolution().unwrap().winning_outcome.as_deref(),
+            Some("Yes")
+        );
+    }
+
+    #[test]
+    fn test_yes_no_tokens_of_categorical_market() {
+        let market = parse_market(serde_json::json!({
+            "tokens": [
+                { "tokenId": "111", "outcome": "Up" },
+                { "tokenId": "222", "outcome": "Down" },
+            ],
+        }));
+        assert!(market.yes_token().is_none());
+        assert!(market.no_token().is_none());
+    }
+}
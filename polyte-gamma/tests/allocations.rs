@@ -0,0 +1,56 @@
+//! Allocation checks, kept in their own test binary so the counting global
+//! allocator does not replace the allocator of the unit tests.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+    hint::black_box,
+};
+
+use polyte_gamma::Gamma;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+/// System allocator counting allocations made by each thread
+struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn allocations() -> usize {
+    ALLOCATIONS.with(Cell::get)
+}
+
+#[test]
+fn test_namespace_access_does_not_allocate() {
+    let gamma = Gamma::new().unwrap();
+
+    let before = allocations();
+    for _ in 0..1_000 {
+        black_box(gamma.markets());
+        black_box(gamma.events());
+        black_box(gamma.series());
+        black_box(gamma.tags());
+        black_box(gamma.sports());
+        black_box(gamma.comments());
+    }
+    assert_eq!(allocations() - before, 0);
+
+    // Requests still allocate their own path and query
+    let before = allocations();
+    black_box(gamma.markets().list());
+    assert!(allocations() > before);
+}
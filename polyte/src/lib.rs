@@ -47,13 +47,8 @@
 //!     // Use CLOB API to place an order
 //!     if let Some(first_market) = markets.first() {
 //!         if let Some(token) = first_market.tokens.first() {
-//!             let order_params = CreateOrderParams {
-//!                 token_id: token.token_id.clone(),
-//!                 price: 0.52,
-//!                 size: 100.0,
-//!                 side: OrderSide::Buy,
-//!                 expiration: None,
-//!             };
+//!             let order_params =
+//!                 CreateOrderParams::new(token.token_id.clone(), 0.52, 100.0, OrderSide::Buy);
 //!
 //!             let response = polymarket.clob.place_order(&order_params).await?;
 //!             println!("Order placed: {:?}", response.order_id);
@@ -72,7 +67,7 @@ pub use polyte_data;
 pub use polyte_gamma;
 
 #[cfg(all(feature = "clob", feature = "gamma", feature = "data"))]
-use polyte_clob::{Account, Chain, Clob, ClobBuilder};
+use polyte_clob::{Account, Chain, Clob, ClobBuilder, RetryPolicy};
 #[cfg(all(feature = "clob", feature = "gamma", feature = "data"))]
 use polyte_data::{DataApi, DataApiBuilder};
 #[cfg(all(feature = "clob", feature = "gamma", feature = "data"))]
@@ -84,7 +79,8 @@ pub mod prelude {
     pub use polyte_clob::ws;
     #[cfg(feature = "clob")]
     pub use polyte_clob::{
-        Account, Chain, Clob, ClobBuilder, ClobError, CreateOrderParams, Credentials, OrderSide,
+        Account, AssetType, Chain, Clob, ClobBuilder, ClobError, CreateOrderParams, Credentials,
+        OrderSide, OrderType, RetryPolicy,
     };
     #[cfg(feature = "data")]
     pub use polyte_data::{DataApi, DataApiError};
@@ -150,6 +146,8 @@ pub struct PolymarketBuilder {
     gamma_base_url: Option<String>,
     data_base_url: Option<String>,
     timeout_ms: Option<u64>,
+    pool_size: Option<usize>,
+    retry: Option<RetryPolicy>,
     chain: Option<Chain>,
     account: Account,
 }
@@ -162,6 +160,8 @@ impl PolymarketBuilder {
             gamma_base_url: None,
             data_base_url: None,
             timeout_ms: None,
+            pool_size: None,
+            retry: None,
             chain: None,
             account,
         }
@@ -191,7 +191,22 @@ impl PolymarketBuilder {
         self
     }
 
-    /// Set chain
+    /// Set the connection pool size used by all three clients
+    pub fn pool_size(mut self, size: usize) -> Self {
+        self.pool_size = Some(size);
+        self
+    }
+
+    /// Set the retry policy used by all three clients
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry = Some(policy);
+        self
+    }
+
+    /// Set chain.
+    ///
+    /// Base URLs that are not set explicitly default to the chain's
+    /// [`Endpoints`](polyte_clob::Endpoints).
     pub fn chain(mut self, chain: Chain) -> Self {
         self.chain = Some(chain);
         self
@@ -199,15 +214,26 @@ impl PolymarketBuilder {
 
     /// Build the Polymarket client
     pub fn build(self) -> Result<Polymarket, PolymarketError> {
+        let endpoints = self.chain.map(|chain| chain.endpoints());
+
         // Build Gamma client
         let mut gamma_builder = Gamma::builder();
 
-        if let Some(url) = self.gamma_base_url {
+        let gamma_base_url = self
+            .gamma_base_url
+            .or_else(|| endpoints.map(|e| e.gamma.to_string()));
+        if let Some(url) = gamma_base_url {
             gamma_builder = gamma_builder.base_url(url);
         }
         if let Some(timeout) = self.timeout_ms {
             gamma_builder = gamma_builder.timeout_ms(timeout);
         }
+        if let Some(size) = self.pool_size {
+            gamma_builder = gamma_builder.pool_size(size);
+        }
+        if let Some(retry) = self.retry.clone() {
+            gamma_builder = gamma_builder.retry_policy(retry);
+        }
 
         let gamma = gamma_builder.build()?;
 
@@ -220,6 +246,12 @@ impl PolymarketBuilder {
         if let Some(timeout) = self.timeout_ms {
             clob_builder = clob_builder.timeout_ms(timeout);
         }
+        if let Some(size) = self.pool_size {
+            clob_builder = clob_builder.pool_size(size);
+        }
+        if let Some(retry) = self.retry.clone() {
+            clob_builder = clob_builder.retry_policy(retry);
+        }
         if let Some(chain) = self.chain {
             clob_builder = clob_builder.chain(chain);
         }
@@ -229,12 +261,21 @@ impl PolymarketBuilder {
         // Build Data API client
         let mut data_builder = DataApiBuilder::default();
 
-        if let Some(url) = self.data_base_url {
+        let data_base_url = self
+            .data_base_url
+            .or_else(|| endpoints.map(|e| e.data.to_string()));
+        if let Some(url) = data_base_url {
             data_builder = data_builder.base_url(url);
         }
         if let Some(timeout) = self.timeout_ms {
             data_builder = data_builder.timeout_ms(timeout);
         }
+        if let Some(size) = self.pool_size {
+            data_builder = data_builder.pool_size(size);
+        }
+        if let Some(retry) = self.retry.clone() {
+            data_builder = data_builder.retry_policy(retry);
+        }
 
         let data = data_builder.build()?;
 